@@ -1,4 +1,10 @@
-use super::{output_type::GenericZpk, tools::relative_degree};
+use super::{
+    output_type::{GenericBa, GenericZpk},
+    tools::{
+        ba::{self, coefficients, comb},
+        normalize, relative_degree,
+    },
+};
 use ndarray::{array, concatenate, Array1, ArrayView, Axis};
 use num::{Complex, Float, Num, NumCast, Zero};
 use std::{
@@ -146,7 +152,7 @@ impl<T: Num + Copy> Div<T> for GenericBandFilter<T> {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct GenericOrdBandFilter<T>(GenericOrdBandFilterType<T>);
+pub struct GenericOrdBandFilter<T>(pub(crate) GenericOrdBandFilterType<T>);
 
 impl<T: std::ops::Deref> std::ops::Deref for GenericOrdBandFilter<T> {
     type Target = GenericOrdBandFilterType<T>;
@@ -184,7 +190,7 @@ impl<T: Float> GenericOrdBandFilter<T> {
                 "for highpass filter ws must be smaller than wp",
             )))?;
         }
-        Ok(Self(GenericOrdBandFilterType::Lowpass { wp, ws }))
+        Ok(Self(GenericOrdBandFilterType::Highpass { wp, ws }))
     }
 
     pub fn bandpass(wp_low: T, wp_high: T, ws_low: T, ws_high: T) -> Result<Self, Error> {
@@ -193,6 +199,11 @@ impl<T: Float> GenericOrdBandFilter<T> {
                 "for bandpass filter ws_low must be smaller than wp_low",
             )))?;
         }
+        if wp_high > ws_high {
+            Err(Error::Validation(Cow::from(
+                "for bandpass filter wp_high must be smaller than ws_high",
+            )))?;
+        }
         Ok(Self(GenericOrdBandFilterType::Bandpass {
             wp_low,
             wp_high,
@@ -207,7 +218,12 @@ impl<T: Float> GenericOrdBandFilter<T> {
                 "for bandstop filter wp_low must be smaller than ws_low",
             )))?;
         }
-        Ok(Self(GenericOrdBandFilterType::Bandpass {
+        if ws_high > wp_high {
+            Err(Error::Validation(Cow::from(
+                "for bandstop filter ws_high must be smaller than wp_high",
+            )))?;
+        }
+        Ok(Self(GenericOrdBandFilterType::Bandstop {
             wp_low,
             wp_high,
             ws_low,
@@ -251,6 +267,47 @@ impl<T: Float> GenericOrdBandFilter<T> {
         };
         Self(inner)
     }
+
+    /// all the passband and stopband edges
+    pub fn to_vec(self) -> Vec<T> {
+        use GenericOrdBandFilterType as S;
+        match self.0 {
+            S::Lowpass { wp, ws } | S::Highpass { wp, ws } => vec![wp, ws],
+            S::Bandpass {
+                wp_low,
+                wp_high,
+                ws_low,
+                ws_high,
+            }
+            | S::Bandstop {
+                wp_low,
+                wp_high,
+                ws_low,
+                ws_high,
+            } => vec![wp_low, wp_high, ws_low, ws_high],
+        }
+    }
+
+    /// the passband edges as a [`GenericBandFilter`] of the same type
+    pub const fn passband(self) -> GenericBandFilter<T> {
+        use GenericOrdBandFilterType as S;
+        match self.0 {
+            S::Lowpass { wp, .. } => GenericBandFilter::Lowpass(wp),
+            S::Highpass { wp, .. } => GenericBandFilter::Highpass(wp),
+            S::Bandpass {
+                wp_low, wp_high, ..
+            } => GenericBandFilter::Bandpass {
+                low: wp_low,
+                high: wp_high,
+            },
+            S::Bandstop {
+                wp_low, wp_high, ..
+            } => GenericBandFilter::Bandstop {
+                low: wp_low,
+                high: wp_high,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -621,3 +678,133 @@ where
         k: k_bs,
     }
 }
+
+/// Transform a lowpass filter prototype to the band of `wo`, see [`lp2bf_zpk`]
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn lp2bf<T: Float>(
+    input: GenericBa<T>,
+    wo: GenericBandFilter<T>,
+) -> Result<GenericBa<T>, ba::Error> {
+    match wo {
+        GenericBandFilter::Lowpass(wo) => lp2lp(input, wo),
+        GenericBandFilter::Highpass(wo) => lp2hp(input, wo),
+        GenericBandFilter::Bandpass { low, high } => lp2bp(input, (low * high).sqrt(), high - low),
+        GenericBandFilter::Bandstop { low, high } => lp2bs(input, (low * high).sqrt(), high - low),
+    }
+}
+
+/// Transform a lowpass filter prototype to a lowpass filter with cutoff frequency `wo`
+///
+/// transfer function version of [`lp2lp_zpk`], `s` is replaced with `s / wo`
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn lp2lp<T: Float>(input: GenericBa<T>, wo: T) -> Result<GenericBa<T>, ba::Error> {
+    let GenericBa { b, a } = coefficients(input)?;
+    let (n, d) = (b.len(), a.len());
+    let m = n.max(d);
+
+    // pwo(i) = wo^(m - 1 - i)
+    let pwo = |i: usize| wo.powi((m - 1 - i) as _);
+    let start1 = n.saturating_sub(d);
+    let start2 = d.saturating_sub(n);
+
+    let b = Array1::from_shape_fn(n, |i| b[i] * pwo(start1) / pwo(start2 + i));
+    let a = Array1::from_shape_fn(d, |i| a[i] * pwo(start1) / pwo(start1 + i));
+    normalize(GenericBa { b, a })
+}
+
+/// Transform a lowpass filter prototype to a highpass filter with cutoff frequency `wo`
+///
+/// transfer function version of [`lp2hp_zpk`], `s` is replaced with `wo / s`
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn lp2hp<T: Float>(input: GenericBa<T>, wo: T) -> Result<GenericBa<T>, ba::Error> {
+    let GenericBa { b, a } = coefficients(input)?;
+    let m = b.len().max(a.len());
+
+    // the coefficients are reversed and padded to the same length
+    let reverse = |coeffs: &Array1<Complex<T>>| {
+        Array1::from_shape_fn(m, |i| {
+            coeffs
+                .len()
+                .checked_sub(i + 1)
+                .map_or_else(Complex::zero, |j| coeffs[j] * wo.powi(i as _))
+        })
+    };
+    normalize(GenericBa {
+        b: reverse(&b),
+        a: reverse(&a),
+    })
+}
+
+/// Transform a lowpass filter prototype to a bandpass filter with center frequency `wo`
+/// and bandwidth `bw`
+///
+/// transfer function version of [`lp2bp_zpk`], `s` is replaced with `(s^2 + wo^2) / (s bw)`
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn lp2bp<T: Float>(input: GenericBa<T>, wo: T, bw: T) -> Result<GenericBa<T>, ba::Error> {
+    let GenericBa { b, a } = coefficients(input)?;
+    let m = b.len().max(a.len()) - 1;
+    let wosq = wo * wo;
+
+    let transform = |coeffs: &Array1<Complex<T>>| {
+        let n = coeffs.len() - 1;
+        let np = n + m;
+        let mut out = Array1::zeros(np + 1);
+        for i in 0..=n {
+            for k in 0..=i {
+                let j = m - i + 2 * k;
+                let value =
+                    coeffs[n - i] * (comb::<T>(i, k) * wosq.powi((i - k) as _) / bw.powi(i as _));
+                out[np - j] = out[np - j] + value;
+            }
+        }
+        out
+    };
+    normalize(GenericBa {
+        b: transform(&b),
+        a: transform(&a),
+    })
+}
+
+/// Transform a lowpass filter prototype to a bandstop filter with center frequency `wo`
+/// and bandwidth `bw`
+///
+/// transfer function version of [`lp2bs_zpk`], `s` is replaced with `(s bw) / (s^2 + wo^2)`
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn lp2bs<T: Float>(input: GenericBa<T>, wo: T, bw: T) -> Result<GenericBa<T>, ba::Error> {
+    let GenericBa { b, a } = coefficients(input)?;
+    let m = b.len().max(a.len()) - 1;
+    let wosq = wo * wo;
+
+    let transform = |coeffs: &Array1<Complex<T>>| {
+        let n = coeffs.len() - 1;
+        let mut out = Array1::zeros(2 * m + 1);
+        for i in 0..=n {
+            for k in 0..=m - i {
+                let j = i + 2 * k;
+                let value = coeffs[n - i]
+                    * (comb::<T>(m - i, k) * wosq.powi((m - i - k) as _) * bw.powi(i as _));
+                out[2 * m - j] = out[2 * m - j] + value;
+            }
+        }
+        out
+    };
+    normalize(GenericBa {
+        b: transform(&b),
+        a: transform(&a),
+    })
+}
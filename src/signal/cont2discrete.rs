@@ -0,0 +1,215 @@
+use super::{
+    lti::{GenericDlti, GenericLti, GenericLtiSystem},
+    output_type::GenericStateSpace,
+    tools::{
+        ss::{self, map_matrix},
+        ss2tf, ss2zpk,
+    },
+};
+use nalgebra::DMatrix;
+use num::{
+    complex::{Complex64, ComplexFloat},
+    traits::FloatConst,
+    Complex, Float,
+};
+use thiserror::Error;
+
+/// Method used by [`cont2discrete`] to map a continuous-time system to a discrete-time one
+#[derive(Debug, Clone, Copy, Default)]
+pub enum DiscretizationMethod<T> {
+    /// zero-order hold, the input is constant between samples
+    #[default]
+    Zoh,
+    /// first-order hold, the input is linear between samples
+    Foh,
+    /// impulse invariance, only valid for strictly proper systems
+    Impulse,
+    /// Tustin's method, equivalent to [`DiscretizationMethod::Gbt`] with `alpha = 0.5`
+    ///
+    /// if `prewarp` is set the response of the systems matches at this angular frequency (rad/s)
+    Bilinear { prewarp: Option<T> },
+    /// forward differencing, equivalent to [`DiscretizationMethod::Gbt`] with `alpha = 0`
+    Euler,
+    /// backward differencing, equivalent to [`DiscretizationMethod::Gbt`] with `alpha = 1`
+    BackwardDiff,
+    /// generalized bilinear transformation with `0 <= alpha <= 1`
+    Gbt { alpha: T },
+}
+
+/// Transform a continuous to a discrete state-space system
+///
+/// the discrete system has sampling period `dt` and the same representation of `system`,
+/// zpk and ba systems are discretized through their state-space realization.
+///
+/// # Errors
+///
+/// Fails if the system has no state-space realization, if the impulse method is applied to a
+/// system which is not strictly proper, if `alpha` is outside of `[0, 1]` or if the matrix
+/// inverted by the generalized bilinear transformation is singular
+pub fn cont2discrete<T>(
+    system: impl Into<GenericLtiSystem<T>>,
+    dt: T,
+    method: DiscretizationMethod<T>,
+) -> Result<GenericDlti<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let system = system.into();
+    let discrete = discretize(system.to_state_space()?, dt, method)?;
+
+    let system = match system {
+        GenericLtiSystem::Zpk(_) => GenericLtiSystem::Zpk(ss2zpk(discrete)?),
+        GenericLtiSystem::Ba(_) => GenericLtiSystem::Ba(ss2tf(discrete)?),
+        GenericLtiSystem::StateSpace(_) => GenericLtiSystem::StateSpace(discrete),
+    };
+    Ok(GenericDlti::new(system, dt))
+}
+
+impl<T: Float + FloatConst + ComplexFloat> GenericLti<T> {
+    /// Discretize the system with sampling period `dt`, see [`cont2discrete`]
+    ///
+    /// # Errors
+    ///
+    /// See [`cont2discrete`]
+    pub fn to_discrete(
+        &self,
+        dt: T,
+        method: DiscretizationMethod<T>,
+    ) -> Result<GenericDlti<T>, Error> {
+        cont2discrete(self.system.clone(), dt, method)
+    }
+}
+
+fn discretize<T: Float>(
+    ss: GenericStateSpace<T>,
+    dt: T,
+    method: DiscretizationMethod<T>,
+) -> Result<GenericStateSpace<T>, Error> {
+    let to_f64 = |a: T| a.to_f64().unwrap_or(f64::NAN);
+    let to_c64 =
+        |m: &DMatrix<Complex<T>>| map_matrix(m, |a| Complex64::new(to_f64(a.re), to_f64(a.im)));
+    let (a, b, c, d) = (to_c64(&ss.a), to_c64(&ss.b), to_c64(&ss.c), to_c64(&ss.d));
+    let dt = to_f64(dt);
+
+    let (ad, bd, cd, dd) = match method {
+        DiscretizationMethod::Gbt { alpha } => gbt(a, b, c, d, dt, to_f64(alpha))?,
+        DiscretizationMethod::Bilinear { prewarp: None } => gbt(a, b, c, d, dt, 0.5)?,
+        DiscretizationMethod::Bilinear {
+            prewarp: Some(omega),
+        } => {
+            // sampling period that maps omega to itself
+            let omega = to_f64(omega);
+            let dt = 2.0 * (omega * dt / 2.0).tan() / omega;
+            gbt(a, b, c, d, dt, 0.5)?
+        }
+        DiscretizationMethod::Euler => gbt(a, b, c, d, dt, 0.0)?,
+        DiscretizationMethod::BackwardDiff => gbt(a, b, c, d, dt, 1.0)?,
+        DiscretizationMethod::Zoh => {
+            let (n, m) = (a.nrows(), b.ncols());
+            let mut em = DMatrix::zeros(n + m, n + m);
+            em.view_mut((0, 0), (n, n)).copy_from(&(a * scalar(dt)));
+            em.view_mut((0, n), (n, m)).copy_from(&(b * scalar(dt)));
+            let ms = em.exp();
+
+            let ad = ms.view((0, 0), (n, n)).into_owned();
+            let bd = ms.view((0, n), (n, m)).into_owned();
+            (ad, bd, c, d)
+        }
+        DiscretizationMethod::Foh => {
+            let (n, m) = (a.nrows(), b.ncols());
+            let mut em = DMatrix::zeros(n + 2 * m, n + 2 * m);
+            em.view_mut((0, 0), (n, n)).copy_from(&(a * scalar(dt)));
+            em.view_mut((0, n), (n, m)).copy_from(&(b * scalar(dt)));
+            em.view_mut((n, n + m), (m, m))
+                .copy_from(&DMatrix::identity(m, m));
+            let ms = em.exp();
+
+            let ms11 = ms.view((0, 0), (n, n)).into_owned();
+            let ms12 = ms.view((0, n), (n, m)).into_owned();
+            let ms13 = ms.view((0, n + m), (n, m)).into_owned();
+            let bd = &ms12 - &ms13 + &ms11 * &ms13;
+            let dd = d + &c * &ms13;
+            (ms11, bd, c, dd)
+        }
+        DiscretizationMethod::Impulse => {
+            if d.iter().any(|d| d.norm() > 1e-8) {
+                return Err(Error::NotStrictlyProper);
+            }
+            let ad = (a * scalar(dt)).exp();
+            let bd = &ad * &b * scalar(dt);
+            let dd = &c * b * scalar(dt);
+            (ad, bd, c, dd)
+        }
+    };
+
+    let from_c64 = |m: &DMatrix<Complex64>| {
+        map_matrix(m, |a| {
+            Complex::new(
+                T::from(a.re).unwrap_or_else(T::nan),
+                T::from(a.im).unwrap_or_else(T::nan),
+            )
+        })
+    };
+    Ok(GenericStateSpace {
+        a: from_c64(&ad),
+        b: from_c64(&bd),
+        c: from_c64(&cd),
+        d: from_c64(&dd),
+    })
+}
+
+type Matrices = (
+    DMatrix<Complex64>,
+    DMatrix<Complex64>,
+    DMatrix<Complex64>,
+    DMatrix<Complex64>,
+);
+
+/// generalized bilinear transformation, `alpha` weights the state at the next sample
+fn gbt(
+    a: DMatrix<Complex64>,
+    b: DMatrix<Complex64>,
+    c: DMatrix<Complex64>,
+    d: DMatrix<Complex64>,
+    dt: f64,
+    alpha: f64,
+) -> Result<Matrices, Error> {
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(Error::Alpha(alpha));
+    }
+
+    let n = a.nrows();
+    let identity = DMatrix::identity(n, n);
+    let ima = &identity - &a * scalar(alpha * dt);
+    let lu = ima.clone().lu();
+
+    let ad = lu
+        .solve(&(&identity + &a * scalar((1.0 - alpha) * dt)))
+        .ok_or(Error::Singular)?;
+    let bd = lu.solve(&(b * scalar(dt))).ok_or(Error::Singular)?;
+    let cd = ima
+        .transpose()
+        .lu()
+        .solve(&c.transpose())
+        .ok_or(Error::Singular)?
+        .transpose();
+    let dd = d + &c * &bd * scalar(alpha);
+
+    Ok((ad, bd, cd, dd))
+}
+
+const fn scalar(a: f64) -> Complex64 {
+    Complex64::new(a, 0.0)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    StateSpace(#[from] ss::Error),
+    #[error("the impulse method is only applicable to strictly proper systems")]
+    NotStrictlyProper,
+    #[error("alpha must be within the interval [0, 1] for the gbt method, received {0}")]
+    Alpha(f64),
+    #[error("the generalized bilinear transformation of the system is singular")]
+    Singular,
+}
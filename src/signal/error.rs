@@ -1,10 +1,39 @@
 use std::fmt::Debug;
 use thiserror::Error;
 
-use super::filter_design;
+use super::{
+    cont2discrete, filter_design, fir_filter_design::firls, fir_filter_design::remez, lti, tools,
+    tools::ba, tools::sos, tools::ss,
+};
 
 #[derive(Debug, Error)]
 pub enum Error {
     #[error(transparent)]
     IIRFilter(#[from] filter_design::error::Error),
+    #[error(transparent)]
+    Ba(#[from] ba::Error),
+    #[error(transparent)]
+    Sos(#[from] sos::Error),
+    #[error(transparent)]
+    Roots(#[from] tools::Error),
+    #[error(transparent)]
+    StateSpace(#[from] ss::Error),
+    #[error(transparent)]
+    Lti(#[from] lti::Error),
+    #[error(transparent)]
+    Cont2Discrete(#[from] cont2discrete::Error),
+    #[error(transparent)]
+    Firls(#[from] firls::Error),
+    #[error(transparent)]
+    Remez(#[from] remez::Error),
+    #[error("the length of the input vector x must be greater than padlen, which is {padlen}, received {len}")]
+    PadLen { len: usize, padlen: usize },
+    #[error("the filter has complex coefficients, use Filter::lfilter instead")]
+    ComplexCoefficients,
+    #[error("zi must hold max(len(a), len(b)) - 1 = {expected} states, received {len} states")]
+    BaInitialState { expected: usize, len: usize },
+    #[error("zi must hold two states for each of the {sections} sections, received {len} states")]
+    SosInitialState { sections: usize, len: usize },
+    #[error("zi must hold one entry for each of the {states} states, received {len} states")]
+    StateSpaceInitialState { states: usize, len: usize },
 }
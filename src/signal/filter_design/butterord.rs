@@ -1,8 +1,10 @@
-use ndarray::{array, Array1};
-use num::{Float, NumCast};
+use num::Float;
 use thiserror::Error;
 
-use crate::signal::{band_filter::GenericOrdBandFilter, GenericSampling};
+use crate::signal::{
+    band_filter::{GenericBandFilter, GenericOrdBandFilter},
+    GenericSampling,
+};
 
 use super::{OrdCompute, OrdResult};
 
@@ -22,6 +24,11 @@ impl<T: Float> OrdCompute<T> for ButterOrd<T> {
     }
 }
 
+/// Butterworth filter order selection
+///
+/// Return the order of the lowest order digital or analog Butterworth filter that loses
+/// no more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband,
+/// together with the Butterworth natural frequency (i.e. the "3dB frequency").
 pub fn buttord<T: Float>(
     band_filter: GenericOrdBandFilter<T>,
     gpass: T,
@@ -29,9 +36,8 @@ pub fn buttord<T: Float>(
     sampling: GenericSampling<T>,
 ) -> Result<OrdResult<T>, Error> {
     _validate_gpass_gstop(gpass, gstop)?;
-    let band_filter = _validate_wp_ws(band_filter, sampling);
+    let band_filter = _validate_wp_ws(band_filter, sampling)?;
     let band_filter = _pre_warp(band_filter, sampling);
-    let (nat, band_filter) = _find_nat_freq(band_filter, gpass, gstop);
 
     let g_stop = T::from(10.0)
         .unwrap()
@@ -40,37 +46,62 @@ pub fn buttord<T: Float>(
         .unwrap()
         .powf(T::from(0.1).unwrap() * gpass.abs());
 
-    // int(ceil(log10((GSTOP - 1.0) / (GPASS - 1.0)) / (2 * log10(nat))))
-    let ord = <f64 as NumCast>::from(
-        (((g_stop - T::one()) / (g_pass - T::one())).log10() / (T::from(2).unwrap() * nat.log10()))
-            .ceil(),
-    )
-    .unwrap();
+    // log10((GSTOP - 1.0) / (GPASS - 1.0)) / (2 * log10(nat))
+    let order = |nat: T| {
+        ((g_stop - T::one()) / (g_pass - T::one())).log10() / (T::from(2).unwrap() * nat.log10())
+    };
+
+    let (nat, band_filter) = _find_nat_freq(band_filter, order);
+    let ord = _validate_order(order(nat).ceil())?;
 
-    let w0 = (g_pass - T::one()).powf(T::from(-1.0 / (2.0 * ord)).unwrap());
-    let ord = ord as u32;
+    // find the Butterworth natural frequency WN (or the "3dB" frequency")
+    // to give exactly gpass at passb
+    let w0 = if ord == 0 {
+        T::one()
+    } else {
+        (g_pass - T::one()).powf(T::from(-1.0 / (2.0 * f64::from(ord))).unwrap())
+    };
 
+    // now convert this frequency back from lowpass prototype
+    // to the original analog filter
     use crate::signal::band_filter::GenericOrdBandFilterType as S;
-    let band_filter = match band_filter.as_ref() {
-        S::Lowpass { .. } => band_filter * w0,
-        S::Highpass { .. } => band_filter / w0,
+    let two = T::from(2).unwrap();
+    let wn = match *band_filter.as_ref() {
+        S::Lowpass { wp, .. } => GenericBandFilter::Lowpass(wp * w0),
+        S::Highpass { wp, .. } => GenericBandFilter::Highpass(wp / w0),
         S::Bandpass {
-            wp_low,
-            wp_high,
-            ws_low,
-            ws_high,
-        } => todo!(),
+            wp_low, wp_high, ..
+        } => {
+            let bw = wp_high - wp_low;
+            let discr = (w0.powi(2) / T::from(4).unwrap() * bw.powi(2) + wp_low * wp_high).sqrt();
+            let wn_1 = (w0 * bw / two + discr).abs();
+            let wn_2 = (-w0 * bw / two + discr).abs();
+            GenericBandFilter::Bandpass {
+                low: wn_1.min(wn_2),
+                high: wn_1.max(wn_2),
+            }
+        }
         S::Bandstop {
-            wp_low,
-            wp_high,
-            ws_low,
-            ws_high,
-        } => todo!(),
+            wp_low, wp_high, ..
+        } => {
+            let bw = wp_high - wp_low;
+            let discr = (bw.powi(2) + T::from(4).unwrap() * w0.powi(2) * wp_low * wp_high).sqrt();
+            let wn_1 = ((bw + discr) / (two * w0)).abs();
+            let wn_2 = ((bw - discr) / (two * w0)).abs();
+            GenericBandFilter::Bandstop {
+                low: wn_1.min(wn_2),
+                high: wn_1.max(wn_2),
+            }
+        }
     };
-    todo!()
+
+    Ok(OrdResult {
+        order: ord,
+        filter: _postprocess_wn(wn, sampling),
+    })
 }
 
-fn _validate_gpass_gstop<T: Float>(gpass: T, gstop: T) -> Result<(), Error> {
+pub(super) fn _validate_gpass_gstop<T: Float>(gpass: T, gstop: T) -> Result<(), Error> {
     if gpass <= T::zero() {
         return Err(Error::BadGPass(gpass.to_f64().unwrap()));
     }
@@ -86,18 +117,36 @@ fn _validate_gpass_gstop<T: Float>(gpass: T, gstop: T) -> Result<(), Error> {
     Ok(())
 }
 
-fn _validate_wp_ws<T: Float>(
+/// normalizes digital edges to the Nyquist frequency, all the edges must be greater than 0
+/// and, for digital filters, less than `fs / 2`
+pub(super) fn _validate_wp_ws<T: Float>(
     mut band_filter: GenericOrdBandFilter<T>,
     sampling: GenericSampling<T>,
-) -> GenericOrdBandFilter<T> {
+) -> Result<GenericOrdBandFilter<T>, Error> {
+    let edges = band_filter.to_vec();
+    if edges.iter().any(|w| w.is_nan() || *w <= T::zero()) {
+        return Err(Error::BadEdges);
+    }
+
     if let GenericSampling::Digital { fs } = &sampling {
         band_filter = (band_filter * T::from(2.0).unwrap()) / *fs;
+        if band_filter.to_vec().iter().any(|w| *w >= T::one()) {
+            return Err(Error::EdgesAboveNyquist);
+        }
     }
 
-    band_filter
+    Ok(band_filter)
+}
+
+/// the order of the filter as an integer, fails if it is not finite, which happens when the
+/// passband and stopband edges coincide
+pub(super) fn _validate_order<T: Float>(order: T) -> Result<u32, Error> {
+    order
+        .to_u32()
+        .ok_or_else(|| Error::BadOrder(order.to_f64().unwrap_or(f64::NAN)))
 }
 
-fn _pre_warp<T: Float>(
+pub(super) fn _pre_warp<T: Float>(
     mut band_filter: GenericOrdBandFilter<T>,
     sampling: GenericSampling<T>,
 ) -> GenericOrdBandFilter<T> {
@@ -110,32 +159,161 @@ fn _pre_warp<T: Float>(
     band_filter
 }
 
-fn _find_nat_freq<T: Float>(
+/// returns the natural frequency of the lowpass prototype and the passband edges,
+/// for bandstop filters the passband edges are moved to minimize `order`
+pub(super) fn _find_nat_freq<T: Float>(
     band_filter: GenericOrdBandFilter<T>,
-    gpass: T,
-    gstop: T,
+    order: impl Fn(T) -> T,
 ) -> (T, GenericOrdBandFilter<T>) {
-    let nat = match *band_filter.as_ref() {
-        crate::signal::band_filter::GenericOrdBandFilterType::Lowpass { wp, ws } => wp / ws,
-        crate::signal::band_filter::GenericOrdBandFilterType::Highpass { wp, ws } => ws / wp,
-        crate::signal::band_filter::GenericOrdBandFilterType::Bandpass {
+    use crate::signal::band_filter::GenericOrdBandFilterType as S;
+
+    match *band_filter.as_ref() {
+        S::Lowpass { wp, ws } => (ws / wp, band_filter),
+        S::Highpass { wp, ws } => (wp / ws, band_filter),
+        S::Bandpass {
             wp_low,
             wp_high,
             ws_low,
             ws_high,
         } => {
-            let nat_1 = (ws_low.powi(2) - wp_low * wp_high) / (ws_low * (wp_low - wp_high));
-            let nat_2 = (ws_high.powi(2) - wp_low * wp_high) / (ws_high * (wp_low - wp_high));
-            nat_1.abs().min(nat_2.abs())
+            let nat = |ws: T| (ws.powi(2) - wp_low * wp_high) / (ws * (wp_low - wp_high));
+            (nat(ws_low).abs().min(nat(ws_high).abs()), band_filter)
         }
-        crate::signal::band_filter::GenericOrdBandFilterType::Bandstop {
+        S::Bandstop {
             wp_low,
             wp_high,
             ws_low,
             ws_high,
-        } => unimplemented!(),
-    };
-    (nat, band_filter)
+        } => {
+            let nat = |wp_low: T, wp_high: T| {
+                let nat = |ws: T| (ws * (wp_low - wp_high)) / (ws.powi(2) - wp_low * wp_high);
+                nat(ws_low).abs().min(nat(ws_high).abs())
+            };
+            let delta = T::from(1e-12).unwrap();
+
+            let wp_low = fminbound(|wp| order(nat(wp, wp_high)), wp_low, ws_low - delta);
+            let wp_high = fminbound(|wp| order(nat(wp_low, wp)), ws_high + delta, wp_high);
+
+            let band_filter = GenericOrdBandFilter(S::Bandstop {
+                wp_low,
+                wp_high,
+                ws_low,
+                ws_high,
+            });
+            (nat(wp_low, wp_high), band_filter)
+        }
+    }
+}
+
+/// converts the natural frequency of the analog filter back to the original frequency scale
+pub(super) fn _postprocess_wn<T: Float>(
+    wn: GenericBandFilter<T>,
+    sampling: GenericSampling<T>,
+) -> GenericBandFilter<T> {
+    use std::f64::consts::PI;
+
+    match sampling {
+        GenericSampling::Analog => wn,
+        GenericSampling::Digital { fs } => {
+            wn.cast_with_fn(|wn| wn.atan() * T::from(2.0 / PI).unwrap() * fs / T::from(2).unwrap())
+        }
+    }
+}
+
+/// Bounded minimization of a scalar function with Brent's method, port of scipy's `fminbound`
+/// with `xtol = 1e-5` and at most 500 function evaluations
+fn fminbound<T: Float>(fun: impl Fn(T) -> T, x1: T, x2: T) -> T {
+    let from = |v: f64| T::from(v).unwrap();
+    let xatol = from(1e-5);
+    let maxfun = 500;
+
+    let sqrt_eps = from(2.2e-16_f64.sqrt());
+    let golden_mean = from(0.5 * (3.0 - 5.0_f64.sqrt()));
+    let (mut a, mut b) = (x1, x2);
+    let mut fulc = a + golden_mean * (b - a);
+    let (mut nfc, mut xf) = (fulc, fulc);
+    let (mut rat, mut e) = (T::zero(), T::zero());
+    let mut fx = fun(xf);
+    let mut num = 1;
+
+    let (mut ffulc, mut fnfc) = (fx, fx);
+    let mut xm = from(0.5) * (a + b);
+    let mut tol1 = sqrt_eps * xf.abs() + xatol / from(3.0);
+    let mut tol2 = from(2.0) * tol1;
+
+    // sign of `x`, with 1 for 0
+    let sign = |x: T| if x < T::zero() { -T::one() } else { T::one() };
+
+    while (xf - xm).abs() > tol2 - from(0.5) * (b - a) {
+        let mut golden = true;
+
+        // check for parabolic fit
+        if e.abs() > tol1 {
+            let r = (xf - nfc) * (fx - ffulc);
+            let q = (xf - fulc) * (fx - fnfc);
+            let mut p = (xf - fulc) * q - (xf - nfc) * r;
+            let mut q = from(2.0) * (q - r);
+            if q > T::zero() {
+                p = -p;
+            }
+            q = q.abs();
+            let r = e;
+            e = rat;
+
+            // check for acceptability of parabola
+            if p.abs() < (from(0.5) * q * r).abs() && p > q * (a - xf) && p < q * (b - xf) {
+                golden = false;
+                rat = p / q;
+                let x = xf + rat;
+
+                if (x - a) < tol2 || (b - x) < tol2 {
+                    rat = tol1 * sign(xm - xf);
+                }
+            }
+        }
+
+        if golden {
+            e = if xf >= xm { a - xf } else { b - xf };
+            rat = golden_mean * e;
+        }
+
+        let x = xf + sign(rat) * rat.abs().max(tol1);
+        let fu = fun(x);
+        num += 1;
+
+        if fu <= fx {
+            if x >= xf {
+                a = xf;
+            } else {
+                b = xf;
+            }
+            (fulc, ffulc) = (nfc, fnfc);
+            (nfc, fnfc) = (xf, fx);
+            (xf, fx) = (x, fu);
+        } else {
+            if x < xf {
+                a = x;
+            } else {
+                b = x;
+            }
+            if fu <= fnfc || nfc == xf {
+                (fulc, ffulc) = (nfc, fnfc);
+                (nfc, fnfc) = (x, fu);
+            } else if fu <= ffulc || fulc == xf || fulc == nfc {
+                (fulc, ffulc) = (x, fu);
+            }
+        }
+
+        xm = from(0.5) * (a + b);
+        tol1 = sqrt_eps * xf.abs() + xatol / from(3.0);
+        tol2 = from(2.0) * tol1;
+
+        if num >= maxfun {
+            break;
+        }
+    }
+
+    xf
 }
 
 #[derive(Debug, Error)]
@@ -146,4 +324,10 @@ pub enum Error {
     BadGStop(f64),
     #[error("gpass should be smaller than gstop, received: gpass {gpass}, gstop {gstop}")]
     BadGpassAndGstop { gpass: f64, gstop: f64 },
+    #[error("values for wp and ws must be greater than 0")]
+    BadEdges,
+    #[error("values for wp and ws must be less than fs / 2")]
+    EdgesAboveNyquist,
+    #[error("the filter order must be finite, received {0}")]
+    BadOrder(f64),
 }
@@ -0,0 +1,65 @@
+use num::Float;
+
+use crate::signal::{band_filter::GenericOrdBandFilter, GenericSampling};
+
+use super::{
+    butterord::{
+        _find_nat_freq, _postprocess_wn, _pre_warp, _validate_gpass_gstop, _validate_order,
+        _validate_wp_ws, Error,
+    },
+    OrdCompute, OrdResult,
+};
+
+pub struct Cheby1Ord<T> {
+    pub band_filter: GenericOrdBandFilter<T>,
+    pub gpass: T,
+    pub gstop: T,
+    pub sampling: GenericSampling<T>,
+}
+
+impl<T: Float> OrdCompute<T> for Cheby1Ord<T> {
+    fn compute_order(&self) -> Result<OrdResult<T>, crate::signal::error::Error> {
+        Ok(
+            cheb1ord(self.band_filter, self.gpass, self.gstop, self.sampling)
+                .map_err(super::Error::from)?,
+        )
+    }
+}
+
+/// Chebyshev type I filter order selection
+///
+/// Return the order of the lowest order digital or analog Chebyshev Type I filter that loses
+/// no more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband,
+/// together with the Chebyshev natural frequency (the passband edges) to use with
+/// [`Cheby1Filter`](super::Cheby1Filter) and `rp = gpass`.
+pub fn cheb1ord<T: Float>(
+    band_filter: GenericOrdBandFilter<T>,
+    gpass: T,
+    gstop: T,
+    sampling: GenericSampling<T>,
+) -> Result<OrdResult<T>, Error> {
+    _validate_gpass_gstop(gpass, gstop)?;
+    let band_filter = _validate_wp_ws(band_filter, sampling)?;
+    let band_filter = _pre_warp(band_filter, sampling);
+
+    let order = cheby_order(gpass, gstop);
+    let (nat, band_filter) = _find_nat_freq(band_filter, &order);
+    let ord = _validate_order(order(nat).ceil())?;
+
+    // natural frequencies are just the passband edges
+    Ok(OrdResult {
+        order: ord,
+        filter: _postprocess_wn(band_filter.passband(), sampling),
+    })
+}
+
+/// order of the Chebyshev lowpass prototype as a function of the natural frequency,
+/// `acosh(sqrt((GSTOP - 1) / (GPASS - 1))) / acosh(nat)`
+pub(super) fn cheby_order<T: Float>(gpass: T, gstop: T) -> impl Fn(T) -> T {
+    let ten = T::from(10.0).unwrap();
+    let g_stop = ten.powf(T::from(0.1).unwrap() * gstop.abs());
+    let g_pass = ten.powf(T::from(0.1).unwrap() * gpass.abs());
+    let v_pass_stop = ((g_stop - T::one()) / (g_pass - T::one())).sqrt().acosh();
+
+    move |nat: T| v_pass_stop / nat.acosh()
+}
@@ -0,0 +1,85 @@
+use num::Float;
+
+use crate::signal::{
+    band_filter::{GenericBandFilter, GenericOrdBandFilter},
+    GenericSampling,
+};
+
+use super::{
+    butterord::{
+        _find_nat_freq, _postprocess_wn, _pre_warp, _validate_gpass_gstop, _validate_order,
+        _validate_wp_ws, Error,
+    },
+    cheby1ord::cheby_order,
+    OrdCompute, OrdResult,
+};
+
+pub struct Cheby2Ord<T> {
+    pub band_filter: GenericOrdBandFilter<T>,
+    pub gpass: T,
+    pub gstop: T,
+    pub sampling: GenericSampling<T>,
+}
+
+impl<T: Float> OrdCompute<T> for Cheby2Ord<T> {
+    fn compute_order(&self) -> Result<OrdResult<T>, crate::signal::error::Error> {
+        Ok(
+            cheb2ord(self.band_filter, self.gpass, self.gstop, self.sampling)
+                .map_err(super::Error::from)?,
+        )
+    }
+}
+
+/// Chebyshev type II filter order selection
+///
+/// Return the order of the lowest order digital or analog Chebyshev Type II filter that loses
+/// no more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband,
+/// together with the Chebyshev natural frequency (the "3dB frequency") to use with
+/// [`Cheby2Filter`](super::Cheby2Filter) and `rs = gstop`.
+pub fn cheb2ord<T: Float>(
+    band_filter: GenericOrdBandFilter<T>,
+    gpass: T,
+    gstop: T,
+    sampling: GenericSampling<T>,
+) -> Result<OrdResult<T>, Error> {
+    _validate_gpass_gstop(gpass, gstop)?;
+    let band_filter = _validate_wp_ws(band_filter, sampling)?;
+    let band_filter = _pre_warp(band_filter, sampling);
+
+    let order = cheby_order(gpass, gstop);
+    let (nat, band_filter) = _find_nat_freq(band_filter, &order);
+    let ord = _validate_order(order(nat).ceil())?;
+
+    // find frequency where analog response is -gpass dB,
+    // then convert back from low-pass prototype to the original filter
+    let new_freq = T::one() / (order(nat) / T::from(ord).unwrap() * nat.acosh()).cosh();
+    let two = T::from(2).unwrap();
+
+    let wn = match band_filter.passband() {
+        GenericBandFilter::Lowpass(wp) => GenericBandFilter::Lowpass(wp / new_freq),
+        GenericBandFilter::Highpass(wp) => GenericBandFilter::Highpass(wp * new_freq),
+        GenericBandFilter::Bandstop { low, high } => {
+            let nat_low = new_freq / two * (low - high)
+                + (new_freq.powi(2) * (high - low).powi(2) / T::from(4).unwrap() + high * low)
+                    .sqrt();
+            GenericBandFilter::Bandstop {
+                low: nat_low,
+                high: high * low / nat_low,
+            }
+        }
+        GenericBandFilter::Bandpass { low, high } => {
+            let nat_low = T::one() / (two * new_freq) * (low - high)
+                + ((high - low).powi(2) / (T::from(4).unwrap() * new_freq.powi(2)) + high * low)
+                    .sqrt();
+            GenericBandFilter::Bandpass {
+                low: nat_low,
+                high: high * low / nat_low,
+            }
+        }
+    };
+
+    Ok(OrdResult {
+        order: ord,
+        filter: _postprocess_wn(wn, sampling),
+    })
+}
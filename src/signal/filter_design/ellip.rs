@@ -1 +1,199 @@
+use crate::signal::output_type::GenericZpk;
+use crate::special::{ellipj, ellipk, ellipkm1};
+use ndarray::{array, concatenate, Array1, Axis};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, NumCast};
+use thiserror::Error;
 
+use super::{GenericIIRFilterSettings, ProtoIIRFilter};
+
+/// Elliptic (Cauer) digital and analog filter design
+///
+/// `rp` is the maximum ripple allowed below unity gain in the passband and
+/// `rs` the minimum attenuation required in the stop band, both in decibels.
+pub struct EllipFilter<T> {
+    pub rp: T,
+    pub rs: T,
+    pub settings: GenericIIRFilterSettings<T>,
+}
+
+impl<T: Float + FloatConst + ComplexFloat> ProtoIIRFilter<T> for EllipFilter<T> {
+    fn proto_filter(&self) -> Result<GenericZpk<T>, crate::signal::error::Error> {
+        Ok(ellipap(self.settings.order, self.rp, self.rs).map_err(super::Error::from)?)
+    }
+
+    fn filter_settings(&self) -> &GenericIIRFilterSettings<T> {
+        &self.settings
+    }
+}
+
+/// Return (z,p,k) of Nth-order elliptic analog lowpass filter
+///
+/// The filter is a normalized prototype that has `rp` decibels of ripple in the passband
+/// and a stopband `rs` decibels down.
+///
+/// The filter's angular (e.g., rad/s) cutoff frequency is normalized to 1,
+/// defined as the point at which the gain first drops below `-rp`.
+pub fn ellipap<T: Float + FloatConst>(order: u32, rp: T, rs: T) -> Result<GenericZpk<T>, Error> {
+    let from = |v: f64| -> T { NumCast::from(v).unwrap() };
+    let epsilon = from(2e-16);
+
+    if order == 0 {
+        // even order filters have DC gain of -rp dB
+        return Ok(GenericZpk {
+            z: array![],
+            p: array![],
+            k: from(10.0).powf(-rp / from(20.0)),
+        });
+    }
+    if order == 1 {
+        let p = -(T::one() / pow10m1(from(0.1) * rp)).sqrt();
+        return Ok(GenericZpk {
+            z: array![],
+            p: array![Complex::new(p, T::zero())],
+            k: -p,
+        });
+    }
+
+    let eps_sq = pow10m1(from(0.1) * rp);
+    let eps = eps_sq.sqrt();
+    let ck1_sq = eps_sq / pow10m1(from(0.1) * rs);
+    if ck1_sq.is_zero() {
+        return Err(Error::Specification);
+    }
+
+    let val = ellipk(ck1_sq);
+    let m = ellipdeg(order, ck1_sq);
+    let capk = ellipk(m);
+    let n = from(order as f64);
+    let odd = order % 2 == 1;
+
+    let j = Array1::range(if odd { T::zero() } else { T::one() }, n, from(2.0));
+    let sncndn = j.mapv(|j| ellipj(j * capk / n, m));
+
+    let z: Array1<_> = sncndn
+        .iter()
+        .map(|&(s, ..)| s)
+        .filter(|s| Float::abs(*s) > epsilon)
+        .map(|s| Complex::new(T::zero(), T::one() / (m.sqrt() * s)))
+        .collect();
+    let z = concatenate![Axis(0), z, z.mapv(|z| z.conj())];
+
+    let r = arc_jac_sc1(T::one() / eps, ck1_sq)?;
+    let v0 = capk * r / (n * val);
+
+    let (sv, cv, dv, _) = ellipj(v0, T::one() - m);
+    let p = sncndn.mapv(|(s, c, d, _)| {
+        -Complex::new(c * d * sv * cv, s * dv) / (T::one() - (d * sv).powi(2))
+    });
+
+    let p = if odd {
+        let norm = p
+            .iter()
+            .map(|p| p.norm_sqr())
+            .fold(T::zero(), |a, b| a + b)
+            .sqrt();
+        let newp: Array1<_> = p
+            .iter()
+            .filter(|p| Float::abs(p.im) > epsilon * norm)
+            .map(|p| p.conj())
+            .collect();
+        concatenate![Axis(0), p, newp]
+    } else {
+        concatenate![Axis(0), p, p.mapv(|p| p.conj())]
+    };
+
+    let mut k = (p.mapv(|p| -p).product() / z.mapv(|z| -z).product()).re;
+    if !odd {
+        k = k / (T::one() + eps_sq).sqrt();
+    }
+
+    Ok(GenericZpk { z, p, k })
+}
+
+/// `10^x - 1` for `x` near 0
+pub(super) fn pow10m1<T: Float + FloatConst>(x: T) -> T {
+    (T::LN_10() * x).exp_m1()
+}
+
+/// Maximum number of terms of the series in [`ellipdeg`]
+const ELLIPDEG_MMAX: i32 = 7;
+
+/// Solve the degree equation `n * K(m) / K'(m) = K1(m1) / K1'(m1)` for `m` using nomes
+fn ellipdeg<T: Float + FloatConst>(n: u32, m1: T) -> T {
+    let k1 = ellipk(m1);
+    let k1p = ellipkm1(m1);
+
+    let q1 = (-T::PI() * k1p / k1).exp();
+    let q = q1.powf(T::one() / T::from(n).unwrap());
+
+    let num = (0..=ELLIPDEG_MMAX)
+        .map(|m| q.powi(m * (m + 1)))
+        .fold(T::zero(), |a, b| a + b);
+    let den = (1..=ELLIPDEG_MMAX + 1)
+        .map(|m| q.powi(m * m))
+        .fold(T::zero(), |a, b| a + b);
+    let den = T::one() + (den + den);
+
+    T::from(16).unwrap() * q * (num / den).powi(4)
+}
+
+/// Maximum number of iterations in the Landen transformation recursion sequence
+const ARC_JAC_SN_MAXITER: usize = 10;
+
+/// Inverse Jacobian elliptic sn, solves for `z` in `w = sn(z, m)`
+fn arc_jac_sn<T: Float + FloatConst>(w: Complex<T>, m: T) -> Result<Complex<T>, Error> {
+    // (1 - k^2)^0.5, this expression works for small k
+    let one = Complex::new(T::one(), T::zero());
+    let complement = |k: Complex<T>| ((one - k) * (one + k)).sqrt();
+
+    let k = m.sqrt();
+    if k > T::one() {
+        return Ok(Complex::new(T::nan(), T::nan()));
+    }
+    if k == T::one() {
+        return Ok(w.atanh());
+    }
+
+    let mut ks = vec![k];
+    while !ks[ks.len() - 1].is_zero() {
+        let k = ks[ks.len() - 1];
+        let kp = complement(Complex::new(k, T::zero())).re;
+        ks.push((T::one() - kp) / (T::one() + kp));
+        if ks.len() > ARC_JAC_SN_MAXITER + 1 {
+            return Err(Error::LandenConvergence);
+        }
+    }
+
+    let capk = ks[1..]
+        .iter()
+        .fold(T::one(), |acc, k| acc * (T::one() + *k))
+        * T::FRAC_PI_2();
+
+    let wn = ks.windows(2).fold(w, |wn, ks| {
+        let (kn, knext) = (ks[0], ks[1]);
+        (wn * T::from(2).unwrap()) / ((complement(wn * kn) + one) * (T::one() + knext))
+    });
+
+    let u = wn.asin() * (T::from(2).unwrap() / T::PI());
+    Ok(u * capk)
+}
+
+/// Real inverse Jacobian sc with complementary modulus, solves for `z` in `w = sc(z, 1 - m)`
+fn arc_jac_sc1<T: Float + FloatConst>(w: T, m: T) -> Result<T, Error> {
+    // sc(z, m) = -i * sn(i * z, 1 - m)
+    let z = arc_jac_sn(Complex::new(T::zero(), w), m)?;
+    if Float::abs(z.re) > T::from(1e-14).unwrap() {
+        return Err(Error::ArcJacSc1);
+    }
+    Ok(z.im)
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum Error {
+    #[error("Cannot design a filter with given rp and rs specifications")]
+    Specification,
+    #[error("Landen transformation not converging")]
+    LandenConvergence,
+    #[error("the inverse Jacobian sc has a non zero real part")]
+    ArcJacSc1,
+}
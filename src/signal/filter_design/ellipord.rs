@@ -0,0 +1,67 @@
+use num::{traits::FloatConst, Float};
+
+use crate::{
+    signal::{band_filter::GenericOrdBandFilter, GenericSampling},
+    special::{ellipk, ellipkm1},
+};
+
+use super::{
+    butterord::{
+        _find_nat_freq, _postprocess_wn, _pre_warp, _validate_gpass_gstop, _validate_order,
+        _validate_wp_ws, Error,
+    },
+    ellip::pow10m1,
+    OrdCompute, OrdResult,
+};
+
+pub struct EllipOrd<T> {
+    pub band_filter: GenericOrdBandFilter<T>,
+    pub gpass: T,
+    pub gstop: T,
+    pub sampling: GenericSampling<T>,
+}
+
+impl<T: Float + FloatConst> OrdCompute<T> for EllipOrd<T> {
+    fn compute_order(&self) -> Result<OrdResult<T>, crate::signal::error::Error> {
+        Ok(
+            ellipord(self.band_filter, self.gpass, self.gstop, self.sampling)
+                .map_err(super::Error::from)?,
+        )
+    }
+}
+
+/// Elliptic (Cauer) filter order selection
+///
+/// Return the order of the lowest order digital or analog elliptic filter that loses
+/// no more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband,
+/// together with the natural frequency (the passband edges) to use with
+/// [`EllipFilter`](super::EllipFilter), `rp = gpass` and `rs = gstop`.
+pub fn ellipord<T: Float + FloatConst>(
+    band_filter: GenericOrdBandFilter<T>,
+    gpass: T,
+    gstop: T,
+    sampling: GenericSampling<T>,
+) -> Result<OrdResult<T>, Error> {
+    _validate_gpass_gstop(gpass, gstop)?;
+    let band_filter = _validate_wp_ws(band_filter, sampling)?;
+    let band_filter = _pre_warp(band_filter, sampling);
+
+    let tenth = T::from(0.1).unwrap();
+    let arg1_sq = pow10m1(tenth * gpass) / pow10m1(tenth * gstop.abs());
+    let d1 = (ellipk(arg1_sq), ellipkm1(arg1_sq));
+
+    // K(1 / nat^2) K'(arg1^2) / (K'(1 / nat^2) K(arg1^2))
+    let order = |nat: T| {
+        let arg0_sq = (T::one() / nat).powi(2);
+        ellipk(arg0_sq) * d1.1 / (ellipkm1(arg0_sq) * d1.0)
+    };
+
+    let (nat, band_filter) = _find_nat_freq(band_filter, order);
+    let ord = _validate_order(order(nat).ceil())?;
+
+    // natural frequencies are just the passband edges
+    Ok(OrdResult {
+        order: ord,
+        filter: _postprocess_wn(band_filter.passband(), sampling),
+    })
+}
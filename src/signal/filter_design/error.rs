@@ -6,6 +6,14 @@ pub enum Error {
     Bessel(#[from] super::bessel::Error),
     #[error(transparent)]
     ButterOrd(#[from] super::butterord::Error),
+    #[error(transparent)]
+    Ellip(#[from] super::ellip::Error),
+    #[error(transparent)]
+    Gammatone(#[from] super::gammatone::Error),
+    #[error(transparent)]
+    IIRDesign(#[from] super::iirdesign::Error),
+    #[error(transparent)]
+    IIRNotch(#[from] super::iirnotch::Error),
     #[error("{0}")]
     Infallible(#[from] Infallible),
 }
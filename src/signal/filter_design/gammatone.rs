@@ -0,0 +1,190 @@
+use ndarray::Array1;
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
+use thiserror::Error;
+
+use super::ba_output;
+use crate::signal::{
+    output_type::{DesiredFilterOutput, GenericBa, GenericFilterOutput},
+    GenericSampling,
+};
+
+/// Kind of filter designed by [`gammatone`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GammatoneType {
+    /// finite impulse response, a truncated and sampled gammatone impulse response
+    ///
+    /// `order` defaults to 4 and must be between 1 and 24, `numtaps` defaults to
+    /// `max(fs * 0.015, 15)`
+    Fir {
+        order: Option<u32>,
+        numtaps: Option<usize>,
+    },
+    /// fourth order infinite impulse response
+    #[default]
+    Iir,
+}
+
+/// Gammatone filter design
+///
+/// This function computes the coefficients of an FIR or IIR gammatone digital filter centered
+/// at `freq`, in the same units as `fs`. The bandwidth of the filter is proportional to the
+/// equivalent rectangular bandwidth of the human auditory filter at `freq`.
+///
+/// The FIR filter is approximately normalized to unit gain at `freq` for the default order,
+/// the IIR filter is always normalized to unit gain at `freq`.
+///
+/// # Errors
+///
+/// Fails if `sampling` is analog, if `freq` is not between 0 and `fs / 2`, if the FIR order
+/// is not between 1 and 24 or if the FIR filter has no taps
+///
+/// # References
+///
+/// Slaney, Malcolm, "An Efficient Implementation of the Patterson-Holdsworth Auditory Filter
+/// Bank", Apple Computer Technical Report 35, 1993, pp.3-8, 34-39.
+pub fn gammatone<T>(
+    freq: T,
+    ftype: GammatoneType,
+    sampling: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ba = match ftype {
+        GammatoneType::Fir { order, numtaps } => gammatone_fir(freq, order, numtaps, sampling),
+        GammatoneType::Iir => gammatone_iir(freq, sampling),
+    }
+    .map_err(super::Error::from)?;
+    ba_output(ba, desired_output, sampling)
+}
+
+fn gammatone_fir<T: Float + FloatConst>(
+    freq: T,
+    order: Option<u32>,
+    numtaps: Option<usize>,
+    sampling: GenericSampling<T>,
+) -> Result<GenericBa<T>, Error> {
+    let fs = check_frequency(freq, sampling)?;
+
+    let order = order.unwrap_or(4);
+    if !(1..=24).contains(&order) {
+        return Err(Error::Order(order));
+    }
+    let numtaps = numtaps.unwrap_or_else(|| {
+        (fs * T::from(0.015).unwrap())
+            .to_usize()
+            .unwrap_or_default()
+            .max(15)
+    });
+    if numtaps == 0 {
+        return Err(Error::NumTaps);
+    }
+
+    let bw = T::from(1.019).unwrap() * hz_to_erb(freq);
+    let exponent = T::from(order - 1).unwrap();
+    let b = Array1::range(T::zero(), T::from(numtaps).unwrap(), T::one()).mapv(|n| {
+        let t = n / fs;
+        t.powf(exponent) * (-T::TAU() * bw * t).exp() * (T::TAU() * freq * t).cos()
+    });
+
+    // normalize to unit gain at freq for the fourth order filter
+    let factorial = (1..order).fold(T::one(), |acc, k| acc * T::from(k).unwrap());
+    let scale = T::from(2).unwrap() * (T::TAU() * bw).powi(order as i32) / factorial / fs;
+
+    Ok(GenericBa {
+        b: b.mapv(|b| Complex::from(b * scale)),
+        a: Array1::ones(1),
+    })
+}
+
+fn gammatone_iir<T: Float + FloatConst>(
+    freq: T,
+    sampling: GenericSampling<T>,
+) -> Result<GenericBa<T>, Error> {
+    let fs = check_frequency(freq, sampling)?;
+    let c = |a: f64| T::from(a).unwrap();
+
+    let t = fs.recip();
+    let bw = T::TAU() * c(1.019) * hz_to_erb(freq);
+    let fr = T::TAU() * freq * t;
+    let bwt = bw * t;
+
+    // gain at the center frequency
+    let i = Complex::<T>::i();
+    let g1 = (i * fr * c(2.0)).exp() * t * c(-2.0);
+    let g2 = (i * fr - bwt).exp() * t * c(2.0);
+    let g3 = (c(3.0) + c(2.0).powf(c(1.5))).sqrt() * fr.sin();
+    let g4 = (c(3.0) - c(2.0).powf(c(1.5))).sqrt() * fr.sin();
+    let g5 = (i * fr * c(2.0)).exp();
+
+    let g = [-g4, g4, -g3, g3]
+        .into_iter()
+        .fold(Complex::from(T::one()), |acc, g| {
+            acc * (g1 + g2 * (fr.cos() + g))
+        });
+    let den =
+        (g5 * c(-2.0) + (g5 + c(1.0)) * c(2.0) / bwt.exp() - c(2.0) / (bwt * c(2.0)).exp()).powi(4);
+    let g = (g / den).norm();
+
+    let decay = |k: f64| (bwt * c(k)).exp();
+    let cos = |k: f64| (fr * c(k)).cos();
+    let t4 = t.powi(4) / g;
+
+    let b = [
+        t4,
+        c(-4.0) * t4 * cos(1.0) / decay(1.0),
+        c(6.0) * t4 * cos(2.0) / decay(2.0),
+        c(-4.0) * t4 * cos(3.0) / decay(3.0),
+        t4 * cos(4.0) / decay(4.0),
+    ];
+    let a = [
+        T::one(),
+        c(-8.0) * cos(1.0) / decay(1.0),
+        c(4.0) * (c(4.0) + c(3.0) * cos(2.0)) / decay(2.0),
+        c(-8.0) * (c(6.0) * cos(1.0) + cos(3.0)) / decay(3.0),
+        c(2.0) * (c(18.0) + c(16.0) * cos(2.0) + cos(4.0)) / decay(4.0),
+        c(-8.0) * (c(6.0) * cos(1.0) + cos(3.0)) / decay(5.0),
+        c(4.0) * (c(4.0) + c(3.0) * cos(2.0)) / decay(6.0),
+        c(-8.0) * cos(1.0) / decay(7.0),
+        decay(-8.0),
+    ];
+
+    Ok(GenericBa {
+        b: b.into_iter().map(Complex::from).collect(),
+        a: a.into_iter().map(Complex::from).collect(),
+    })
+}
+
+/// equivalent rectangular bandwidth of the human auditory filter centered at `hz`
+fn hz_to_erb<T: Float>(hz: T) -> T {
+    let ear_q = T::from(9.26449).unwrap();
+    let min_bw = T::from(24.7).unwrap();
+    hz / ear_q + min_bw
+}
+
+/// validates the center frequency and returns the sampling frequency
+fn check_frequency<T: Float>(freq: T, sampling: GenericSampling<T>) -> Result<T, Error> {
+    let GenericSampling::Digital { fs } = sampling else {
+        return Err(Error::Analog);
+    };
+    if freq.is_nan() || freq <= T::zero() || freq >= fs / T::from(2).unwrap() {
+        return Err(Error::Frequency {
+            freq: freq.to_f64().unwrap_or(f64::NAN),
+            fs: fs.to_f64().unwrap_or(f64::NAN),
+        });
+    }
+    Ok(fs)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("gammatone filters can only be designed for digital sampling")]
+    Analog,
+    #[error("freq must be between 0 and fs / 2, received freq = {freq} with fs = {fs}")]
+    Frequency { freq: f64, fs: f64 },
+    #[error("the order of FIR gammatone filters must be between 1 and 24, received {0}")]
+    Order(u32),
+    #[error("FIR gammatone filters must have at least one tap")]
+    NumTaps,
+}
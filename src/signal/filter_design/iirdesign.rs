@@ -0,0 +1,158 @@
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
+use thiserror::Error;
+
+use crate::{
+    optimize::{util::Espilon, Metric},
+    signal::{
+        band_filter::{GenericBandFilter, GenericOrdBandFilter},
+        output_type::{DesiredFilterOutput, GenericFilterOutput},
+        GenericSampling,
+    },
+};
+
+use super::{
+    BesselFilter, BesselNorm, ButterFilter, ButterOrd, Cheby1Filter, Cheby1Ord, Cheby2Filter,
+    Cheby2Ord, EllipFilter, EllipOrd, GenericIIRFilterSettings, IIRFilterDesign, OrdCompute,
+    OrdResult,
+};
+
+/// IIR filter family used by [`iirdesign`] and [`iirfilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IIRFilterFamily {
+    Butter,
+    Cheby1,
+    Cheby2,
+    Ellip,
+    Bessel(BesselNorm),
+}
+
+/// Complete IIR digital and analog filter design
+///
+/// Given passband and stopband frequencies and gains, construct an analog or digital IIR filter
+/// of minimum order for a given basic type. The order is selected with the [`OrdCompute`]
+/// implementation of the filter family, `gpass` is used as the passband ripple and `gstop`
+/// as the stopband attenuation of the designed filter.
+///
+/// Bessel filters have no order selection, use [`iirfilter`] instead.
+pub fn iirdesign<T>(
+    band_filter: GenericOrdBandFilter<T>,
+    gpass: T,
+    gstop: T,
+    family: IIRFilterFamily,
+    sampling: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat + Metric + Espilon,
+    Complex<T>: Espilon,
+{
+    let OrdResult { order, filter } = match family {
+        IIRFilterFamily::Butter => ButterOrd {
+            band_filter,
+            gpass,
+            gstop,
+            sampling,
+        }
+        .compute_order()?,
+        IIRFilterFamily::Cheby1 => Cheby1Ord {
+            band_filter,
+            gpass,
+            gstop,
+            sampling,
+        }
+        .compute_order()?,
+        IIRFilterFamily::Cheby2 => Cheby2Ord {
+            band_filter,
+            gpass,
+            gstop,
+            sampling,
+        }
+        .compute_order()?,
+        IIRFilterFamily::Ellip => EllipOrd {
+            band_filter,
+            gpass,
+            gstop,
+            sampling,
+        }
+        .compute_order()?,
+        IIRFilterFamily::Bessel(_) => {
+            return Err(super::Error::from(Error::NoOrderSelection(family)).into())
+        }
+    };
+
+    iirfilter(
+        order,
+        filter,
+        Some(gpass),
+        Some(gstop),
+        family,
+        sampling,
+        desired_output,
+    )
+}
+
+/// IIR digital and analog filter design given order and critical points
+///
+/// `rp` is the maximum ripple in the passband, required by [`IIRFilterFamily::Cheby1`] and
+/// [`IIRFilterFamily::Ellip`], `rs` is the minimum attenuation in the stop band, required by
+/// [`IIRFilterFamily::Cheby2`] and [`IIRFilterFamily::Ellip`], both in decibels.
+pub fn iirfilter<T>(
+    order: u32,
+    band_filter: GenericBandFilter<T>,
+    rp: Option<T>,
+    rs: Option<T>,
+    family: IIRFilterFamily,
+    analog: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat + Metric + Espilon,
+    Complex<T>: Espilon,
+{
+    let settings = GenericIIRFilterSettings {
+        order,
+        band_filter,
+        analog,
+    };
+    let rp = || {
+        rp.ok_or(Error::MissingRipple(family))
+            .map_err(super::Error::from)
+    };
+    let rs = || {
+        rs.ok_or(Error::MissingAttenuation(family))
+            .map_err(super::Error::from)
+    };
+
+    match family {
+        IIRFilterFamily::Butter => ButterFilter { settings }.compute_filter(desired_output),
+        IIRFilterFamily::Cheby1 => Cheby1Filter {
+            rp: rp()?,
+            settings,
+        }
+        .compute_filter(desired_output),
+        IIRFilterFamily::Cheby2 => Cheby2Filter {
+            rs: rs()?,
+            settings,
+        }
+        .compute_filter(desired_output),
+        IIRFilterFamily::Ellip => EllipFilter {
+            rp: rp()?,
+            rs: rs()?,
+            settings,
+        }
+        .compute_filter(desired_output),
+        IIRFilterFamily::Bessel(norm) => {
+            BesselFilter { norm, settings }.compute_filter(desired_output)
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0:?} filters do not have order selection, use iirfilter")]
+    NoOrderSelection(IIRFilterFamily),
+    #[error("passband ripple (rp) must be provided to design {0:?} filters")]
+    MissingRipple(IIRFilterFamily),
+    #[error("stopband attenuation (rs) must be provided to design {0:?} filters")]
+    MissingAttenuation(IIRFilterFamily),
+}
@@ -0,0 +1,207 @@
+use ndarray::{array, Array1};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
+use thiserror::Error;
+
+use super::ba_output;
+use crate::signal::{
+    output_type::{DesiredFilterOutput, GenericBa, GenericFilterOutput},
+    GenericSampling,
+};
+
+/// Response of the comb filters designed by [`iircomb`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CombType {
+    /// narrow notches with a flat passband between them
+    #[default]
+    Notch,
+    /// narrow peaks with a flat stopband between them
+    Peak,
+}
+
+/// Design second-order IIR notch digital filter
+///
+/// A notch filter is a band-stop filter with a narrow bandwidth (high quality factor).
+/// It rejects a narrow frequency band around `w0` and leaves the rest of the spectrum
+/// little changed.
+///
+/// `w0` is the frequency to remove, in the same units as `fs`, and `quality` is the
+/// dimensionless quality factor characterizing the notch filter, `w0 / quality` is its
+/// -3 dB bandwidth.
+///
+/// # Errors
+///
+/// Fails if `sampling` is analog, if `w0` is not between 0 and `fs / 2` or if `quality`
+/// is not positive
+pub fn iirnotch<T>(
+    w0: T,
+    quality: T,
+    sampling: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ba = notch_peak(w0, quality, sampling, CombType::Notch).map_err(super::Error::from)?;
+    ba_output(ba, desired_output, sampling)
+}
+
+/// Design second-order IIR peak (resonant) digital filter
+///
+/// A peak filter is a band-pass filter with a narrow bandwidth (high quality factor).
+/// It rejects components outside a narrow frequency band around `w0`.
+///
+/// `w0` is the frequency to retain, in the same units as `fs`, and `quality` is the
+/// dimensionless quality factor characterizing the peak filter, `w0 / quality` is its
+/// -3 dB bandwidth.
+///
+/// # Errors
+///
+/// Fails if `sampling` is analog, if `w0` is not between 0 and `fs / 2` or if `quality`
+/// is not positive
+pub fn iirpeak<T>(
+    w0: T,
+    quality: T,
+    sampling: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ba = notch_peak(w0, quality, sampling, CombType::Peak).map_err(super::Error::from)?;
+    ba_output(ba, desired_output, sampling)
+}
+
+/// Design IIR notching or peaking digital comb filter
+///
+/// A notching comb filter consists of regularly-spaced band-stop filters with a narrow
+/// bandwidth (high quality factor), each rejects a narrow frequency band and leaves the rest
+/// of the spectrum little changed.
+///
+/// A peaking comb filter consists of regularly-spaced band-pass filters with a narrow
+/// bandwidth (high quality factor), each rejects components outside a narrow frequency band.
+///
+/// `w0` is the fundamental frequency of the comb, in the same units as `fs`, and `fs` must be
+/// divisible by `w0`. The comb filter repeats at multiples of `w0`, `quality` sets the -3 dB
+/// bandwidth `w0 / quality` of each notch or peak.
+///
+/// If `pass_zero` is `false` the nulls of the filter are centered on zero, `w0` and its
+/// harmonics and the peaks halfway between them, otherwise the peaks are centered on the
+/// harmonics and the nulls halfway between them.
+///
+/// # Errors
+///
+/// Fails if `sampling` is analog, if `w0` is not between 0 and `fs / 2`, if `fs / w0` is not
+/// an integer or if `quality` is not positive
+pub fn iircomb<T>(
+    w0: T,
+    quality: T,
+    comb_type: CombType,
+    pass_zero: bool,
+    sampling: GenericSampling<T>,
+    desired_output: DesiredFilterOutput,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let fs = check_arguments(w0, quality, sampling).map_err(super::Error::from)?;
+
+    let n = (fs / w0).round();
+    if Float::abs((w0 - fs / n) / fs) > T::from(1e-14).unwrap() {
+        return Err(super::Error::from(Error::CombFrequency {
+            w0: to_f64(w0),
+            fs: to_f64(fs),
+        })
+        .into());
+    }
+
+    let w0 = T::TAU() * w0 / fs;
+    let w_delta = w0 / quality;
+
+    // gain at the center of the notches and peaks, the edges of the bands are at -3 dB
+    let (g0, g) = match comb_type {
+        CombType::Notch => (T::one(), T::zero()),
+        CombType::Peak => (T::zero(), T::one()),
+    };
+    let beta = Float::tan(n * w_delta / T::from(4).unwrap());
+
+    let ax = (T::one() - beta) / (T::one() + beta);
+    let bx = (g0 + g * beta) / (T::one() + beta);
+    let cx = (g0 - g * beta) / (T::one() + beta);
+
+    let negative = match comb_type {
+        CombType::Notch => !pass_zero,
+        CombType::Peak => pass_zero,
+    };
+    let sign = if negative { -T::one() } else { T::one() };
+
+    let n = n.to_usize().unwrap_or_default();
+    let mut b = Array1::zeros(n + 1);
+    let mut a = Array1::zeros(n + 1);
+    b[0] = Complex::from(bx);
+    b[n] = Complex::from(sign * cx);
+    a[0] = Complex::from(T::one());
+    a[n] = Complex::from(sign * ax);
+
+    ba_output(GenericBa { b, a }, desired_output, sampling)
+}
+
+fn notch_peak<T: Float + FloatConst>(
+    w0: T,
+    quality: T,
+    sampling: GenericSampling<T>,
+    filter_type: CombType,
+) -> Result<GenericBa<T>, Error> {
+    let fs = check_arguments(w0, quality, sampling)?;
+
+    let w0 = T::TAU() * w0 / fs;
+    let bw = w0 / quality;
+
+    // the bandwidth is measured at -3 dB
+    let beta = (bw / T::from(2).unwrap()).tan();
+    let gain = T::one() / (T::one() + beta);
+    let two = T::from(2).unwrap();
+
+    let b = match filter_type {
+        CombType::Notch => array![gain, -two * gain * w0.cos(), gain],
+        CombType::Peak => array![T::one() - gain, T::zero(), gain - T::one()],
+    };
+    let a = array![T::one(), -two * gain * w0.cos(), two * gain - T::one()];
+
+    Ok(GenericBa {
+        b: b.mapv(Complex::from),
+        a: a.mapv(Complex::from),
+    })
+}
+
+/// validates the arguments and returns the sampling frequency
+fn check_arguments<T: Float>(w0: T, quality: T, sampling: GenericSampling<T>) -> Result<T, Error> {
+    let GenericSampling::Digital { fs } = sampling else {
+        return Err(Error::Analog);
+    };
+    if w0.is_nan() || w0 <= T::zero() || w0 >= fs / T::from(2).unwrap() {
+        return Err(Error::Frequency {
+            w0: to_f64(w0),
+            fs: to_f64(fs),
+        });
+    }
+    if quality.is_nan() || quality <= T::zero() {
+        return Err(Error::Quality(to_f64(quality)));
+    }
+    Ok(fs)
+}
+
+fn to_f64<T: Float>(a: T) -> f64 {
+    a.to_f64().unwrap_or(f64::NAN)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("notch, peak and comb filters can only be designed for digital sampling")]
+    Analog,
+    #[error("w0 must be between 0 and fs / 2, received w0 = {w0} with fs = {fs}")]
+    Frequency { w0: f64, fs: f64 },
+    #[error("fs / w0 must be an integer, received w0 = {w0} with fs = {fs}")]
+    CombFrequency { w0: f64, fs: f64 },
+    #[error("the quality factor must be positive, received {0}")]
+    Quality(f64),
+}
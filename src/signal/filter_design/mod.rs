@@ -1,9 +1,12 @@
 use super::{
     band_filter::GenericBandFilter,
-    output_type::{DesiredFilterOutput, GenericFilterOutput, GenericZpk},
+    output_type::{DesiredFilterOutput, GenericBa, GenericFilterOutput, GenericZpk},
     GenericSampling,
 };
-use crate::signal::{band_filter::lp2bf_zpk, tools::bilinear_zpk};
+use crate::signal::{
+    band_filter::lp2bf_zpk,
+    tools::{bilinear_zpk, tf2zpk},
+};
 use num::{complex::ComplexFloat, traits::FloatConst, Float};
 
 pub mod bessel;
@@ -11,15 +14,29 @@ pub mod butter;
 #[allow(unused)]
 pub mod butterord;
 pub mod cheby1;
+pub mod cheby1ord;
 pub mod cheby2;
+pub mod cheby2ord;
+pub mod ellip;
+pub mod ellipord;
 pub mod error;
-// pub mod ellip;
+pub mod gammatone;
+pub mod iirdesign;
+pub mod iirnotch;
 pub use error::Error;
 
 pub use bessel::{besselap, BesselFilter, BesselNorm};
 pub use butter::{buttap, ButterFilter};
+pub use butterord::{buttord, ButterOrd};
 pub use cheby1::{cheb1ap, Cheby1Filter};
+pub use cheby1ord::{cheb1ord, Cheby1Ord};
 pub use cheby2::{cheb2ap, Cheby2Filter};
+pub use cheby2ord::{cheb2ord, Cheby2Ord};
+pub use ellip::{ellipap, EllipFilter};
+pub use ellipord::{ellipord, EllipOrd};
+pub use gammatone::{gammatone, GammatoneType};
+pub use iirdesign::{iirdesign, iirfilter, IIRFilterFamily};
+pub use iirnotch::{iircomb, iirnotch, iirpeak, CombType};
 
 /// Generic iir_filter
 ///
@@ -53,7 +70,24 @@ where
         result = bilinear_zpk(result, *fs);
     }
 
-    Ok(GenericFilterOutput::get_output(result, desired_output))
+    GenericFilterOutput::get_output(result, desired_output, analog)
+}
+
+/// Converts a ba filter in the desired output representation
+///
+/// the ba filter is returned as is, the other representations go through its zpk form
+fn ba_output<T>(
+    ba: GenericBa<T>,
+    desired_output: DesiredFilterOutput,
+    sampling: GenericSampling<T>,
+) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    match desired_output {
+        DesiredFilterOutput::Ba => Ok(GenericFilterOutput::Ba(ba)),
+        desired_output => GenericFilterOutput::get_output(tf2zpk(ba)?, desired_output, sampling),
+    }
 }
 
 pub struct GenericIIRFilterSettings<T> {
@@ -0,0 +1,186 @@
+use nalgebra::{DMatrix, DVector};
+use ndarray::Array1;
+use num::{traits::FloatConst, Float};
+use thiserror::Error;
+
+use crate::signal::{
+    output_type::{GenericBa, GenericFilterOutput},
+    GenericSampling,
+};
+
+/// FIR filter design using least-squares error minimization
+///
+/// Calculate the coefficients of the linear-phase finite impulse response (FIR) filter which
+/// has the best approximation to the desired frequency response in the least-squares sense,
+/// that is the integral of the weighted squared error within the specified bands is minimized.
+///
+/// `bands` contains pairs of band edges, in the same units as `fs`, between 0 and `fs / 2`,
+/// the bands must not overlap. `desired` contains the gain at each band edge, the desired
+/// response within a band is the line between the gains at its edges. The optional `weight`
+/// contains the relative weight of each band, its default is one for every band.
+///
+/// Analog sampling designs the filter for the normalized frequencies, with the Nyquist
+/// frequency at 1.
+///
+/// # Errors
+///
+/// Fails if `numtaps` is even, if `bands`, `desired` or `weight` are inconsistent or if a
+/// gain or a weight is negative
+///
+/// # References
+///
+/// Ivan Selesnick, Linear-Phase FIR Filter Design By Least Squares. OpenStax CNX.
+/// Aug 9, 2005. <http://cnx.org/contents/eb1ecb35-03a9-4610-ba87-41cd771c95f2@7>
+pub fn firls<T: Float + FloatConst>(
+    numtaps: usize,
+    bands: impl Into<Array1<T>>,
+    desired: impl Into<Array1<T>>,
+    weight: impl Into<Option<Array1<T>>>,
+    sampling: GenericSampling<T>,
+) -> Result<GenericFilterOutput<T>, Error> {
+    if numtaps.is_multiple_of(2) {
+        return Err(Error::NumTaps(numtaps));
+    }
+    let nyq = match sampling {
+        GenericSampling::Digital { fs } => fs.to_f64().unwrap_or(f64::NAN) / 2.0,
+        GenericSampling::Analog => 1.0,
+    };
+    let to_f64 = |a: &T| a.to_f64().unwrap_or(f64::NAN);
+    let bands: Vec<f64> = bands.into().iter().map(|f| to_f64(f) / nyq).collect();
+    let desired: Vec<f64> = desired.into().iter().map(to_f64).collect();
+
+    if bands.len() % 2 == 1 {
+        return Err(Error::BandEdges(bands.len()));
+    }
+    if bands.iter().any(|f| f.is_nan() || *f < 0.0 || *f > 1.0) {
+        return Err(Error::Range);
+    }
+    if desired.len() != bands.len() {
+        return Err(Error::Desired {
+            desired: desired.len(),
+            edges: bands.len(),
+        });
+    }
+    let bands: Vec<(f64, f64)> = bands.chunks(2).map(|b| (b[0], b[1])).collect();
+    let desired: Vec<(f64, f64)> = desired.chunks(2).map(|d| (d[0], d[1])).collect();
+    if bands.iter().any(|(low, high)| high <= low) || bands.windows(2).any(|b| b[1].0 < b[0].0) {
+        return Err(Error::Monotonic);
+    }
+    if bands.windows(2).any(|b| b[0].1 > b[1].0) {
+        return Err(Error::Overlap);
+    }
+    if desired
+        .iter()
+        .any(|(d1, d2)| d1.is_nan() || d2.is_nan() || *d1 < 0.0 || *d2 < 0.0)
+    {
+        return Err(Error::NegativeDesired);
+    }
+    let weight: Vec<f64> = weight.into().map_or_else(
+        || vec![1.0; bands.len()],
+        |w| w.iter().map(to_f64).collect(),
+    );
+    if weight.len() != bands.len() {
+        return Err(Error::Weight {
+            weight: weight.len(),
+            bands: bands.len(),
+        });
+    }
+    if weight.iter().any(|w| w.is_nan() || *w < 0.0) {
+        return Err(Error::NegativeWeight);
+    }
+
+    let m = (numtaps - 1) / 2;
+    let pi = std::f64::consts::PI;
+    let sinc = |x: f64| {
+        if x == 0.0 {
+            1.0
+        } else {
+            (pi * x).sin() / (pi * x)
+        }
+    };
+    let integrate = |g: &dyn Fn(f64, f64, f64) -> f64| {
+        bands
+            .iter()
+            .zip(&desired)
+            .zip(&weight)
+            .map(|(((f1, f2), (d1, d2)), w)| {
+                let slope = (d2 - d1) / (f2 - f1);
+                let intercept = d1 - f1 * slope;
+                w * (g(*f2, slope, intercept) - g(*f1, slope, intercept))
+            })
+            .sum::<f64>()
+    };
+
+    // the normal equations are the sum of a Toeplitz and a Hankel matrix, q(n) is the integral
+    // of the weight times cos(pi n f), the common factors 1 / pi and 1 / 2 cancel out
+    let q: Vec<f64> = (0..numtaps)
+        .map(|n| integrate(&|f, _, _| f * sinc(f * n as f64)))
+        .collect();
+    let lhs = DMatrix::from_fn(m + 1, m + 1, |i, j| q[i.abs_diff(j)] + q[i + j]);
+
+    // integral of the weighted linear desired response times cos(pi n f)
+    let rhs = DVector::from_fn(m + 1, |n, _| {
+        let n = n as f64;
+        integrate(&|f, slope, intercept| {
+            let linear = f * slope.mul_add(f, intercept) * sinc(f * n);
+            if n == 0.0 {
+                linear - slope * f * f / 2.0
+            } else {
+                linear + slope * (n * pi * f).cos() / (pi * n).powi(2)
+            }
+        })
+    });
+
+    let a = solve(lhs, rhs);
+    let b: Array1<T> = a
+        .iter()
+        .skip(1)
+        .rev()
+        .chain(std::iter::once(&(2.0 * a[0])))
+        .chain(a.iter().skip(1))
+        .map(|a| T::from(*a).unwrap_or_else(T::nan))
+        .collect();
+
+    Ok(GenericFilterOutput::Ba(GenericBa {
+        b: b.mapv(Into::into),
+        a: Array1::ones(1),
+    }))
+}
+
+/// solve the normal equations with the Cholesky decomposition, falling back to the least
+/// squares solution when the matrix is rank deficient
+fn solve(lhs: DMatrix<f64>, rhs: DVector<f64>) -> DVector<f64> {
+    if let Some(cholesky) = lhs.clone().cholesky() {
+        let a = cholesky.solve(&rhs);
+        if a.iter().all(|a| a.is_finite()) {
+            return a;
+        }
+    }
+    let n = lhs.nrows();
+    let svd = lhs.svd(true, true);
+    let eps = svd.singular_values.max() * f64::EPSILON * n as f64;
+    svd.solve(&rhs, eps)
+        .expect("u and v are computed and eps is positive")
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("numtaps must be odd, received {0}")]
+    NumTaps(usize),
+    #[error("bands must contain frequency pairs, received {0} band edges")]
+    BandEdges(usize),
+    #[error("bands must be between 0 and fs / 2")]
+    Range,
+    #[error("desired must have one entry per band edge, received {desired} gains for {edges} band edges")]
+    Desired { desired: usize, edges: usize },
+    #[error("bands must be monotonically nondecreasing and have width > 0")]
+    Monotonic,
+    #[error("bands must not overlap")]
+    Overlap,
+    #[error("desired must be non-negative")]
+    NegativeDesired,
+    #[error("weight must have one entry per band, received {weight} weights for {bands} bands")]
+    Weight { weight: usize, bands: usize },
+    #[error("weight must be non-negative")]
+    NegativeWeight,
+}
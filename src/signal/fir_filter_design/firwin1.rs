@@ -3,7 +3,7 @@ use crate::signal::output_type::{Ba, FilterOutput, GenericBa, GenericFilterOutpu
 use crate::signal::{BandFilter, GenericSampling, Sampling};
 use crate::special::sinc;
 use ndarray::{array, Array1};
-use num::Float;
+use num::{traits::FloatConst, Complex, Float, One};
 
 use super::windows::{get_window, WindowType};
 use super::{kaiser_atten, kaiser_beta, GenericFIRFilterSettings};
@@ -27,7 +27,7 @@ impl Firwin1Filter<f64> {
     }
 }
 
-pub fn firwin<T: Float>(
+pub fn firwin<T: Float + FloatConst>(
     numtaps: i64,
     cutoff: GenericBandFilter<T>,
     width: Option<T>,
@@ -112,3 +112,150 @@ pub fn firwin<T: Float>(
         b: h.mapv(Into::into),
     })
 }
+
+/// FIR filter design using the window method
+///
+/// From the given frequencies `freq` and corresponding gains `gain`, this function constructs
+/// an FIR filter with linear phase and (approximately) the given frequency response.
+///
+/// `freq` must be nondecreasing, start at 0 and end at the Nyquist frequency, a frequency may
+/// be repeated once to give a discontinuity in the gain. The desired response is linearly
+/// interpolated on a uniform grid of `nfreqs` points, which defaults to one more than the
+/// smallest power of two not less than `numtaps`.
+///
+/// If `antisymmetric` is `false` the filter is of type I (odd `numtaps`) or II (even
+/// `numtaps`), otherwise of type III (odd `numtaps`) or IV (even `numtaps`). Type II filters
+/// must have zero gain at the Nyquist frequency, type III filters zero gain at 0 and at the
+/// Nyquist frequency and type IV filters zero gain at 0.
+///
+/// # Panics
+///
+/// Panics if `numtaps` is less than 3, if `nfreqs` is not greater than `numtaps`, if `freq` and
+/// `gain` have different lengths, if `freq` is not a valid breakpoint sequence or if `gain`
+/// does not vanish where the filter type requires it
+pub fn firwin2<T: Float + FloatConst>(
+    numtaps: i64,
+    freq: impl Into<Array1<T>>,
+    gain: impl Into<Array1<T>>,
+    nfreqs: Option<usize>,
+    antisymmetric: bool,
+    window: WindowType<T>,
+    sampling: GenericSampling<T>,
+) -> GenericFilterOutput<T> {
+    let nyq = match sampling {
+        GenericSampling::Digital { fs } => T::from(0.5).unwrap() * fs,
+        GenericSampling::Analog => T::one(),
+    };
+    let mut freq = freq.into();
+    let gain = gain.into();
+
+    if freq.len() != gain.len() {
+        panic!("freq and gain must be of same length.");
+    }
+    if numtaps < 3 {
+        panic!("numtaps must be greater than or equal to 3.");
+    }
+    let numtaps = numtaps as usize;
+    let nfreqs = nfreqs.unwrap_or_else(|| 1 + numtaps.next_power_of_two());
+    if nfreqs <= numtaps {
+        panic!("ntaps must be less than nfreqs");
+    }
+
+    let size = freq.len();
+    if size < 2 || freq[0] != T::zero() || freq[size - 1] != nyq {
+        panic!("freq must start with 0 and end with fs/2.");
+    }
+    if freq.windows(2).into_iter().any(|f| f[1] < f[0]) {
+        panic!("The values in freq must be nondecreasing.");
+    }
+    if freq.windows(3).into_iter().any(|f| f[0] == f[2]) {
+        panic!("A value in freq must not occur more than twice.");
+    }
+    if freq[1] == T::zero() {
+        panic!("Value 0 must not be repeated in freq.");
+    }
+    if freq[size - 2] == nyq {
+        panic!("Value fs/2 must not be repeated in freq.");
+    }
+
+    let odd = numtaps % 2 == 1;
+    let (first, last) = (gain[0], gain[size - 1]);
+    match (antisymmetric, odd) {
+        (false, false) if last != T::zero() => {
+            panic!("A Type II filter must have zero gain at the Nyquist frequency.")
+        }
+        (true, true) if first != T::zero() || last != T::zero() => {
+            panic!("A Type III filter must have zero gain at zero and Nyquist frequencies.")
+        }
+        (true, false) if first != T::zero() => {
+            panic!("A Type IV filter must have zero gain at zero frequency.")
+        }
+        _ => {}
+    }
+
+    // separate repeated frequencies so that the interpolation is well defined
+    let eps = T::epsilon() * nyq;
+    for k in 0..size - 1 {
+        if freq[k] == freq[k + 1] {
+            freq[k] = freq[k] - eps;
+            freq[k + 1] = freq[k + 1] + eps;
+        }
+    }
+    if freq.windows(2).into_iter().any(|f| f[1] <= f[0]) {
+        panic!("freq cannot contain numbers that are too close to a repeated value.");
+    }
+
+    // desired response on a uniform grid, delayed so that the first numtaps samples of the
+    // inverse transform are the filter coefficients
+    let delay = T::from(numtaps - 1).unwrap() / T::from(2).unwrap();
+    let rotation = if antisymmetric {
+        Complex::i()
+    } else {
+        Complex::one()
+    };
+    let fx = Array1::linspace(T::zero(), nyq, nfreqs).mapv(|x| {
+        let phase = -delay * T::PI() * x / nyq;
+        Complex::from_polar(interp(x, &freq, &gain), phase) * rotation
+    });
+
+    // first numtaps samples of the inverse real FFT of length 2 (nfreqs - 1)
+    let n = 2 * (nfreqs - 1);
+    let two = T::from(2).unwrap();
+    let h = (0..numtaps).map(|t| {
+        let nyquist = if t % 2 == 0 {
+            fx[nfreqs - 1].re
+        } else {
+            -fx[nfreqs - 1].re
+        };
+        let sum = (1..nfreqs - 1).fold(fx[0].re + nyquist, |acc, k| {
+            let phase = T::TAU() * T::from(k * t % n).unwrap() / T::from(n).unwrap();
+            acc + two * (fx[k] * Complex::from_polar(T::one(), phase)).re
+        });
+        sum / T::from(n).unwrap()
+    });
+
+    let win = get_window(window, numtaps as _, false);
+    let mut h = h.collect::<Array1<T>>() * win;
+    if antisymmetric && odd {
+        h[numtaps / 2] = T::zero();
+    }
+
+    GenericFilterOutput::Ba(GenericBa {
+        a: array![T::one()].mapv(Into::into),
+        b: h.mapv(Into::into),
+    })
+}
+
+/// piecewise linear interpolation of the points `(xp, fp)` at `x`, `xp` must be increasing
+fn interp<T: Float>(x: T, xp: &Array1<T>, fp: &Array1<T>) -> T {
+    let last = xp.len() - 1;
+    if x <= xp[0] {
+        return fp[0];
+    }
+    if x >= xp[last] {
+        return fp[last];
+    }
+    let k = xp.iter().position(|xp| *xp > x).unwrap_or(last);
+    let t = (x - xp[k - 1]) / (xp[k] - xp[k - 1]);
+    fp[k - 1] + t * (fp[k] - fp[k - 1])
+}
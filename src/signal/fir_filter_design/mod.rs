@@ -1,5 +1,7 @@
+pub mod firls;
 pub mod firwin1;
 mod pass_zero;
+pub mod remez;
 mod tools;
 pub mod windows;
 
@@ -17,4 +19,6 @@ pub struct GenericFIRFilterSettings<T> {
     pub sampling: GenericSampling<T>,
 }
 
+pub use firls::firls;
 pub use firwin1::*;
+pub use remez::{remez, RemezType};
@@ -0,0 +1,699 @@
+use ndarray::Array1;
+use num::{traits::FloatConst, Float};
+use thiserror::Error;
+
+use crate::signal::{
+    output_type::{GenericBa, GenericFilterOutput},
+    GenericSampling,
+};
+
+/// Kind of filter designed by [`remez`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RemezType {
+    /// even symmetric filter (type I or II) with a constant gain in each band
+    #[default]
+    Bandpass,
+    /// odd symmetric filter (type III or IV), the desired gain of each band is scaled by the
+    /// frequency and its weight is inversely proportional to the frequency
+    Differentiator,
+    /// odd symmetric filter (type III or IV)
+    Hilbert,
+}
+
+/// Calculate the minimax optimal filter using the Remez exchange algorithm
+///
+/// Calculate the coefficients of the finite impulse response (FIR) filter whose transfer
+/// function minimizes the maximum weighted error between the desired gain and the realized
+/// gain in the specified frequency bands, using the Parks-McClellan algorithm.
+///
+/// `bands` contains the band edges, in the same units as `fs`, there must be at least one
+/// band and the edges must be nonnegative, monotonic and not exceed `fs / 2`. `desired`
+/// contains the gain of each band and the optional `weight` the relative weight of the error
+/// in each band, its default is one for every band.
+///
+/// `maxiter` is the maximum number of exchange iterations, default 25, and `grid_density` is
+/// the density of the frequency grid, which has roughly `2 * numtaps * grid_density` points,
+/// default 16.
+///
+/// Analog sampling designs the filter for the normalized frequencies, with the Nyquist
+/// frequency at 1.
+///
+/// # Errors
+///
+/// Fails if the arguments are inconsistent or if the algorithm does not converge within
+/// `maxiter` iterations, in which case reducing the transition band widths usually helps
+///
+/// # References
+///
+/// J. H. McClellan and T. W. Parks, "A unified approach to the design of optimum FIR linear
+/// phase digital filters", IEEE Trans. Circuit Theory, vol. CT-20, pp. 697-701, 1973.
+#[allow(clippy::too_many_arguments)]
+pub fn remez<T: Float + FloatConst>(
+    numtaps: usize,
+    bands: impl Into<Array1<T>>,
+    desired: impl Into<Array1<T>>,
+    weight: impl Into<Option<Array1<T>>>,
+    remez_type: RemezType,
+    maxiter: impl Into<Option<usize>>,
+    grid_density: impl Into<Option<usize>>,
+    sampling: GenericSampling<T>,
+) -> Result<GenericFilterOutput<T>, Error> {
+    let fs = match sampling {
+        GenericSampling::Digital { fs } => fs,
+        GenericSampling::Analog => T::from(2).unwrap(),
+    };
+    let bands = bands.into();
+    let desired = desired.into();
+    let weight = weight.into().unwrap_or_else(|| Array1::ones(desired.len()));
+
+    if bands.is_empty() || bands.len() % 2 == 1 {
+        return Err(Error::BandEdges(bands.len()));
+    }
+    let numbands = bands.len() / 2;
+    if desired.len() != numbands {
+        return Err(Error::Desired {
+            desired: desired.len(),
+            bands: numbands,
+        });
+    }
+    if weight.len() != numbands {
+        return Err(Error::Weight {
+            weight: weight.len(),
+            bands: numbands,
+        });
+    }
+    let mut last = T::zero();
+    for edge in bands.iter() {
+        if edge.is_nan() || *edge < last {
+            return Err(Error::Monotonic);
+        }
+        if *edge * T::from(2).unwrap() > fs {
+            return Err(Error::Nyquist);
+        }
+        last = *edge;
+    }
+    let bands = bands.mapv(|edge| edge / fs);
+
+    let symmetric = remez_type == RemezType::Bandpass;
+    let odd = numtaps % 2 == 1;
+    let r = numtaps / 2 + usize::from(odd && symmetric);
+    if r == 0 {
+        return Err(Error::NumTaps(numtaps));
+    }
+
+    let maxiter = maxiter.into().unwrap_or(25);
+    let grid_density = grid_density.into().unwrap_or(16);
+    let mut grid = dense_grid(
+        r,
+        numtaps,
+        &bands,
+        &desired,
+        &weight,
+        remez_type,
+        grid_density,
+    );
+    if grid.frequency.len() <= r {
+        return Err(Error::Grid {
+            size: grid.frequency.len(),
+            extrema: r + 1,
+        });
+    }
+
+    // the response of even length or odd symmetric filters is a cosine series times a fixed
+    // factor, the approximation is done on the cosine series
+    let factor = |f: T| match (symmetric, odd) {
+        (true, true) => T::one(),
+        (true, false) => (T::PI() * f).cos(),
+        (false, true) => (T::TAU() * f).sin(),
+        (false, false) => (T::PI() * f).sin(),
+    };
+    if !(symmetric && odd) {
+        for ((f, d), w) in grid
+            .frequency
+            .iter()
+            .zip(&mut grid.desired)
+            .zip(&mut grid.weight)
+        {
+            let c = factor(*f);
+            *d = *d / c;
+            *w = *w * c;
+        }
+    }
+
+    let approximation = exchange(&grid, r, maxiter)?;
+
+    // sample the optimal response and recover the coefficients
+    let n = T::from(numtaps).unwrap();
+    let taps: Vec<T> = (0..=numtaps / 2)
+        .map(|i| {
+            let f = T::from(i).unwrap() / n;
+            approximation.evaluate(f) * factor(f)
+        })
+        .collect();
+    let h = frequency_sample(numtaps, &taps, symmetric);
+
+    Ok(GenericFilterOutput::Ba(GenericBa {
+        b: h.mapv(Into::into),
+        a: Array1::ones(1),
+    }))
+}
+
+/// desired gain and weight on the dense frequency grid, frequencies are normalized to the
+/// sampling frequency
+struct Grid<T> {
+    frequency: Vec<T>,
+    desired: Vec<T>,
+    weight: Vec<T>,
+}
+
+/// sample the bands every `0.5 / (grid_density * r)`, the last point of each band is moved to
+/// its upper edge, differentiators have a desired gain proportional to the frequency and the
+/// weight of the bands with nonzero gain is inversely proportional to the frequency
+fn dense_grid<T: Float>(
+    r: usize,
+    numtaps: usize,
+    bands: &Array1<T>,
+    desired: &Array1<T>,
+    weight: &Array1<T>,
+    remez_type: RemezType,
+    grid_density: usize,
+) -> Grid<T> {
+    let delf = T::from(0.5).unwrap() / T::from(grid_density * r).unwrap();
+    let symmetric = remez_type == RemezType::Bandpass;
+    let differentiator = remez_type == RemezType::Differentiator;
+    let desired_at = |f: T, band: usize| {
+        if differentiator {
+            desired[band] * f
+        } else {
+            desired[band]
+        }
+    };
+    let weight_at = |f: T, band: usize| {
+        if differentiator && desired[band] >= T::from(1e-4).unwrap() {
+            weight[band] / f
+        } else {
+            weight[band]
+        }
+    };
+
+    let mut grid = Grid {
+        frequency: vec![],
+        desired: vec![],
+        weight: vec![],
+    };
+    // odd symmetric filters vanish at 0, the grid starts one step later
+    let mut f = if !symmetric && bands[0] < delf {
+        delf
+    } else {
+        bands[0]
+    };
+    for band in 0..desired.len() {
+        let high = bands[2 * band + 1];
+        // every band has at least one point
+        loop {
+            grid.frequency.push(f);
+            grid.desired.push(desired_at(f, band));
+            grid.weight.push(weight_at(f, band));
+            f = f + delf;
+            if f > high {
+                break;
+            }
+        }
+        let last = grid.frequency.len() - 1;
+        grid.frequency[last] = high;
+        grid.desired[last] = desired_at(high, band);
+        grid.weight[last] = weight_at(high, band);
+
+        if band + 1 < desired.len() {
+            f = bands[2 * band + 2];
+        }
+    }
+
+    // odd symmetric filters of odd length also vanish at the Nyquist frequency
+    let odd = numtaps % 2 == 1;
+    if !symmetric && odd && grid.frequency[grid.frequency.len() - 1] > T::from(0.5).unwrap() - delf
+    {
+        grid.frequency.pop();
+        grid.desired.pop();
+        grid.weight.pop();
+    }
+    grid
+}
+
+/// barycentric form of the trigonometric polynomial interpolating the grid at the extremal
+/// frequencies with alternating error
+struct Approximation<T> {
+    ad: Vec<T>,
+    x: Vec<T>,
+    y: Vec<T>,
+    /// absolute value of the deviation at the extremal frequencies
+    deviation: T,
+    /// sign of the error at the first extremal frequency
+    sign: T,
+}
+
+impl<T: Float + FloatConst> Approximation<T> {
+    fn new(extrema: &[usize], grid: &Grid<T>) -> Self {
+        let r = extrema.len() - 1;
+        let x: Vec<T> = extrema
+            .iter()
+            .map(|e| (T::TAU() * grid.frequency[*e]).cos())
+            .collect();
+
+        // the products are interleaved to avoid overflow and underflow
+        let ld = (r - 1) / 15 + 1;
+        let ad: Vec<T> = (0..=r)
+            .map(|i| {
+                let mut denom = T::one();
+                for j in 0..ld {
+                    for k in (j..=r).step_by(ld).filter(|k| *k != i) {
+                        denom = denom * T::from(2).unwrap() * (x[i] - x[k]);
+                    }
+                }
+                denom.recip()
+            })
+            .collect();
+
+        let alternate = |i: usize| {
+            if i.is_multiple_of(2) {
+                T::one()
+            } else {
+                -T::one()
+            }
+        };
+        let (numer, denom) =
+            extrema
+                .iter()
+                .enumerate()
+                .fold((T::zero(), T::zero()), |(numer, denom), (i, e)| {
+                    (
+                        numer + ad[i] * grid.desired[*e],
+                        denom + alternate(i) * ad[i] / grid.weight[*e],
+                    )
+                });
+        let delta = numer / denom;
+        let sign = if delta > T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        let deviation = delta.abs();
+
+        let y = extrema
+            .iter()
+            .enumerate()
+            .map(|(i, e)| grid.desired[*e] + sign * alternate(i) * deviation / grid.weight[*e])
+            .collect();
+
+        Self {
+            ad,
+            x,
+            y,
+            deviation,
+            sign,
+        }
+    }
+
+    fn evaluate(&self, frequency: T) -> T {
+        let xc = (T::TAU() * frequency).cos();
+        let mut numer = T::zero();
+        let mut denom = T::zero();
+        for ((ad, x), y) in self.ad.iter().zip(&self.x).zip(&self.y) {
+            let c = xc - *x;
+            if c.abs() < T::from(1e-7).unwrap() {
+                return *y;
+            }
+            let c = *ad / c;
+            denom = denom + c;
+            numer = numer + c * *y;
+        }
+        numer / denom
+    }
+}
+
+/// labels of the extremal frequency search of McClellan, Parks and Rabiner
+#[derive(Clone, Copy)]
+enum Step {
+    Iterate,
+    Next,
+    Up,
+    Rise,
+    Down,
+    Descend,
+    Fall,
+    Found,
+    Retry,
+    Climb,
+    Keep,
+    Ends,
+    BelowFirst,
+    NoneBelow,
+    AboveLast,
+    ScanAbove,
+    Shift,
+    DropFirst,
+}
+
+/// Remez exchange on the dense grid, the `r + 1` extremal frequencies start equally spaced
+/// on the grid and are searched for as in the original Fortran program, the extrema missed
+/// below the first and above the last extremal frequency replace the smallest end one, the
+/// exchange stops once the extremal set is unchanged
+fn exchange<T: Float + FloatConst>(
+    grid: &Grid<T>,
+    r: usize,
+    maxiter: usize,
+) -> Result<Approximation<T>, Error> {
+    // the indices follow the original program, extremal frequencies and grid points start at 1
+    let ngrid = grid.frequency.len();
+    let (nz, nzz) = (r + 1, r + 2);
+    let error = |approximation: &Approximation<T>, l: usize| {
+        (approximation.evaluate(grid.frequency[l - 1]) - grid.desired[l - 1]) * grid.weight[l - 1]
+    };
+
+    let mut iext = vec![0; nzz + 1];
+    let spacing = (ngrid - 1) as f64 / r as f64;
+    for (j, e) in iext.iter_mut().enumerate().take(nz).skip(1) {
+        *e = ((j - 1) as f64 * spacing) as usize + 1;
+    }
+    iext[nz] = ngrid;
+
+    let one = T::one();
+    let margin = T::from(1.00001).unwrap();
+    let mut previous = -one;
+    let mut current: Option<Approximation<T>> = None;
+    let mut niter = 0;
+    let (mut j, mut l, mut kup, mut klow, mut k1, mut knz) = (0, 0, 0, 0, 0, 0);
+    let (mut nut, mut nut1, mut nu) = (one, one, one);
+    let (mut comp, mut y1, mut ynz, mut err) = (T::zero(), T::zero(), T::zero(), T::zero());
+    let mut luck = 0;
+    let mut changes = 0;
+
+    let mut step = Step::Iterate;
+    loop {
+        let approximation = || {
+            current
+                .as_ref()
+                .expect("the approximation is computed first")
+        };
+        step = match step {
+            Step::Iterate => {
+                iext[nzz] = ngrid + 1;
+                niter += 1;
+                if niter > maxiter {
+                    return Err(Error::NotConverged {
+                        iterations: maxiter,
+                    });
+                }
+                let indices: Vec<usize> = iext[1..=nz].iter().map(|e| e - 1).collect();
+                let next = Approximation::new(&indices, grid);
+
+                // the deviation increases at each exchange, unless rounding errors dominate
+                if next.deviation <= previous {
+                    return Err(Error::NotConverged { iterations: niter });
+                }
+                previous = next.deviation;
+                nu = next.sign;
+                comp = next.deviation;
+                current = Some(next);
+
+                changes = 0;
+                k1 = iext[1];
+                knz = iext[nz];
+                klow = 0;
+                nut = -nu;
+                j = 1;
+                Step::Next
+            }
+            Step::Next => {
+                if j == nzz {
+                    ynz = comp;
+                }
+                if j >= nzz {
+                    Step::Ends
+                } else {
+                    kup = iext[j + 1];
+                    l = iext[j] + 1;
+                    nut = -nut;
+                    if j == 2 {
+                        y1 = comp;
+                    }
+                    comp = approximation().deviation;
+                    if l >= kup {
+                        Step::Down
+                    } else {
+                        err = error(approximation(), l);
+                        if nut * err - comp <= T::zero() {
+                            Step::Down
+                        } else {
+                            comp = nut * err;
+                            Step::Up
+                        }
+                    }
+                }
+            }
+            Step::Up => {
+                l += 1;
+                if l >= kup {
+                    Step::Rise
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp <= T::zero() {
+                        Step::Rise
+                    } else {
+                        comp = nut * err;
+                        Step::Up
+                    }
+                }
+            }
+            Step::Rise => {
+                iext[j] = l - 1;
+                j += 1;
+                klow = l - 1;
+                changes += 1;
+                Step::Next
+            }
+            Step::Down => {
+                l -= 1;
+                Step::Descend
+            }
+            Step::Descend => {
+                l -= 1;
+                if l <= klow {
+                    Step::Retry
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp > T::zero() {
+                        comp = nut * err;
+                        Step::Fall
+                    } else if changes == 0 {
+                        Step::Descend
+                    } else {
+                        Step::Keep
+                    }
+                }
+            }
+            Step::Fall => {
+                l -= 1;
+                if l <= klow {
+                    Step::Found
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp <= T::zero() {
+                        Step::Found
+                    } else {
+                        comp = nut * err;
+                        Step::Fall
+                    }
+                }
+            }
+            Step::Found => {
+                klow = iext[j];
+                iext[j] = l + 1;
+                j += 1;
+                changes += 1;
+                Step::Next
+            }
+            Step::Retry => {
+                l = iext[j] + 1;
+                if changes > 0 {
+                    Step::Rise
+                } else {
+                    Step::Climb
+                }
+            }
+            Step::Climb => {
+                l += 1;
+                if l >= kup {
+                    Step::Keep
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp <= T::zero() {
+                        Step::Climb
+                    } else {
+                        comp = nut * err;
+                        Step::Up
+                    }
+                }
+            }
+            Step::Keep => {
+                klow = iext[j];
+                j += 1;
+                Step::Next
+            }
+            Step::Ends => {
+                if j > nzz {
+                    Step::AboveLast
+                } else {
+                    k1 = k1.min(iext[1]);
+                    knz = knz.max(iext[nz]);
+                    nut1 = nut;
+                    nut = -nu;
+                    l = 0;
+                    kup = k1;
+                    comp = ynz * margin;
+                    luck = 1;
+                    Step::BelowFirst
+                }
+            }
+            Step::BelowFirst => {
+                l += 1;
+                if l >= kup {
+                    Step::NoneBelow
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp <= T::zero() {
+                        Step::BelowFirst
+                    } else {
+                        comp = nut * err;
+                        j = nzz;
+                        Step::Up
+                    }
+                }
+            }
+            Step::NoneBelow => {
+                luck = 6;
+                l = ngrid + 1;
+                klow = knz;
+                nut = -nut1;
+                comp = y1 * margin;
+                Step::ScanAbove
+            }
+            Step::AboveLast => {
+                if luck > 9 {
+                    Step::DropFirst
+                } else {
+                    y1 = y1.max(comp);
+                    k1 = iext[nzz];
+                    l = ngrid + 1;
+                    klow = knz;
+                    nut = -nut1;
+                    comp = y1 * margin;
+                    Step::ScanAbove
+                }
+            }
+            Step::ScanAbove => {
+                l -= 1;
+                if l <= klow {
+                    Step::Shift
+                } else {
+                    err = error(approximation(), l);
+                    if nut * err - comp <= T::zero() {
+                        Step::ScanAbove
+                    } else {
+                        j = nzz;
+                        comp = nut * err;
+                        luck += 10;
+                        Step::Fall
+                    }
+                }
+            }
+            Step::Shift => {
+                if luck == 6 {
+                    if changes > 0 {
+                        Step::Iterate
+                    } else {
+                        break;
+                    }
+                } else {
+                    // the extremum found below the first one replaces the last
+                    for j in 1..=r {
+                        iext[nzz - j] = iext[nz - j];
+                    }
+                    iext[1] = k1;
+                    Step::Iterate
+                }
+            }
+            Step::DropFirst => {
+                // the extremum found above the last one replaces the first
+                let kn = iext[nzz];
+                for j in 1..=r {
+                    iext[j] = iext[j + 1];
+                }
+                iext[nz] = kn;
+                Step::Iterate
+            }
+        };
+    }
+
+    Ok(current.expect("the approximation is computed first"))
+}
+
+/// coefficients of the filter from samples of its amplitude response at `k / numtaps`
+fn frequency_sample<T: Float + FloatConst>(
+    numtaps: usize,
+    taps: &[T],
+    symmetric: bool,
+) -> Array1<T> {
+    let n = T::from(numtaps).unwrap();
+    let m = (n - T::one()) / T::from(2).unwrap();
+    let two = T::from(2).unwrap();
+    let last = if numtaps % 2 == 1 {
+        (numtaps - 1) / 2
+    } else {
+        numtaps / 2 - 1
+    };
+
+    Array1::from_shape_fn(numtaps, |i| {
+        let offset = T::from(i).unwrap() - m;
+        let x = T::TAU() * offset / n;
+        let init = match (symmetric, numtaps % 2 == 1) {
+            (true, _) => taps[0],
+            (false, true) => T::zero(),
+            (false, false) => taps[numtaps / 2] * (T::PI() * offset).sin(),
+        };
+        let val = (1..=last).fold(init, |acc, k| {
+            let k_f = T::from(k).unwrap();
+            let basis = if symmetric {
+                (x * k_f).cos()
+            } else {
+                (x * k_f).sin()
+            };
+            acc + two * taps[k] * basis
+        });
+        val / n
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("the number of band edges must be even and nonzero, received {0}")]
+    BandEdges(usize),
+    #[error("the number of desired gains must equal the number of bands, received {desired} for {bands} bands")]
+    Desired { desired: usize, bands: usize },
+    #[error(
+        "the number of weights must equal the number of bands, received {weight} for {bands} bands"
+    )]
+    Weight { weight: usize, bands: usize },
+    #[error("band edges must be nonnegative and monotonic")]
+    Monotonic,
+    #[error("band edges must not exceed fs / 2")]
+    Nyquist,
+    #[error("numtaps is too small for the requested filter type, received {0}")]
+    NumTaps(usize),
+    #[error("the frequency grid has {size} points but {extrema} extremal frequencies are required, increase the grid density or the band widths")]
+    Grid { size: usize, extrema: usize },
+    #[error(
+        "failure to converge after {iterations} iterations, try reducing the transition band width"
+    )]
+    NotConverged { iterations: usize },
+}
@@ -1,7 +1,5 @@
-use ndarray::Array1;
-use ndarray::{array, s, NewAxis};
-use num::{Float, NumCast};
-use std::{f64::consts::PI, ops::Div};
+use ndarray::{array, s, Array1, Array2, ArrayView1, ArrayView2};
+use num::{traits::FloatConst, Float, NumCast, ToPrimitive};
 
 use crate::{if_len_guard, special::sinc};
 
@@ -112,8 +110,7 @@ impl<T: Float> WindowType<T> {
     }
 }
 
-// TODO! actually implement generic window selection
-pub fn get_window<T: Float>(
+pub fn get_window<T: Float + FloatConst>(
     window: WindowType<T>,
     nx: u64,
     fftbins: impl Into<Option<bool>>,
@@ -121,8 +118,7 @@ pub fn get_window<T: Float>(
     let fftbins = fftbins.into().unwrap_or(true);
     let m = nx;
     let sym = !fftbins;
-    let window = window.cast::<f64>();
-    let tmp: Array1<f64> = match window {
+    match window {
         WindowType::Boxcar => boxcar(m, sym),
         WindowType::Triang => triang(m, sym),
         WindowType::Blackman => blackman(m, sym),
@@ -145,10 +141,9 @@ pub fn get_window<T: Float>(
         WindowType::Gaussian { std_dev } => gaussian(m, std_dev, sym),
         WindowType::GeneralCosine { coeffs } => general_cosine(m, coeffs, sym),
         WindowType::GeneralGaussian { power, width } => general_gaussian(m, power, width, sym),
-        WindowType::Dpss { half_bandwidth } => todo!(),
-        WindowType::Chebwin { attenuation } => todo!(),
-    };
-    tmp.mapv(T::from).mapv(Option::unwrap)
+        WindowType::Dpss { half_bandwidth } => dpss(m, half_bandwidth, sym),
+        WindowType::Chebwin { attenuation } => chebwin(m, attenuation, sym),
+    }
 }
 
 pub fn boxcar<T: Float>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
@@ -164,29 +159,22 @@ pub fn boxcar<T: Float>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
     _boxcar(m, sym.into().unwrap_or(true))
 }
 
-pub fn triang(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _triang(m: u64, sym: bool) -> Array1<f64> {
+pub fn triang<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _triang<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = Array1::range(1.0, (m as f64 + 1.0).div(2.0).floor() + 1.0, 1.0);
+        let one = T::one();
+        let two = cast::<T>(2);
+        let n = Array1::range(one, cast::<T>(m.div_ceil(2)) + one, one);
 
-        let w = if m % 2 == 0 {
-            let w = (2.0 * n - 1.0) / (m as f64);
-            let mut reversed = w.to_vec();
-            reversed.reverse();
-            let mut w = w.to_vec();
-            w.extend(&reversed);
-            w
+        let w: Vec<T> = if m % 2 == 0 {
+            let w = n.mapv(|n| (two * n - one) / cast(m));
+            w.iter().chain(w.iter().rev()).copied().collect()
         } else {
-            let w = 2.0 * n / ((m as f64) + 1.0);
-            let mut reversed = w.to_vec();
-            reversed.remove(reversed.len() - 1);
-            reversed.reverse();
-            let mut w = w.to_vec();
-            w.extend(&reversed);
-            w
+            let w = n.mapv(|n| two * n / (cast::<T>(m) + one));
+            w.iter().chain(w.iter().rev().skip(1)).copied().collect()
         };
 
         truncate(w, needs_trunc)
@@ -195,82 +183,88 @@ pub fn triang(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
     _triang(m, sym.into().unwrap_or(true))
 }
 
-pub fn general_cosine(
+pub fn general_cosine<T: Float + FloatConst>(
     m: u64,
-    a: impl Into<Array1<f64>>,
+    a: impl Into<Array1<T>>,
     sym: impl Into<Option<bool>>,
-) -> Array1<f64> {
+) -> Array1<T> {
     let sym = sym.into().unwrap_or(true);
-    fn _general_cosine(m: u64, a: Array1<f64>, sym: bool) -> Array1<f64> {
+    fn _general_cosine<T: Float + FloatConst>(m: u64, a: Array1<T>, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let fac = Array1::linspace(-PI, PI, m as _);
-        let mut w = Array1::<f64>::zeros((m as _,));
+        let fac = Array1::linspace(-T::PI(), T::PI(), m as _);
+        let mut w = Array1::<T>::zeros((m as _,));
 
         for (k, a) in a.iter().enumerate() {
-            w = w + fac.map(|e| (e * (k as f64)).cos()) * (*a);
+            w = w + fac.mapv(|e| (e * cast(k)).cos() * *a);
         }
         truncate(w, needs_trunc)
     }
     _general_cosine(m, a.into(), sym)
 }
 
-pub fn blackman(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
+pub fn blackman<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
     let sym = sym.into().unwrap_or(true);
 
-    fn _blackman(m: u64, sym: bool) -> Array1<f64> {
-        general_cosine(m, array![0.42, 0.50, 0.08], sym)
+    fn _blackman<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
+        general_cosine(m, array![0.42, 0.50, 0.08].mapv(cast), sym)
     }
 
     _blackman(m, sym)
 }
 
-pub fn general_hamming(m: u64, alpha: f64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _general_hamming(m: u64, alpha: f64, sym: bool) -> Array1<f64> {
-        general_cosine(m, array![alpha, 1.0 - alpha], sym)
+pub fn general_hamming<T: Float + FloatConst>(
+    m: u64,
+    alpha: T,
+    sym: impl Into<Option<bool>>,
+) -> Array1<T> {
+    fn _general_hamming<T: Float + FloatConst>(m: u64, alpha: T, sym: bool) -> Array1<T> {
+        general_cosine(m, array![alpha, T::one() - alpha], sym)
     }
 
     _general_hamming(m, alpha, sym.into().unwrap_or(true))
 }
 
-pub fn hamming(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _hamming(m: u64, sym: bool) -> Array1<f64> {
-        general_hamming(m, 0.54, sym)
+pub fn hamming<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _hamming<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
+        general_hamming(m, cast(0.54), sym)
     }
     _hamming(m, sym.into().unwrap_or(true))
 }
 
-pub fn hann(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _hann(m: u64, sym: bool) -> Array1<f64> {
-        general_hamming(m, 0.5, sym)
+pub fn hann<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _hann<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
+        general_hamming(m, cast(0.5), sym)
     }
     _hann(m, sym.into().unwrap_or(true))
 }
 
-pub fn bartlett(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _bartlett(m: u64, sym: bool) -> Array1<f64> {
+pub fn bartlett<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _bartlett<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = Array1::range(0.0, m as f64, 1.0);
-
-        let filter_map = n.map(|&a| a <= (m as f64 - 1.0) / 2.0);
-        let left = 2.0 * n.clone() / (m as f64 - 1.0);
-        let right = 2.0 - 2.0 * n / (m as f64 - 1.0);
-        let mut w = Array1::<f64>::zeros((m as usize,));
-        for (i, v) in filter_map.into_iter().enumerate() {
-            w[i] = if v { left[i] } else { right[i] };
-        }
+        let one = T::one();
+        let two = cast::<T>(2);
+        let m = cast::<T>(m);
+
+        let w = Array1::range(T::zero(), m, one).mapv(|n| {
+            if n <= (m - one) / two {
+                two * n / (m - one)
+            } else {
+                two - two * n / (m - one)
+            }
+        });
         truncate(w, needs_trunc)
     }
     _bartlett(m, sym.into().unwrap_or(true))
 }
 
-pub fn flattop(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _flattop(m: u64, sym: bool) -> Array1<f64> {
+pub fn flattop<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _flattop<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         let a = array![
             0.21557895,
             0.41663158,
@@ -278,7 +272,7 @@ pub fn flattop(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
             0.083578947,
             0.006947368
         ];
-        general_cosine(m, a, sym)
+        general_cosine(m, a.mapv(cast), sym)
     }
 
     _flattop(m, sym.into().unwrap_or(true))
@@ -298,112 +292,131 @@ fn extract<T: Clone>(condition: Array1<bool>, arr: Array1<T>) -> Array1<T> {
     result.into()
 }
 
-pub fn parzen(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _parzen(m: u64, sym: bool) -> Array1<f64> {
+pub fn parzen<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _parzen<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
-        let m = m as f64;
-        let n = Array1::range(-(m - 1.0) / 2.0, (m - 1.0) / 2.0 + 0.5, 1.0);
-        let na = extract(n.map(|&v| v < -(m - 1.0) / 4.0), n.clone());
-        let nb = extract(n.map(|&v| v.abs() <= (m - 1.0) / 4.0), n);
-        let wa = 2.0 * (1.0 - na.mapv(f64::abs) / (m / 2.0)).mapv(|v| v.powi(3));
-        let wb = 1.0 - 6.0 * (nb.mapv(f64::abs) / (m / 2.0)).mapv(|v| v.powi(2))
-            + 6.0 * (nb.mapv(f64::abs) / (m / 2.0)).mapv(|v| v.powi(3));
-        let mut w = wa.clone().to_vec();
+
+        let one = T::one();
+        let two = cast::<T>(2);
+        let four = cast::<T>(4);
+        let six = cast::<T>(6);
+        let m = cast::<T>(m);
+
+        let n = Array1::range(-(m - one) / two, (m - one) / two + cast(0.5), one);
+        let na = extract(n.mapv(|v| v < -(m - one) / four), n.clone());
+        let nb = extract(n.mapv(|v| v.abs() <= (m - one) / four), n);
+        let wa = na.mapv(|v| two * (one - v.abs() / (m / two)).powi(3));
+        let wb = nb.mapv(|v| {
+            let v = v.abs() / (m / two);
+            one - six * v.powi(2) + six * v.powi(3)
+        });
+        let mut w = wa.to_vec();
         w.extend(&wb);
-        w.extend(wa.to_vec().into_iter().rev());
+        w.extend(wa.iter().rev());
 
         truncate(w, needs_trunc)
     }
     _parzen(m, sym.into().unwrap_or(true))
 }
 #[allow(clippy::reversed_empty_ranges)]
-pub fn bohman(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _bohman(m: u64, sym: bool) -> Array1<f64> {
+pub fn bohman<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _bohman<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let fac = Array1::linspace(-1.0, 1.0, m as _)
+        let one = T::one();
+        let pi = T::PI();
+        let fac = Array1::linspace(-one, one, m as _)
             .slice(s![1..-1])
-            .mapv(f64::abs);
-        let temp = (1.0 - fac.clone()) * fac.mapv(|a| (PI * a).cos())
-            + 1.0 / PI * fac.mapv(|a| (PI * a).sin());
+            .mapv(T::abs);
+        let temp = fac.mapv(|a| (one - a) * (pi * a).cos() + one / pi * (pi * a).sin());
         let mut w = Vec::with_capacity(temp.len());
-        w.push(0.0);
+        w.push(T::zero());
         w.extend(temp);
-        w.push(0.0);
+        w.push(T::zero());
         truncate(w, needs_trunc)
     }
     _bohman(m, sym.into().unwrap_or(true))
 }
 
-pub fn blackmanharris(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _blackmanharris(m: u64, sym: bool) -> Array1<f64> {
-        general_cosine(m, vec![0.35875, 0.48829, 0.14128, 0.01168], sym)
+pub fn blackmanharris<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _blackmanharris<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
+        general_cosine(
+            m,
+            array![0.35875, 0.48829, 0.14128, 0.01168].mapv(cast),
+            sym,
+        )
     }
 
     _blackmanharris(m, sym.into().unwrap_or(true))
 }
 
-pub fn nuttall(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _nuttall(m: u64, sym: bool) -> Array1<f64> {
-        general_cosine(m, vec![0.3635819, 0.4891775, 0.1365995, 0.0106411], sym)
+pub fn nuttall<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _nuttall<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
+        general_cosine(
+            m,
+            array![0.3635819, 0.4891775, 0.1365995, 0.0106411].mapv(cast),
+            sym,
+        )
     }
 
     _nuttall(m, sym.into().unwrap_or(true))
 }
 
-pub fn barthann(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
+pub fn barthann<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
     let sym = sym.into().unwrap_or(true);
-    fn _barthann(m: u64, sym: bool) -> Array1<f64> {
+    fn _barthann<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = (0..m).map(|a| a as f64).collect::<Array1<f64>>();
-        let m = m as f64;
-        let fac = (n / (m - 1.0) - 0.5).mapv(|a| a.abs());
-        let w =
-            0.62 - 0.48 * fac.clone() + 0.38 * (2.0 * std::f64::consts::PI * fac).mapv(|a| a.cos());
+        let one = T::one();
+        let m = cast::<T>(m);
+        let w = Array1::range(T::zero(), m, one).mapv(|n| {
+            let fac = (n / (m - one) - cast(0.5)).abs();
+            cast::<T>(0.62) - cast::<T>(0.48) * fac + cast::<T>(0.38) * (T::TAU() * fac).cos()
+        });
         truncate(w, needs_trunc)
     }
     _barthann(m, sym)
 }
 
-pub fn cosine(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _cosine(m: u64, sym: bool) -> Array1<f64> {
+pub fn cosine<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _cosine<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        let w = Array1::range(0.0, m as _, 1.0) + 0.5;
-        let m = m as f64;
-        let w = (PI / m * w).mapv(f64::sin);
+        let m = cast::<T>(m);
+        let w =
+            Array1::range(T::zero(), m, T::one()).mapv(|n| (T::PI() / m * (n + cast(0.5))).sin());
 
         truncate(w, needs_trunc)
     }
     _cosine(m, sym.into().unwrap_or(true))
 }
 
-pub fn exponential(
+pub fn exponential<T: Float + FloatConst>(
     m: u64,
-    center: impl Into<Option<f64>>,
-    tau: impl Into<Option<f64>>,
+    center: impl Into<Option<T>>,
+    tau: impl Into<Option<T>>,
     sym: impl Into<Option<bool>>,
-) -> Array1<f64> {
-    fn _exponential(m: u64, center: Option<f64>, tau: f64, sym: bool) -> Array1<f64> {
+) -> Array1<T> {
+    fn _exponential<T: Float + FloatConst>(
+        m: u64,
+        center: Option<T>,
+        tau: T,
+        sym: bool,
+    ) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        let center = if let Some(center) = center {
-            center
-        } else {
-            ((m as f64) - 1.0) / 2.0
-        };
+        let center = center.unwrap_or_else(|| (cast::<T>(m) - T::one()) / cast(2));
 
-        let n = Array1::range(0.0, m as f64, 1.0);
-        let w = (-(n - center).mapv(f64::abs) / tau).mapv(f64::exp);
+        let n = Array1::range(T::zero(), cast(m), T::one());
+        let w = n.mapv(|n| (-(n - center).abs() / tau).exp());
 
         truncate(w, needs_trunc)
     }
@@ -411,38 +424,49 @@ pub fn exponential(
     _exponential(
         m,
         center.into(),
-        tau.into().unwrap_or(1.0),
+        tau.into().unwrap_or_else(T::one),
         sym.into().unwrap_or(true),
     )
 }
 
-pub fn tukey(m: u64, alpha: impl Into<Option<f64>>, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _tukey(m: u64, alpha: f64, sym: bool) -> Array1<f64> {
+pub fn tukey<T: Float + FloatConst>(
+    m: u64,
+    alpha: impl Into<Option<T>>,
+    sym: impl Into<Option<bool>>,
+) -> Array1<T> {
+    fn _tukey<T: Float + FloatConst>(m: u64, alpha: T, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
-        if alpha <= 0.0 {
+        if alpha <= T::zero() {
             return Array1::ones((m as usize,));
-        } else if alpha >= 1.0 {
+        } else if alpha >= T::one() {
             return hann(m, sym);
         }
 
         let (m, needs_trunc) = extend(m, sym);
-        let m = m as f64;
-        let n = Array1::range(0.0, m, 1.0);
 
-        let width = (alpha * (m - 1.0) / 2.0).floor() as usize;
+        let one = T::one();
+        let two = cast::<T>(2);
+        let half = cast::<T>(0.5);
+        let pi = T::PI();
+        let len = m as usize;
+        let m = cast::<T>(m);
+        let n = Array1::range(T::zero(), m, one);
+
+        let width = (alpha * (m - one) / two)
+            .floor()
+            .to_usize()
+            .unwrap_or_default();
 
         let n1 = n.slice(s![0..(width + 1)]);
-        let n2 = n.slice(s![(width + 1)..((m as usize) - width - 1)]);
-        let n3 = n.slice(s![(m as usize - width - 1)..]);
+        let n2 = n.slice(s![(width + 1)..(len - width - 1)]);
+        let n3 = n.slice(s![(len - width - 1)..]);
 
-        let w1 =
-            0.5 * (1.0 + (PI * (-1.0 + 2.0 * n1.to_owned() / alpha / (m - 1.0))).mapv(f64::cos));
-        let w2 = Array1::<f64>::ones((n2.len(),));
-        let w3 = 0.5
-            * (1.0
-                + (PI * (-2.0 / alpha + 1.0 + 2.0 * n3.to_owned() / alpha / (m - 1.0)))
-                    .mapv(f64::cos));
+        let w1 = n1.mapv(|n| half * (one + (pi * (-one + two * n / alpha / (m - one))).cos()));
+        let w2 = Array1::<T>::ones((n2.len(),));
+        let w3 = n3.mapv(|n| {
+            half * (one + (pi * (-two / alpha + one + two * n / alpha / (m - one))).cos())
+        });
 
         let mut w = Vec::with_capacity(w1.len() + w2.len() + w3.len());
         w.extend(w1);
@@ -451,49 +475,64 @@ pub fn tukey(m: u64, alpha: impl Into<Option<f64>>, sym: impl Into<Option<bool>>
         truncate(w, needs_trunc)
     }
 
-    _tukey(m, alpha.into().unwrap_or(0.5), sym.into().unwrap_or(true))
+    _tukey(
+        m,
+        alpha.into().unwrap_or_else(|| cast(0.5)),
+        sym.into().unwrap_or(true),
+    )
 }
 
-pub fn taylor(
+pub fn taylor<T: Float + FloatConst>(
     m: u64,
     nbar: impl Into<Option<u64>>,
-    sll: impl Into<Option<f64>>,
+    sll: impl Into<Option<T>>,
     norm: impl Into<Option<bool>>,
     sym: impl Into<Option<bool>>,
-) -> Array1<f64> {
-    fn _taylor(m: u64, nbar: u64, sll: f64, _norm: bool, sym: bool) -> Array1<f64> {
+) -> Array1<T> {
+    fn _taylor<T: Float + FloatConst>(
+        m: u64,
+        nbar: u64,
+        sll: T,
+        _norm: bool,
+        sym: bool,
+    ) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        let b = 10.0_f64.powf(sll / 20.0);
-        let a = b.acosh() / PI;
-        let nbar = nbar as f64;
-        let s2 = nbar.powi(2) / (a.powi(2) + (nbar - 0.5).powi(2));
-        let ma = Array1::range(1.0, nbar, 1.0);
-        let mut fm = Array1::<f64>::zeros((nbar as usize - 1,));
+        let one = T::one();
+        let two = cast::<T>(2);
+        let half = cast::<T>(0.5);
+        let m = cast::<T>(m);
+
+        let b = cast::<T>(10).powf(sll / cast(20));
+        let a = b.acosh() / T::PI();
+        let nbar_f = cast::<T>(nbar);
+        let s2 = nbar_f.powi(2) / (a.powi(2) + (nbar_f - half).powi(2));
+        let ma = Array1::range(one, nbar_f, one);
+        let mut fm = Array1::<T>::zeros((nbar as usize - 1,));
         let signs =
-            Array1::<f64>::from_shape_fn(ma.raw_dim(), |i| if i % 2 == 0 { 1.0 } else { -1.0 });
-        let m2 = ma.clone() * ma.clone();
+            Array1::<T>::from_shape_fn(ma.raw_dim(), |i| if i % 2 == 0 { one } else { -one });
+        let m2 = ma.mapv(|a| a * a);
 
         for (mi, _) in ma.iter().enumerate() {
             let numer = signs[mi]
-                * (1.0 - m2[mi] / s2 / (a.powi(2) + (ma.clone() - 0.5).mapv(|a| a.powi(2))))
+                * ma.mapv(|ma| one - m2[mi] / s2 / (a.powi(2) + (ma - half).powi(2)))
+                    .product();
+            let denom = two
+                * m2.slice(s![..mi]).mapv(|m2j| one - m2[mi] / m2j).product()
+                * m2.slice(s![mi + 1..])
+                    .mapv(|m2j| one - m2[mi] / m2j)
                     .product();
-            let denom = 2.0
-                * (1.0 - m2[mi] / m2.slice(s![..mi]).to_owned()).product()
-                * (1.0 - m2[mi] / m2.slice(s![mi + 1..]).to_owned()).product();
             fm[mi] = numer / denom;
         }
 
-        let w_f = |n: Array1<f64>| {
-            let rhs = 2.0 * PI * ma.slice(s![.., NewAxis]).to_owned() * (n - m as f64 / 2.0 + 0.5)
-                / m as f64;
-            let rhs = rhs.mapv(f64::cos);
-
-            1.0 + 2.0 * fm.dot(&rhs)
-        };
-
-        let w = w_f(Array1::range(0.0, m as f64, 1.0));
+        let w = Array1::range(T::zero(), m, one).mapv(|n| {
+            let n = n - m / two + half;
+            let sum = ma.iter().zip(fm.iter()).fold(T::zero(), |acc, (ma, fm)| {
+                acc + *fm * (T::TAU() * *ma * n / m).cos()
+            });
+            one + two * sum
+        });
 
         truncate(w, needs_trunc)
     }
@@ -501,33 +540,36 @@ pub fn taylor(
     _taylor(
         m,
         nbar.into().unwrap_or(4),
-        sll.into().unwrap_or(30.0),
+        sll.into().unwrap_or_else(|| cast(30)),
         norm.into().unwrap_or(true),
         sym.into().unwrap_or(true),
     )
 }
 
-pub fn lanczos(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _lanczos(m: u64, sym: bool) -> Array1<f64> {
+pub fn lanczos<T: Float + FloatConst>(m: u64, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _lanczos<T: Float + FloatConst>(m: u64, sym: bool) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        fn _calc_right_side(n: u64, m: u64) -> Array1<f64> {
-            let m = m as f64;
-            let n = n as f64;
-            sinc(2.0 * Array1::range(n, m, 1.0) / (m - 1.0) - 1.0)
+        fn _calc_right_side<T: Float>(n: u64, m: u64) -> Array1<T> {
+            let m = cast::<T>(m);
+            let n = cast::<T>(n);
+            sinc(
+                Array1::range(n, m, T::one())
+                    .mapv(|a| cast::<T>(2) * a / (m - T::one()) - T::one()),
+            )
         }
         let w = if m % 2 == 0 {
             let wh = _calc_right_side(m / 2, m);
-            let mut lhs = wh.clone().to_vec();
+            let mut lhs = wh.to_vec();
             lhs.reverse();
             lhs.extend(wh);
             lhs
         } else {
             let wh = _calc_right_side((m + 1) / 2, m);
-            let mut lhs = wh.clone().to_vec();
+            let mut lhs = wh.to_vec();
             lhs.reverse();
-            lhs.push(1.0);
+            lhs.push(T::one());
             lhs.extend(wh);
             lhs
         };
@@ -537,39 +579,41 @@ pub fn lanczos(m: u64, sym: impl Into<Option<bool>>) -> Array1<f64> {
     _lanczos(m, sym.into().unwrap_or(true))
 }
 
-pub fn kaiser(m: u64, beta: f64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _kaiser(m: u64, beta: f64, sym: bool) -> Array1<f64> {
+pub fn kaiser<T: Float + FloatConst>(m: u64, beta: T, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _kaiser<T: Float + FloatConst>(m: u64, beta: T, sym: bool) -> Array1<T> {
         if_len_guard!(m);
 
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = Array1::range(0.0, m as f64, 1.0);
-        let alpha = (m as f64 - 1.0) / 2.0;
-        let l = beta * (1.0 - ((n - alpha) / alpha).mapv(|a| a.powi(2))).mapv(|a| a.sqrt());
-        let l = crate::special::i0(l).unwrap();
-        let r = crate::special::i0(array![beta]).unwrap();
+        let one = T::one();
+        let n = Array1::range(T::zero(), cast(m), one);
+        let alpha = (cast::<T>(m) - one) / cast(2);
+        let l = n.mapv(|n| beta * (one - ((n - alpha) / alpha).powi(2)).sqrt());
 
-        let w = l / r;
-        let w = w.mapv(|a| a.norm());
+        let r = i0(beta);
+        let w = l.mapv(|l| i0(l) / r);
 
         truncate(w, needs_trunc)
     }
     _kaiser(m, beta, sym.into().unwrap_or(true))
 }
 
-pub fn kaiser_bessel_derived(m: u64, beta: f64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _kaiser_bessel_derived(m: u64, beta: f64, _sym: bool) -> Array1<f64> {
+pub fn kaiser_bessel_derived<T: Float + FloatConst>(
+    m: u64,
+    beta: T,
+    sym: impl Into<Option<bool>>,
+) -> Array1<T> {
+    fn _kaiser_bessel_derived<T: Float + FloatConst>(m: u64, beta: T, _sym: bool) -> Array1<T> {
         if m < 1 {
             return array![];
         } else if m % 2 == 1 {
             panic!("Kaiser-Bessel Derived windows are only defined for even number of points");
         }
 
-        let mut kaiser_window =
-            kaiser(((m as f64 / 2.0).floor() + 1.0) as u64, beta, true).to_vec();
-        let mut last_sum = 0.0;
+        let mut kaiser_window = kaiser(m / 2 + 1, beta, true).to_vec();
+        let mut last_sum = T::zero();
         for e in kaiser_window.iter_mut() {
-            *e += last_sum;
+            *e = *e + last_sum;
             last_sum = *e;
         }
         let half_window = kaiser_window
@@ -577,10 +621,10 @@ pub fn kaiser_bessel_derived(m: u64, beta: f64, sym: impl Into<Option<bool>>) ->
             .enumerate()
             .take_while(|(i, _)| *i != kaiser_window.len() - 1)
             .map(|(_, a)| *a)
-            .map(|a| (a / kaiser_window.last().unwrap()).sqrt());
+            .map(|a| (a / *kaiser_window.last().unwrap()).sqrt());
 
         let mut w = half_window.collect::<Vec<_>>();
-        let reversed: Vec<f64> = w.iter().copied().rev().collect();
+        let reversed: Vec<T> = w.iter().copied().rev().collect();
         w.extend(reversed);
 
         w.into()
@@ -588,15 +632,20 @@ pub fn kaiser_bessel_derived(m: u64, beta: f64, sym: impl Into<Option<bool>>) ->
     _kaiser_bessel_derived(m, beta, sym.into().unwrap_or(true))
 }
 
-pub fn gaussian(m: u64, std_dev: f64, sym: impl Into<Option<bool>>) -> Array1<f64> {
-    fn _gaussian(m: u64, std_dev: f64, sym: bool) -> Array1<f64> {
+pub fn gaussian<T: Float + FloatConst>(
+    m: u64,
+    std_dev: T,
+    sym: impl Into<Option<bool>>,
+) -> Array1<T> {
+    fn _gaussian<T: Float + FloatConst>(m: u64, std_dev: T, sym: bool) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = Array1::range(0.0, m as _, 1.0) - (m as f64 - 1.0) / 2.0;
-        let sig2 = 2.0 * std_dev * std_dev;
+        let center = (cast::<T>(m) - T::one()) / cast(2);
+        let sig2 = cast::<T>(2) * std_dev * std_dev;
 
-        let w = (-n.mapv(|a| a.powi(2)) / sig2).mapv(f64::exp);
+        let w = Array1::range(T::zero(), cast(m), T::one())
+            .mapv(|n| (-(n - center).powi(2) / sig2).exp());
 
         truncate(w, needs_trunc)
     }
@@ -604,23 +653,428 @@ pub fn gaussian(m: u64, std_dev: f64, sym: impl Into<Option<bool>>) -> Array1<f6
     _gaussian(m, std_dev, sym.into().unwrap_or(true))
 }
 
-pub fn general_gaussian(
+pub fn general_gaussian<T: Float + FloatConst>(
     m: u64,
-    power: f64,
-    width: f64,
+    power: T,
+    width: T,
     sym: impl Into<Option<bool>>,
-) -> Array1<f64> {
-    fn _general_gaussian(m: u64, power: f64, width: f64, sym: bool) -> Array1<f64> {
+) -> Array1<T> {
+    fn _general_gaussian<T: Float + FloatConst>(
+        m: u64,
+        power: T,
+        width: T,
+        sym: bool,
+    ) -> Array1<T> {
         if_len_guard!(m);
         let (m, needs_trunc) = extend(m, sym);
 
-        let n = Array1::range(0.0, m as _, 1.0) - (m as f64 - 1.0) / 2.0;
-        let w = (-0.5 * (n / width).mapv(f64::abs).mapv(|a| a.powf(2.0 * power))).mapv(f64::exp);
+        let center = (cast::<T>(m) - T::one()) / cast(2);
+        let w = Array1::range(T::zero(), cast(m), T::one()).mapv(|n| {
+            (cast::<T>(-0.5) * ((n - center) / width).abs().powf(cast::<T>(2) * power)).exp()
+        });
         truncate(w, needs_trunc)
     }
     _general_gaussian(m, power, width, sym.into().unwrap_or(true))
 }
 
+/// Dolph-Chebyshev window
+///
+/// the window minimizes the main lobe width for a sidelobe level `at` dB below the main lobe,
+/// the sidelobes are equiripple.
+pub fn chebwin<T: Float + FloatConst>(m: u64, at: T, sym: impl Into<Option<bool>>) -> Array1<T> {
+    fn _chebwin<T: Float + FloatConst>(m: u64, at: T, sym: bool) -> Array1<T> {
+        if_len_guard!(m);
+        let (m, needs_trunc) = extend(m, sym);
+
+        let one = T::one();
+        let pi = T::PI();
+        let mf = cast::<T>(m);
+        let order = mf - one;
+        let beta = ((cast::<T>(10).powf(at.abs() / cast(20))).acosh() / order).cosh();
+        let odd = m % 2 == 1;
+
+        // frequency samples of the Chebyshev polynomial
+        let p = Array1::range(T::zero(), mf, one).mapv(|k| {
+            let x = beta * (pi * k / mf).cos();
+            if x > one {
+                (order * x.acosh()).cosh()
+            } else if x < -one {
+                let sign = if odd { one } else { -one };
+                sign * (order * (-x).acosh()).cosh()
+            } else {
+                (order * x.acos()).cos()
+            }
+        });
+
+        // real part of the DFT, even lengths are shifted by half a sample
+        let shift = if odd { T::zero() } else { pi / mf };
+        let dft = |n: usize| {
+            p.iter().enumerate().fold(T::zero(), |acc, (k, p)| {
+                let k = cast::<T>(k);
+                acc + *p * (shift * k - T::TAU() * (k * cast(n)) / mf).cos()
+            })
+        };
+
+        let half = if odd {
+            (m as usize).div_ceil(2)
+        } else {
+            m as usize / 2 + 1
+        };
+        let w: Array1<T> = (0..half).map(dft).collect();
+        let w: Vec<T> = if odd {
+            w.iter().skip(1).rev().chain(w.iter()).copied().collect()
+        } else {
+            w.iter()
+                .skip(1)
+                .rev()
+                .chain(w.iter().skip(1))
+                .copied()
+                .collect()
+        };
+
+        let max = w.iter().copied().fold(T::neg_infinity(), T::max);
+        let w: Vec<T> = w.into_iter().map(|w| w / max).collect();
+        truncate(w, needs_trunc)
+    }
+    _chebwin(m, at, sym.into().unwrap_or(true))
+}
+
+/// Normalization of the tapers computed by [`dpss_tapers`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DpssNorm {
+    /// unit energy
+    #[default]
+    L2,
+    /// unit maximum, even lengths are corrected with `M² / (M² + NW)`
+    Approximate,
+    /// unit maximum, even lengths are corrected by the maximum of the window shifted by half
+    /// a sample
+    Subsample,
+}
+
+/// Discrete prolate spheroidal sequences computed by [`dpss_tapers`]
+#[derive(Debug, Clone)]
+pub struct DpssTapers<T> {
+    /// one taper per row, ordered by decreasing concentration
+    pub windows: Array2<T>,
+    /// fraction of the energy of each taper within the band `[-NW / M, NW / M]`
+    pub ratios: Array1<T>,
+}
+
+/// Discrete prolate spheroidal (Slepian) window
+///
+/// the first taper of [`dpss_tapers`] normalized with [`DpssNorm::Approximate`], `nw` is the
+/// standardized half bandwidth, the window maximizes the energy within `[-nw / m, nw / m]`.
+pub fn dpss<T: Float + FloatConst>(m: u64, nw: T, sym: impl Into<Option<bool>>) -> Array1<T> {
+    dpss_tapers(m, nw, 1, sym, DpssNorm::Approximate)
+        .windows
+        .row(0)
+        .to_owned()
+}
+
+/// Compute the first `kmax` discrete prolate spheroidal sequences
+///
+/// the tapers are the eigenvectors of the tridiagonal matrix commuting with the time and band
+/// limiting operator, `nw` is the standardized half bandwidth and must be less than `m / 2`.
+/// Even tapers have positive sum and odd tapers start with a positive lobe.
+///
+/// # Panics
+///
+/// Panics if `kmax` is zero or greater than `m`, or if `nw` isn't between 0 and `m / 2`,
+/// windows of length 0 or 1 are returned without checking the parameters
+pub fn dpss_tapers<T: Float + FloatConst>(
+    m: u64,
+    nw: T,
+    kmax: u64,
+    sym: impl Into<Option<bool>>,
+    norm: impl Into<Option<DpssNorm>>,
+) -> DpssTapers<T> {
+    fn _dpss_tapers<T: Float + FloatConst>(
+        m: u64,
+        nw: T,
+        kmax: usize,
+        sym: bool,
+        norm: DpssNorm,
+    ) -> DpssTapers<T> {
+        if len_guards(m) {
+            return DpssTapers {
+                windows: Array2::ones((kmax, m as usize)),
+                ratios: Array1::ones(kmax),
+            };
+        }
+        assert!(
+            kmax > 0 && kmax as u64 <= m,
+            "kmax must be between 1 and the window length"
+        );
+        assert!(
+            nw > T::zero() && nw < cast::<T>(m) / cast(2),
+            "nw must be positive and less than half the window length"
+        );
+        let (m, needs_trunc) = extend(m, sym);
+        let len = m as usize;
+
+        let one = T::one();
+        let two = cast::<T>(2);
+        let mf = cast::<T>(m);
+
+        let w = nw / mf;
+        let diag: Vec<T> = (0..len)
+            .map(|n| ((mf - one - two * cast(n)) / two).powi(2) * (T::TAU() * w).cos())
+            .collect();
+        let off: Vec<T> = (1..len)
+            .map(|n| cast::<T>(n) * (mf - cast(n)) / two)
+            .collect();
+
+        let mut windows = Array2::zeros((kmax, len));
+        for k in 0..kmax {
+            let eigenvalue = tridiagonal_eigenvalue(&diag, &off, len - 1 - k);
+            let taper = inverse_iteration(&diag, &off, eigenvalue, windows.slice(s![..k, ..]));
+            windows.row_mut(k).assign(&taper);
+        }
+
+        // sign convention: even tapers have positive sum, odd tapers a positive first lobe
+        let thresh = (one / mf).max(cast(1e-7));
+        for (k, mut taper) in windows.outer_iter_mut().enumerate() {
+            let negative = if k % 2 == 0 {
+                taper.sum() < T::zero()
+            } else {
+                taper
+                    .iter()
+                    .find(|a| **a * **a > thresh)
+                    .is_some_and(|a| *a < T::zero())
+            };
+            if negative {
+                taper.mapv_inplace(|a| -a);
+            }
+        }
+
+        let ratios = windows
+            .outer_iter()
+            .map(|taper| {
+                (0..len).fold(T::zero(), |acc, lag| {
+                    let rxx = dot(taper.slice(s![..len - lag]), taper.slice(s![lag..]));
+                    let r = if lag == 0 {
+                        two * w
+                    } else {
+                        let lag = cast::<T>(lag);
+                        two * (T::TAU() * w * lag).sin() / (T::PI() * lag)
+                    };
+                    acc + rxx * r
+                })
+            })
+            .collect();
+
+        if norm != DpssNorm::L2 {
+            let max = windows.iter().copied().fold(T::neg_infinity(), T::max);
+            windows.mapv_inplace(|a| a / max);
+            if len.is_multiple_of(2) {
+                let correction = match norm {
+                    DpssNorm::Approximate => mf * mf / mf.mul_add(mf, nw),
+                    _ => mf / subsample_sum(windows.row(0)),
+                };
+                windows.mapv_inplace(|a| a * correction);
+            }
+        }
+
+        let windows = if needs_trunc {
+            windows.slice(s![.., ..len - 1]).to_owned()
+        } else {
+            windows
+        };
+        DpssTapers { windows, ratios }
+    }
+
+    _dpss_tapers(
+        m,
+        nw,
+        kmax as usize,
+        sym.into().unwrap_or(true),
+        norm.into().unwrap_or_default(),
+    )
+}
+
+/// sum of the real spectrum of `w` shifted by half a sample, the maximum of the shifted window
+fn subsample_sum<T: Float + FloatConst>(w: ArrayView1<'_, T>) -> T {
+    let m = cast::<T>(w.len());
+    let spectrum = |k: usize| {
+        w.iter()
+            .enumerate()
+            .fold((T::zero(), T::zero()), |(re, im), (n, w)| {
+                let phase = -T::TAU() * cast(k * n) / m;
+                (re + *w * phase.cos(), im + *w * phase.sin())
+            })
+    };
+    let (s0, _) = spectrum(0);
+    (1..=w.len() / 2).fold(s0, |acc, k| {
+        let (re, im) = spectrum(k);
+        // multiply by 2 exp(-i pi shift) with shift = -(1 - 1 / m) k
+        let phase = T::PI() * (T::one() - m.recip()) * cast(k);
+        acc + cast::<T>(2) * re.mul_add(phase.cos(), -(im * phase.sin()))
+    })
+}
+
+/// `index`-th smallest eigenvalue of the symmetric tridiagonal matrix, found by bisection
+/// on the Sturm sequence
+fn tridiagonal_eigenvalue<T: Float>(diag: &[T], off: &[T], index: usize) -> T {
+    let radius = |i: usize| {
+        let left = if i > 0 { off[i - 1].abs() } else { T::zero() };
+        let right = off.get(i).map_or_else(T::zero, |e| e.abs());
+        left + right
+    };
+    let (mut lo, mut hi) = (0..diag.len())
+        .fold((T::infinity(), T::neg_infinity()), |(lo, hi), i| {
+            (lo.min(diag[i] - radius(i)), hi.max(diag[i] + radius(i)))
+        });
+
+    // number of eigenvalues smaller than x
+    let count = |x: T| {
+        let mut q = T::one();
+        let mut count = 0;
+        for (i, d) in diag.iter().enumerate() {
+            q = if i > 0 {
+                *d - x - off[i - 1] * off[i - 1] / q
+            } else {
+                *d - x
+            };
+            if q == T::zero() {
+                q = T::epsilon() * (d.abs() + x.abs()).max(T::min_positive_value());
+            }
+            if q < T::zero() {
+                count += 1;
+            }
+        }
+        count
+    };
+
+    // every step halves the interval, the bounds meet well before the limit
+    let half = cast::<T>(0.5);
+    for _ in 0..256 {
+        let mid = half * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if count(mid) > index {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    half * (lo + hi)
+}
+
+/// unit eigenvector of the symmetric tridiagonal matrix for `eigenvalue`, orthogonal to the
+/// rows of `previous`
+fn inverse_iteration<T: Float>(
+    diag: &[T],
+    off: &[T],
+    eigenvalue: T,
+    previous: ArrayView2<'_, T>,
+) -> Array1<T> {
+    let orthonormalize = |mut x: Array1<T>| {
+        for v in previous.outer_iter() {
+            let projection = dot(v, x.view());
+            x.zip_mut_with(&v, |x, v| *x = *x - projection * *v);
+        }
+        let norm = dot(x.view(), x.view()).sqrt();
+        x.mapv(|a| a / norm)
+    };
+
+    let mut x: Array1<T> = (0..diag.len())
+        .map(|i| T::one() + (cast::<T>(i + 1) * cast(0.618_034)).fract())
+        .collect();
+    for _ in 0..4 {
+        x = tridiagonal_solve(diag, off, eigenvalue, orthonormalize(x));
+    }
+    orthonormalize(x)
+}
+
+/// solve `(T - shift I) x = b` by gaussian elimination with partial pivoting,
+/// zero pivots of a singular system are replaced with a tiny value
+fn tridiagonal_solve<T: Float>(diag: &[T], off: &[T], shift: T, mut b: Array1<T>) -> Array1<T> {
+    let n = diag.len();
+    if n == 1 {
+        let d = diag[0] - shift;
+        let d = if d == T::zero() { T::epsilon() } else { d };
+        return b.mapv(|b| b / d);
+    }
+
+    let mut d: Vec<T> = diag.iter().map(|d| *d - shift).collect();
+    let mut dl = off.to_vec();
+    let mut du = off.to_vec();
+    let mut du2 = vec![T::zero(); n - 1];
+    let mut pivot = vec![false; n - 1];
+
+    for i in 0..n - 1 {
+        if d[i].abs() >= dl[i].abs() {
+            let fact = if d[i] == T::zero() {
+                T::zero()
+            } else {
+                dl[i] / d[i]
+            };
+            dl[i] = fact;
+            d[i + 1] = d[i + 1] - fact * du[i];
+        } else {
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            dl[i] = fact;
+            let temp = du[i];
+            du[i] = d[i + 1];
+            d[i + 1] = fact.mul_add(-d[i + 1], temp);
+            if i + 2 < n {
+                du2[i] = du[i + 1];
+                du[i + 1] = -fact * du[i + 1];
+            }
+            pivot[i] = true;
+        }
+    }
+
+    let scale = diag
+        .iter()
+        .chain(off.iter())
+        .fold(shift.abs(), |acc, a| acc.max(a.abs()));
+    let tiny = T::epsilon() * scale.max(T::min_positive_value());
+    d.iter_mut()
+        .filter(|d| **d == T::zero())
+        .for_each(|d| *d = tiny);
+
+    for i in 0..n - 1 {
+        if pivot[i] {
+            b.swap(i, i + 1);
+        }
+        b[i + 1] = b[i + 1] - dl[i] * b[i];
+    }
+
+    b[n - 1] = b[n - 1] / d[n - 1];
+    b[n - 2] = du[n - 2].mul_add(-b[n - 1], b[n - 2]) / d[n - 2];
+    for i in (0..n.saturating_sub(2)).rev() {
+        b[i] = du2[i].mul_add(-b[i + 2], du[i].mul_add(-b[i + 1], b[i])) / d[i];
+    }
+    b
+}
+
+fn dot<T: Float>(a: ArrayView1<'_, T>, b: ArrayView1<'_, T>) -> T {
+    a.iter()
+        .zip(b.iter())
+        .fold(T::zero(), |acc, (a, b)| acc + *a * *b)
+}
+
+fn cast<T: Float>(a: impl ToPrimitive) -> T {
+    T::from(a).unwrap()
+}
+
+/// modified Bessel function of the first kind of order 0, from its power series
+/// `sum((x / 2)^2k / k!^2)` whose terms are all positive
+fn i0<T: Float>(x: T) -> T {
+    let q = (x / cast(2)).powi(2);
+    let (mut sum, mut term, mut k) = (T::one(), T::one(), T::one());
+    while term > T::epsilon() * sum {
+        term = term * q / k.powi(2);
+        sum = sum + term;
+        k = k + T::one();
+    }
+    sum
+}
+
 pub fn len_guards(m: u64) -> bool {
     m <= 1
 }
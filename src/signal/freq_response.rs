@@ -0,0 +1,462 @@
+use ndarray::{Array1, ArrayView1, Axis};
+use num::{traits::FloatConst, Complex, Float, One};
+
+use super::{
+    output_type::{GenericBa, GenericFilterOutput, GenericSos, GenericZpk},
+    tools::{self, polymul, roots, ss, ss2tf},
+    GenericSampling,
+};
+
+/// Frequencies at which a frequency response is evaluated
+#[derive(Debug, Clone)]
+pub enum FrequencyGrid<T> {
+    /// `n` frequencies equally spaced around the upper half of the unit circle,
+    /// or around the whole unit circle when `whole` is set.
+    ///
+    /// Analog responses are evaluated at `n` logarithmically spaced frequencies
+    /// chosen around the poles and zeros of the filter, `whole` has no effect.
+    Points { n: usize, whole: bool },
+    /// frequencies in the same units as `fs` for digital filters, in rad/s for analog filters
+    Frequencies(Array1<T>),
+}
+
+impl<T> Default for FrequencyGrid<T> {
+    fn default() -> Self {
+        Self::Points {
+            n: 512,
+            whole: false,
+        }
+    }
+}
+
+/// Frequency response `h` evaluated at the frequencies `w`
+#[derive(Debug, Clone)]
+pub struct FrequencyResponse<T> {
+    pub w: Array1<T>,
+    pub h: Array1<Complex<T>>,
+}
+
+/// Compute the frequency response of a digital filter
+///
+/// The frequencies `w` are expressed in the same units as `fs`,
+/// use `fs = 2π` to get normalized frequencies in radians/sample.
+/// Analog sampling evaluates the response of an analog filter with [`freqs`].
+pub fn freqz<T: Float + FloatConst>(
+    ba: &GenericBa<T>,
+    grid: FrequencyGrid<T>,
+    sampling: GenericSampling<T>,
+) -> FrequencyResponse<T> {
+    match sampling {
+        GenericSampling::Digital { fs } => digital_response(grid, fs, |z| {
+            let zm1 = z.inv();
+            polyval_ascending(ba.b.view(), zm1) / polyval_ascending(ba.a.view(), zm1)
+        }),
+        GenericSampling::Analog => freqs(ba, grid),
+    }
+}
+
+/// Compute the frequency response of a digital filter in zpk form
+///
+/// The frequencies `w` are expressed in the same units as `fs`,
+/// use `fs = 2π` to get normalized frequencies in radians/sample.
+/// Analog sampling evaluates the response of an analog filter with [`freqs_zpk`].
+pub fn freqz_zpk<T: Float + FloatConst>(
+    zpk: &GenericZpk<T>,
+    grid: FrequencyGrid<T>,
+    sampling: GenericSampling<T>,
+) -> FrequencyResponse<T> {
+    match sampling {
+        GenericSampling::Digital { fs } => digital_response(grid, fs, |z| zpk_response(zpk, z)),
+        GenericSampling::Analog => freqs_zpk(zpk, grid),
+    }
+}
+
+/// Compute the frequency response of a digital filter in sos format
+///
+/// The frequencies `w` are expressed in the same units as `fs`,
+/// use `fs = 2π` to get normalized frequencies in radians/sample.
+/// Analog sampling evaluates the response of analog second-order sections,
+/// with `w` in rad/s.
+pub fn sosfreqz<T: Float + FloatConst>(
+    sos: &GenericSos<T>,
+    grid: FrequencyGrid<T>,
+    sampling: GenericSampling<T>,
+) -> FrequencyResponse<T> {
+    match sampling {
+        GenericSampling::Digital { fs } => digital_response(grid, fs, |z| {
+            let zm1 = z.inv();
+            sos.sos
+                .rows()
+                .into_iter()
+                .fold(Complex::one(), |h, section| {
+                    let (b, a) = section.split_at(Axis(0), 3);
+                    h * polyval_ascending(b, zm1) / polyval_ascending(a, zm1)
+                })
+        }),
+        GenericSampling::Analog => sosfreqs(sos, grid),
+    }
+}
+
+/// Compute the frequency response of an analog filter
+///
+/// The frequencies `w` are expressed in rad/s.
+pub fn freqs<T: Float>(ba: &GenericBa<T>, grid: FrequencyGrid<T>) -> FrequencyResponse<T> {
+    let w = analog_grid(grid, || Ok((roots(ba.b.view())?, roots(ba.a.view())?)));
+    let h = w
+        .iter()
+        .map(|w| {
+            let s = Complex::new(T::zero(), *w);
+            polyval_descending(ba.b.view(), s) / polyval_descending(ba.a.view(), s)
+        })
+        .collect();
+
+    FrequencyResponse { w, h }
+}
+
+/// Compute the frequency response of an analog filter in zpk form
+///
+/// The frequencies `w` are expressed in rad/s.
+pub fn freqs_zpk<T: Float>(zpk: &GenericZpk<T>, grid: FrequencyGrid<T>) -> FrequencyResponse<T> {
+    let w = analog_grid(grid, || Ok((zpk.z.clone(), zpk.p.clone())));
+    let h = w
+        .iter()
+        .map(|w| zpk_response(zpk, Complex::new(T::zero(), *w)))
+        .collect();
+
+    FrequencyResponse { w, h }
+}
+
+/// Group delay `gd` evaluated at the frequencies `w`
+///
+/// The delay is expressed in samples for digital filters and in seconds for analog filters.
+#[derive(Debug, Clone)]
+pub struct GroupDelay<T> {
+    pub w: Array1<T>,
+    pub gd: Array1<T>,
+    /// indices of the frequencies where the group delay is singular, the delay is set to 0 there
+    pub singular: Vec<usize>,
+    /// indices of the frequencies where the denominator is extremely small,
+    /// a singularity may be present around them
+    pub near_singular: Vec<usize>,
+}
+
+/// Compute the group delay of a filter
+///
+/// The group delay is the negative derivative of the phase response with respect to frequency.
+/// Digital filters are evaluated on the unit circle with frequencies `w` expressed in the units
+/// of `fs`, analog filters on the imaginary axis with `w` in rad/s.
+pub fn group_delay<T: Float + FloatConst>(
+    ba: &GenericBa<T>,
+    grid: FrequencyGrid<T>,
+    sampling: GenericSampling<T>,
+) -> GroupDelay<T> {
+    let (b, a) = (ba.b.view(), ba.a.view());
+
+    match sampling {
+        GenericSampling::Digital { fs } => {
+            let w = digital_grid(grid, fs);
+
+            // delay of b(z) a(1 / z), shifted back by the order of a
+            let a_rev: Array1<_> = a.iter().rev().copied().collect();
+            let c = polymul(b, a_rev.view());
+            let cr: Array1<_> = c
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c * T::from(i).unwrap())
+                .collect();
+            let shift = T::from(a.len()).unwrap() - T::one();
+
+            let gd = w.iter().map(|w| {
+                let z = Complex::from_polar(T::one(), -T::TAU() * *w / fs);
+                let den = polyval_ascending(c.view(), z);
+                let gd = (polyval_ascending(cr.view(), z) / den).re - shift;
+                (gd, den.norm())
+            });
+            group_delay_output(gd.collect(), w)
+        }
+        GenericSampling::Analog => {
+            let w = analog_grid(grid, || Ok((roots(b)?, roots(a)?)));
+            let (db, da) = (polyder(b), polyder(a));
+
+            // Re(a'(s) / a(s) - b'(s) / b(s)) with s = jw
+            let gd = w.iter().map(|w| {
+                let s = Complex::new(T::zero(), *w);
+                let (bs, as_) = (polyval_descending(b, s), polyval_descending(a, s));
+                let num =
+                    polyval_descending(da.view(), s) * bs - polyval_descending(db.view(), s) * as_;
+                let den = as_ * bs;
+                ((num / den).re, den.norm())
+            });
+            group_delay_output(gd.collect(), w)
+        }
+    }
+}
+
+/// Compute the group delay of a filter in zpk form
+///
+/// See [`group_delay`], each pole adds and each zero removes its own contribution to the delay.
+pub fn group_delay_zpk<T: Float + FloatConst>(
+    zpk: &GenericZpk<T>,
+    grid: FrequencyGrid<T>,
+    sampling: GenericSampling<T>,
+) -> GroupDelay<T> {
+    let (w, x): (Array1<T>, Vec<_>) = match sampling {
+        GenericSampling::Digital { fs } => {
+            let w = digital_grid(grid, fs);
+            let x = w
+                .iter()
+                .map(|w| Complex::from_polar(T::one(), T::TAU() * *w / fs))
+                .collect();
+            (w, x)
+        }
+        GenericSampling::Analog => {
+            let w = analog_grid(grid, || Ok((zpk.z.clone(), zpk.p.clone())));
+            let x = w.iter().map(|w| Complex::new(T::zero(), *w)).collect();
+            (w, x)
+        }
+    };
+
+    // the phase derivative of (x - r) is Re(x / (x - r)) on the unit circle
+    // and Re(1 / (x - r)) on the imaginary axis
+    let contribution = |x: Complex<T>, r: &Complex<T>| match sampling {
+        GenericSampling::Digital { .. } => (x / (x - r)).re,
+        GenericSampling::Analog => (x - r).inv().re,
+    };
+    let sum = |roots: &Array1<Complex<T>>, x| {
+        roots
+            .iter()
+            .map(|r| contribution(x, r))
+            .fold(T::zero(), |a, b| a + b)
+    };
+
+    let gd = x.into_iter().map(|x| {
+        let distance = zpk
+            .p
+            .iter()
+            .chain(zpk.z.iter())
+            .map(|r| (x - r).norm())
+            .fold(T::infinity(), T::min);
+        (sum(&zpk.p, x) - sum(&zpk.z, x), distance)
+    });
+    group_delay_output(gd.collect(), w)
+}
+
+/// builds a [`GroupDelay`] from the delay and the magnitude of its denominator at each frequency,
+/// non finite delays are set to 0
+fn group_delay_output<T: Float>(gd: Vec<(T, T)>, w: Array1<T>) -> GroupDelay<T> {
+    let tiny = T::from(10.0).unwrap() * T::epsilon();
+    let mut singular = vec![];
+    let mut near_singular = vec![];
+
+    let gd = gd
+        .into_iter()
+        .enumerate()
+        .map(|(i, (gd, den))| {
+            if !gd.is_finite() {
+                singular.push(i);
+                return T::zero();
+            }
+            if den < tiny {
+                near_singular.push(i);
+            }
+            gd
+        })
+        .collect();
+
+    GroupDelay {
+        w,
+        gd,
+        singular,
+        near_singular,
+    }
+}
+
+impl<T: Float + FloatConst> GenericFilterOutput<T> {
+    /// Compute the frequency response of the filter
+    ///
+    /// Filters are evaluated with [`freqz`], [`freqz_zpk`] or [`sosfreqz`], digital frequencies
+    /// are expressed in the units of `fs` and analog frequencies in rad/s.
+    /// State-space systems are converted to a transfer function with [`ss2tf`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the transfer function of a state-space system can't be computed
+    pub fn freq_response(
+        &self,
+        grid: FrequencyGrid<T>,
+        sampling: GenericSampling<T>,
+    ) -> Result<FrequencyResponse<T>, ss::Error> {
+        Ok(match self {
+            Self::Ba(ba) => freqz(ba, grid, sampling),
+            Self::Zpk(zpk) => freqz_zpk(zpk, grid, sampling),
+            Self::Sos(sos) => sosfreqz(sos, grid, sampling),
+            Self::StateSpace(ss) => {
+                return Self::Ba(ss2tf(ss.clone())?).freq_response(grid, sampling)
+            }
+        })
+    }
+}
+
+/// analog second-order sections hold the coefficients of `s` in descending powers
+fn sosfreqs<T: Float>(sos: &GenericSos<T>, grid: FrequencyGrid<T>) -> FrequencyResponse<T> {
+    let sections = || sos.sos.rows().into_iter().map(|s| s.split_at(Axis(0), 3));
+    let w = analog_grid(grid, || {
+        let (z, p): (Vec<_>, Vec<_>) = sections()
+            .map(|(b, a)| Ok((roots(b)?, roots(a)?)))
+            .collect::<Result<Vec<_>, tools::Error>>()?
+            .into_iter()
+            .unzip();
+        Ok((
+            z.into_iter().flatten().collect(),
+            p.into_iter().flatten().collect(),
+        ))
+    });
+    let h = w
+        .iter()
+        .map(|w| {
+            let s = Complex::new(T::zero(), *w);
+            sections().fold(Complex::one(), |h, (b, a)| {
+                h * polyval_descending(b, s) / polyval_descending(a, s)
+            })
+        })
+        .collect();
+
+    FrequencyResponse { w, h }
+}
+
+/// evaluates `response` on the unit circle, frequencies are returned in units of `fs`
+fn digital_response<T: Float + FloatConst>(
+    grid: FrequencyGrid<T>,
+    fs: T,
+    response: impl Fn(Complex<T>) -> Complex<T>,
+) -> FrequencyResponse<T> {
+    let w = digital_grid(grid, fs);
+    let h = w
+        .iter()
+        .map(|w| response(Complex::from_polar(T::one(), T::TAU() * *w / fs)))
+        .collect();
+
+    FrequencyResponse { w, h }
+}
+
+/// frequencies for digital responses, in units of `fs`
+fn digital_grid<T: Float>(grid: FrequencyGrid<T>, fs: T) -> Array1<T> {
+    match grid {
+        FrequencyGrid::Points { n, whole } => {
+            let last = if whole {
+                fs
+            } else {
+                fs / (T::one() + T::one())
+            };
+            let n_t = T::from(n).unwrap();
+            Array1::from_iter((0..n).map(|i| last * T::from(i).unwrap() / n_t))
+        }
+        FrequencyGrid::Frequencies(w) => w,
+    }
+}
+
+/// frequencies for analog responses, `zeros_poles` is only computed for [`FrequencyGrid::Points`]
+fn analog_grid<T: Float>(
+    grid: FrequencyGrid<T>,
+    zeros_poles: impl FnOnce() -> Result<(Array1<Complex<T>>, Array1<Complex<T>>), tools::Error>,
+) -> Array1<T> {
+    match grid {
+        FrequencyGrid::Points { n, .. } => {
+            // the zeros and poles only center the frequency range, if the roots can't be
+            // computed the default range is used
+            let (z, p) = zeros_poles().unwrap_or_else(|_| (Array1::zeros(0), Array1::zeros(0)));
+            findfreqs(&z, &p, n)
+        }
+        FrequencyGrid::Frequencies(w) => w,
+    }
+}
+
+/// Find array of frequencies for computing the response of an analog filter,
+/// port of scipy's `findfreqs`
+fn findfreqs<T: Float>(z: &Array1<Complex<T>>, p: &Array1<Complex<T>>, n: usize) -> Array1<T> {
+    let from = |v: f64| T::from(v).unwrap();
+
+    let p = if p.is_empty() {
+        vec![Complex::new(from(-1000.0), T::zero())]
+    } else {
+        p.to_vec()
+    };
+    let ez: Vec<_> = p
+        .into_iter()
+        .filter(|p| p.im >= T::zero())
+        .chain(
+            z.iter()
+                .copied()
+                .filter(|z| z.norm() < from(1e5) && z.im >= T::zero()),
+        )
+        .collect();
+
+    let integ = |z: &Complex<T>| {
+        if z.norm() < from(1e-10) {
+            T::one()
+        } else {
+            T::zero()
+        }
+    };
+    let hfreq = ez
+        .iter()
+        .map(|z| from(3.0) * (z.re + integ(z)).abs() + from(1.5) * z.im)
+        .fold(T::neg_infinity(), T::max);
+    let lfreq = ez
+        .iter()
+        .map(|z| (z.re + integ(z)).abs() + from(2.0) * z.im)
+        .fold(T::infinity(), T::min);
+
+    let hfreq = round_half_even(hfreq.log10() + from(0.5));
+    let lfreq = round_half_even((from(0.1) * lfreq).log10() - from(0.5));
+
+    let step = match n {
+        0 | 1 => T::zero(),
+        n => (hfreq - lfreq) / T::from(n - 1).unwrap(),
+    };
+    Array1::from_iter((0..n).map(|i| from(10.0).powf(lfreq + step * T::from(i).unwrap())))
+}
+
+/// rounds to the nearest integer, with ties rounded to the nearest even integer as numpy does
+fn round_half_even<T: Float>(x: T) -> T {
+    let two = T::one() + T::one();
+    if (x - x.trunc()).abs() == T::one() / two {
+        two * (x / two).round()
+    } else {
+        x.round()
+    }
+}
+
+/// evaluates `sum(coeff[i] * x^i)`
+fn polyval_ascending<T: Float>(coeff: ArrayView1<'_, Complex<T>>, x: Complex<T>) -> Complex<T> {
+    coeff
+        .iter()
+        .rev()
+        .fold(Complex::new(T::zero(), T::zero()), |acc, c| acc * x + c)
+}
+
+/// evaluates `sum(coeff[i] * x^(n - 1 - i))`
+fn polyval_descending<T: Float>(coeff: ArrayView1<'_, Complex<T>>, x: Complex<T>) -> Complex<T> {
+    coeff
+        .iter()
+        .fold(Complex::new(T::zero(), T::zero()), |acc, c| acc * x + c)
+}
+
+fn zpk_response<T: Float>(zpk: &GenericZpk<T>, x: Complex<T>) -> Complex<T> {
+    let num = zpk
+        .z
+        .iter()
+        .fold(Complex::new(zpk.k, T::zero()), |h, z| h * (x - z));
+    zpk.p.iter().fold(num, |h, p| h / (x - p))
+}
+
+/// derivative of a polynomial with coefficients in descending powers
+fn polyder<T: Float>(coeff: ArrayView1<'_, Complex<T>>) -> Array1<Complex<T>> {
+    let n = coeff.len();
+    coeff
+        .iter()
+        .take(n.saturating_sub(1))
+        .enumerate()
+        .map(|(i, c)| c * T::from(n - 1 - i).unwrap())
+        .collect()
+}
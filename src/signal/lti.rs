@@ -0,0 +1,530 @@
+//! Continuous and discrete-time linear time invariant systems
+//!
+//! [`GenericLti`] and [`GenericDlti`] wrap any of the [`Zpk`](crate::signal::output_type::Zpk),
+//! [`Ba`](crate::signal::output_type::Ba) or [`StateSpace`](crate::signal::output_type::StateSpace)
+//! representations, the simulation is always carried out on the state-space form of the system.
+//!
+//! Only systems with real coefficients can be simulated.
+
+use super::{
+    output_type::{GenericBa, GenericFilterOutput, GenericStateSpace, GenericZpk},
+    tools::{sos2tf, ss, tf2ss, zpk2ss},
+    GenericSampling,
+};
+use nalgebra::{DMatrix, DVector};
+use ndarray::{Array1, Array2};
+use num::{complex::ComplexFloat, traits::FloatConst, Float};
+use thiserror::Error;
+
+/// Number of time samples of the responses when neither the times nor their number are given
+const DEFAULT_SAMPLES: usize = 100;
+
+/// Representation of the transfer function of an LTI system
+#[derive(Debug, Clone)]
+pub enum GenericLtiSystem<T> {
+    /// See [Zpk](crate::signal::output_type::Zpk)
+    Zpk(GenericZpk<T>),
+    /// See [Ba](crate::signal::output_type::Ba)
+    Ba(GenericBa<T>),
+    /// See [StateSpace](crate::signal::output_type::StateSpace)
+    StateSpace(GenericStateSpace<T>),
+}
+
+impl<T: Float + FloatConst + ComplexFloat> GenericLtiSystem<T> {
+    /// state-space realization of the system
+    ///
+    /// # Errors
+    ///
+    /// Fails if the transfer function is improper
+    pub fn to_state_space(&self) -> Result<GenericStateSpace<T>, ss::Error> {
+        match self {
+            Self::Zpk(zpk) => zpk2ss(zpk.clone()),
+            Self::Ba(ba) => tf2ss(ba.clone()),
+            Self::StateSpace(ss) => Ok(ss.clone()),
+        }
+    }
+}
+
+impl<T> From<GenericZpk<T>> for GenericLtiSystem<T> {
+    fn from(value: GenericZpk<T>) -> Self {
+        Self::Zpk(value)
+    }
+}
+
+impl<T> From<GenericBa<T>> for GenericLtiSystem<T> {
+    fn from(value: GenericBa<T>) -> Self {
+        Self::Ba(value)
+    }
+}
+
+impl<T> From<GenericStateSpace<T>> for GenericLtiSystem<T> {
+    fn from(value: GenericStateSpace<T>) -> Self {
+        Self::StateSpace(value)
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> From<GenericFilterOutput<T>> for GenericLtiSystem<T> {
+    /// second-order sections are merged in a single transfer function
+    fn from(value: GenericFilterOutput<T>) -> Self {
+        match value {
+            GenericFilterOutput::Zpk(zpk) => Self::Zpk(zpk),
+            GenericFilterOutput::Ba(ba) => Self::Ba(ba),
+            GenericFilterOutput::Sos(sos) => Self::Ba(sos2tf(sos)),
+            GenericFilterOutput::StateSpace(ss) => Self::StateSpace(ss),
+        }
+    }
+}
+
+/// Continuous-time linear time invariant system
+pub type Lti = GenericLti<f64>;
+
+#[derive(Debug, Clone)]
+pub struct GenericLti<T> {
+    pub system: GenericLtiSystem<T>,
+}
+
+impl<T> GenericLti<T> {
+    pub fn new(system: impl Into<GenericLtiSystem<T>>) -> Self {
+        Self {
+            system: system.into(),
+        }
+    }
+
+    pub const fn sampling(&self) -> GenericSampling<T> {
+        GenericSampling::Analog
+    }
+}
+
+/// Discrete-time linear time invariant system with sampling period `dt`
+pub type Dlti = GenericDlti<f64>;
+
+#[derive(Debug, Clone)]
+pub struct GenericDlti<T> {
+    pub system: GenericLtiSystem<T>,
+    pub dt: T,
+}
+
+impl<T: Float> GenericDlti<T> {
+    pub fn new(system: impl Into<GenericLtiSystem<T>>, dt: T) -> Self {
+        Self {
+            system: system.into(),
+            dt,
+        }
+    }
+
+    /// discrete system sampled at the frequency of `sampling`, `None` for analog sampling
+    pub fn from_sampling(
+        system: impl Into<GenericLtiSystem<T>>,
+        sampling: GenericSampling<T>,
+    ) -> Option<Self> {
+        match sampling {
+            GenericSampling::Analog => None,
+            GenericSampling::Digital { fs } => Some(Self::new(system, T::one() / fs)),
+        }
+    }
+
+    pub fn sampling(&self) -> GenericSampling<T> {
+        GenericSampling::Digital {
+            fs: T::one() / self.dt,
+        }
+    }
+}
+
+/// Interpolation of the input between the time samples in [`lsim`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputInterpolation {
+    /// the input is constant between samples
+    ZeroOrderHold,
+    /// the input is linear between samples
+    #[default]
+    FirstOrderHold,
+}
+
+/// Time response of an LTI system
+///
+/// `yout` holds the output at the times `t`, `xout` the state vector with one row for every
+/// time sample.
+#[derive(Debug, Clone)]
+pub struct TimeResponse<T> {
+    pub t: Array1<T>,
+    pub yout: Array1<T>,
+    pub xout: Array2<T>,
+}
+
+/// Simulate the output of a continuous-time linear system
+///
+/// `u` is the input at the equally spaced times `t` and `x0` the initial state at time zero,
+/// zero if `None`. When `t` starts after zero, `x0` is first propagated to `t[0]` without
+/// input. The state is propagated with the exact discretization of the system for the chosen
+/// [`InputInterpolation`].
+///
+/// # Errors
+///
+/// Fails if the system can't be simulated, if `t` is empty, starts before zero or has unequal
+/// steps, or if `u` and `x0` have the wrong length
+pub fn lsim<T>(
+    system: &GenericLti<T>,
+    u: Array1<T>,
+    t: Array1<T>,
+    x0: Option<Array1<T>>,
+    interp: InputInterpolation,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ss = RealStateSpace::new(&system.system)?;
+    if u.len() != t.len() {
+        return Err(Error::InputLength {
+            u: u.len(),
+            t: t.len(),
+        });
+    }
+    let x0 = initial_state(x0, ss.a.nrows())?;
+    let u = u.mapv(to_f64).to_vec();
+    let times = t.mapv(to_f64).to_vec();
+
+    let x = ss.continuous(&u, &times, x0, interp)?;
+    Ok(ss.response(t, x, &u))
+}
+
+/// Impulse response of a continuous-time system
+///
+/// `x0` is added to the state set by the impulse. When `t` is `None` the response is computed
+/// at `n` times (100 if `None`) spanning seven time constants of the slowest pole.
+///
+/// # Errors
+///
+/// See [`lsim`]
+pub fn impulse<T>(
+    system: &GenericLti<T>,
+    x0: Option<Array1<T>>,
+    t: Option<Array1<T>>,
+    n: Option<usize>,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ss = RealStateSpace::new(&system.system)?;
+    let x0 = initial_state(x0, ss.a.nrows())? + &ss.b;
+    let t = t.unwrap_or_else(|| ss.default_response_times(n));
+    let times = t.mapv(to_f64).to_vec();
+    let u = vec![0.0; times.len()];
+
+    let x = ss.continuous(&u, &times, x0, InputInterpolation::ZeroOrderHold)?;
+    Ok(ss.response(t, x, &u))
+}
+
+/// Step response of a continuous-time system
+///
+/// see [`impulse`] for the default times
+///
+/// # Errors
+///
+/// See [`lsim`]
+pub fn step<T>(
+    system: &GenericLti<T>,
+    x0: Option<Array1<T>>,
+    t: Option<Array1<T>>,
+    n: Option<usize>,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ss = RealStateSpace::new(&system.system)?;
+    let x0 = initial_state(x0, ss.a.nrows())?;
+    let t = t.unwrap_or_else(|| ss.default_response_times(n));
+    let times = t.mapv(to_f64).to_vec();
+    let u = vec![1.0; times.len()];
+
+    let x = ss.continuous(&u, &times, x0, InputInterpolation::ZeroOrderHold)?;
+    Ok(ss.response(t, x, &u))
+}
+
+/// Simulate the output of a discrete-time linear system
+///
+/// if `t` is `None` the k-th element of `u` is the input at time `k * dt`, otherwise `u` is
+/// sampled at the times `t` and linearly interpolated at the multiples of `dt` up to the last time.
+/// `x0` is the initial state, zero if `None`.
+///
+/// # Errors
+///
+/// Fails if the system can't be simulated, if `u`, `t` and `x0` have the wrong length or if
+/// `t` doesn't start at or before zero
+pub fn dlsim<T>(
+    system: &GenericDlti<T>,
+    u: Array1<T>,
+    t: Option<Array1<T>>,
+    x0: Option<Array1<T>>,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let ss = RealStateSpace::new(&system.system)?;
+    let x0 = initial_state(x0, ss.a.nrows())?;
+    let dt = to_f64(system.dt);
+    let u = u.mapv(to_f64).to_vec();
+
+    let (tout, u) = match t {
+        None => (Array1::from_shape_fn(u.len(), |i| i as f64 * dt), u),
+        Some(t) => {
+            if t.len() != u.len() {
+                return Err(Error::InputLength {
+                    u: u.len(),
+                    t: t.len(),
+                });
+            }
+            let t = t.mapv(to_f64).to_vec();
+            let Some(&stop) = t.last() else {
+                return Err(Error::TimeSamples(0));
+            };
+
+            let samples = (stop / dt).floor() as usize + 1;
+            let tout = Array1::linspace(0.0, stop, samples);
+            let u = tout
+                .iter()
+                .map(|&x| interp_linear(&t, &u, x).ok_or(Error::InputInterval))
+                .collect::<Result<Vec<_>, _>>()?;
+            (tout, u)
+        }
+    };
+
+    let x = ss.discrete(&u, x0);
+    Ok(ss.response(tout.mapv(from_f64), x, &u))
+}
+
+/// Impulse response of a discrete-time system
+///
+/// the response is computed at the times `t` or at `n` multiples of `dt` (100 if `None`),
+/// `x0` is the initial state, zero if `None`.
+///
+/// # Errors
+///
+/// See [`dlsim`]
+pub fn dimpulse<T>(
+    system: &GenericDlti<T>,
+    x0: Option<Array1<T>>,
+    t: Option<Array1<T>>,
+    n: Option<usize>,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let samples = t
+        .as_ref()
+        .map_or_else(|| n.unwrap_or(DEFAULT_SAMPLES), Array1::len);
+    let u = Array1::from_shape_fn(samples, |i| if i == 0 { T::one() } else { T::zero() });
+    dlsim(system, u, t, x0)
+}
+
+/// Step response of a discrete-time system
+///
+/// see [`dimpulse`] for the times of the response
+///
+/// # Errors
+///
+/// See [`dlsim`]
+pub fn dstep<T>(
+    system: &GenericDlti<T>,
+    x0: Option<Array1<T>>,
+    t: Option<Array1<T>>,
+    n: Option<usize>,
+) -> Result<TimeResponse<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let samples = t
+        .as_ref()
+        .map_or_else(|| n.unwrap_or(DEFAULT_SAMPLES), Array1::len);
+    dlsim(system, Array1::ones(samples), t, x0)
+}
+
+/// real single input single output state-space system used by the simulations
+struct RealStateSpace {
+    a: DMatrix<f64>,
+    b: DVector<f64>,
+    c: DVector<f64>,
+    d: f64,
+}
+
+impl RealStateSpace {
+    fn new<T>(system: &GenericLtiSystem<T>) -> Result<Self, Error>
+    where
+        T: Float + FloatConst + ComplexFloat,
+    {
+        let (a, b, c, d) = system
+            .to_state_space()?
+            .cast_with_fn(to_f64)
+            .real_matrices()
+            .ok_or(Error::ComplexCoefficients)?;
+
+        Ok(Self {
+            a,
+            b: b.column(0).into_owned(),
+            c: c.row(0).transpose(),
+            d: d[(0, 0)],
+        })
+    }
+
+    /// `n` times spanning seven time constants of the slowest mode of the system
+    fn default_response_times<T: Float>(&self, n: Option<usize>) -> Array1<T> {
+        let r = self
+            .a
+            .complex_eigenvalues()
+            .iter()
+            .map(|e| e.re.abs())
+            .fold(f64::INFINITY, f64::min);
+        let r = if r == 0.0 || r.is_infinite() { 1.0 } else { r };
+
+        Array1::linspace(0.0, 7.0 / r, n.unwrap_or(DEFAULT_SAMPLES)).mapv(from_f64)
+    }
+
+    fn continuous(
+        &self,
+        u: &[f64],
+        t: &[f64],
+        x0: DVector<f64>,
+        interp: InputInterpolation,
+    ) -> Result<Vec<DVector<f64>>, Error> {
+        let Some(&start) = t.first() else {
+            return Err(Error::TimeSamples(0));
+        };
+        if start < 0.0 {
+            return Err(Error::InitialTime(start));
+        }
+
+        // x0 is the state at time zero, the free response carries it to the first time
+        let mut x = Vec::with_capacity(t.len());
+        x.push(if start > 0.0 {
+            (&self.a * start).exp() * x0
+        } else {
+            x0
+        });
+        if t.len() == 1 {
+            return Ok(x);
+        }
+
+        let dt = t[1] - t[0];
+        if t.windows(2)
+            .any(|t| ((t[1] - t[0]) - dt).abs() > dt.abs().mul_add(1e-5, 1e-8))
+        {
+            return Err(Error::TimeSteps);
+        }
+
+        let n = self.a.nrows();
+        match interp {
+            InputInterpolation::ZeroOrderHold => {
+                // exp([[A, B], [0, 0]] dt) holds the discretized A and B
+                let mut m = DMatrix::zeros(n + 1, n + 1);
+                m.view_mut((0, 0), (n, n)).copy_from(&(&self.a * dt));
+                m.view_mut((0, n), (n, 1)).copy_from(&(&self.b * dt));
+                let e = m.exp();
+                let ad = e.view((0, 0), (n, n)).into_owned();
+                let bd = e.view((0, n), (n, 1)).column(0).into_owned();
+
+                for i in 1..t.len() {
+                    let next = &ad * &x[i - 1] + &bd * u[i - 1];
+                    x.push(next);
+                }
+            }
+            InputInterpolation::FirstOrderHold => {
+                // the input and its slope are added to the state
+                let mut m = DMatrix::zeros(n + 2, n + 2);
+                m.view_mut((0, 0), (n, n)).copy_from(&(&self.a * dt));
+                m.view_mut((0, n), (n, 1)).copy_from(&(&self.b * dt));
+                m[(n, n + 1)] = 1.0;
+                let e = m.exp();
+                let ad = e.view((0, 0), (n, n)).into_owned();
+                let bd1 = e.view((0, n + 1), (n, 1)).column(0).into_owned();
+                let bd0 = e.view((0, n), (n, 1)).column(0) - &bd1;
+
+                for i in 1..t.len() {
+                    let next = &ad * &x[i - 1] + &bd0 * u[i - 1] + &bd1 * u[i];
+                    x.push(next);
+                }
+            }
+        }
+
+        Ok(x)
+    }
+
+    fn discrete(&self, u: &[f64], x0: DVector<f64>) -> Vec<DVector<f64>> {
+        let mut x = Vec::with_capacity(u.len());
+        if u.is_empty() {
+            return x;
+        }
+
+        x.push(x0);
+        for i in 1..u.len() {
+            let next = &self.a * &x[i - 1] + &self.b * u[i - 1];
+            x.push(next);
+        }
+        x
+    }
+
+    fn response<T: Float>(&self, t: Array1<T>, x: Vec<DVector<f64>>, u: &[f64]) -> TimeResponse<T> {
+        let yout = x
+            .iter()
+            .zip(u)
+            .map(|(x, u)| from_f64(self.c.dot(x) + self.d * u))
+            .collect();
+        let xout = Array2::from_shape_fn((x.len(), self.a.nrows()), |(i, j)| from_f64(x[i][j]));
+
+        TimeResponse { t, yout, xout }
+    }
+}
+
+fn initial_state<T: Float>(x0: Option<Array1<T>>, n: usize) -> Result<DVector<f64>, Error> {
+    match x0 {
+        None => Ok(DVector::zeros(n)),
+        Some(x0) if x0.len() == n => Ok(DVector::from_iterator(n, x0.iter().map(|a| to_f64(*a)))),
+        Some(x0) => Err(Error::InitialState {
+            expected: n,
+            len: x0.len(),
+        }),
+    }
+}
+
+/// linear interpolation of the samples `(t, u)` at `x`, `None` outside of `t`
+fn interp_linear(t: &[f64], u: &[f64], x: f64) -> Option<f64> {
+    if t.is_empty() || x < t[0] || x > t[t.len() - 1] {
+        return None;
+    }
+    if t.len() == 1 {
+        return Some(u[0]);
+    }
+
+    let i = t.partition_point(|&t| t <= x).clamp(1, t.len() - 1);
+    let (t0, t1) = (t[i - 1], t[i]);
+    if t1 == t0 {
+        return Some(u[i - 1]);
+    }
+    Some(u[i - 1] + (u[i] - u[i - 1]) * (x - t0) / (t1 - t0))
+}
+
+fn to_f64<T: Float>(a: T) -> f64 {
+    a.to_f64().unwrap_or(f64::NAN)
+}
+
+fn from_f64<T: Float>(a: f64) -> T {
+    T::from(a).unwrap_or_else(T::nan)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    StateSpace(#[from] ss::Error),
+    #[error("systems with complex coefficients can't be simulated")]
+    ComplexCoefficients,
+    #[error("at least one time sample is needed, received {0}")]
+    TimeSamples(usize),
+    #[error("the initial time must be nonnegative, received {0}")]
+    InitialTime(f64),
+    #[error("time steps are not equally spaced")]
+    TimeSteps,
+    #[error("the input has {u} samples but there are {t} time samples")]
+    InputLength { u: usize, t: usize },
+    #[error("the initial state must have length {expected}, received {len}")]
+    InitialState { expected: usize, len: usize },
+    #[error("the input time samples must cover the interval from zero to the last time")]
+    InputInterval,
+}
@@ -13,14 +13,30 @@
 //!
 //! ### IIR Filter
 //!
-//! sciport-rs provides two functions to directly design IIR iirdesign and iirfilter, where the filter type (e.g., elliptic)<br/>
-//! is passed as an argument and several more filter design functions for specific filter types, e.g., ellip.
+//! sciport-rs provides two functions to directly design IIR [`iirdesign`] and [`iirfilter`], where the filter type (e.g., elliptic)<br/>
+//! is passed as an argument and several more filter design functions for specific filter types, e.g., ellip.<br/>
+//! Narrow band notch and peak filters are designed with [`iirnotch`], [`iirpeak`] and [`iircomb`],<br/>
+//! auditory filters with [`gammatone`].
+//!
+//! ### FIR Filter
+//!
+//! FIR filters are designed with the window method, [`firwin`] for piecewise constant bands<br/>
+//! and [`firwin2`] for arbitrary gain curves sampled at a set of frequencies.<br/>
+//! [`remez`](fn@remez) computes the equiripple filter minimizing the maximum weighted error with the Parks-McClellan algorithm,<br/>
+//! while [`firls`](fn@firls) minimizes the total weighted squared error.
 //! ### Filter coefficients
 //!
 //! Filter coefficients can be stored in several different formats:
 //!  - [`Ba`](`crate::signal::output_type::Ba`)
 //!  - [`Zpk`](`crate::signal::output_type::Zpk`)
-//!  - [`Sos`](`crate::signal::output_type::Sos`) (currently unsupported)
+//!  - [`Sos`](`crate::signal::output_type::Sos`)
+//!  - [`StateSpace`](`crate::signal::output_type::StateSpace`)
+//!
+//! ## Linear Systems
+//!
+//! Continuous and discrete-time linear time invariant systems are represented by [`Lti`] and [`Dlti`],<br/>
+//! their time response is simulated with [`lsim`], [`impulse`] and [`step`] or [`dlsim`], [`dimpulse`] and [`dstep`].<br/>
+//! Continuous systems are discretized with [`cont2discrete`].
 //!
 //! # References:
 //!
@@ -30,24 +46,44 @@ mod convolution;
 mod filter_design;
 #[allow(unused)]
 mod fir_filter_design;
+mod freq_response;
 mod signal_tools;
 
 //pub use convolution::*;
+pub use crate::tools::convolve1d;
 pub use filter_design::*;
+pub use freq_response::{
+    freqs, freqs_zpk, freqz, freqz_zpk, group_delay, group_delay_zpk, sosfreqz, FrequencyGrid,
+    FrequencyResponse, GroupDelay,
+};
 
-pub use fir_filter_design::{firwin, windows, Firwin1Filter, GenericFIRFilterSettings, WindowType};
-use ndarray::{Array, Array1, Dimension, Ix1};
-use num::Complex;
+pub use signal_tools::{
+    filtfilt, lfilter_zi, linear_filter, sosfilt, sosfilt_zi, sosfiltfilt, FiltFiltMethod, PadType,
+};
+
+pub use fir_filter_design::{
+    firls, firwin, firwin2, remez, windows, Firwin1Filter, GenericFIRFilterSettings, RemezType,
+    WindowType,
+};
+use ndarray::{Array, Array1, Axis, Dimension, Ix1};
+use num::{Complex, Float};
 
 pub mod band_filter;
+pub mod cont2discrete;
 pub mod error;
+pub mod lti;
 pub mod output_type;
 pub mod tools;
 
 pub use band_filter::{BandFilter, GenericBandFilter};
+pub use cont2discrete::{cont2discrete, DiscretizationMethod};
 pub use filter_design::GenericIIRFilterSettings;
 pub use filter_design::IIRFilterDesign;
 pub use filter_design::{OrdCompute, OrdResult};
+pub use lti::{
+    dimpulse, dlsim, dstep, impulse, lsim, step, Dlti, GenericDlti, GenericLti, GenericLtiSystem,
+    InputInterpolation, Lti, TimeResponse,
+};
 
 pub type Sampling = GenericSampling<f64>;
 
@@ -83,16 +119,96 @@ impl<T> GenericSampling<T> {
     }
 }
 
+impl<T: Float> GenericSampling<T> {
+    /// `true` if a pole at `root` is stable, that is if it lies inside the unit circle for
+    /// digital systems or in the open left half plane for analog ones
+    ///
+    /// roots closer than a relative tolerance of `sqrt(epsilon)` to the boundary are marginally
+    /// stable and are rejected, as rounding errors could move them on either side.
+    pub fn is_stable_root(&self, root: Complex<T>) -> bool {
+        let tol = T::epsilon().sqrt();
+        match self {
+            Self::Analog => root.re < -tol * root.norm(),
+            Self::Digital { .. } => root.norm() < T::one() - tol,
+        }
+    }
+}
+
 pub trait Filter<T> {
     fn lfilter(
         &self,
         x: Array1<Complex<T>>,
         zi: Option<Array1<Complex<T>>>,
-    ) -> LFilterOutput<T, Ix1>;
+    ) -> LFilterOutput<Complex<T>, Ix1>;
+
+    /// Filter every lane of an N-dimensional signal along `axis`, e.g. channels × samples
+    ///
+    /// `zi` must have the same shape of `x` except along `axis`, where its length is
+    /// the length of the filter state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zi` and `x` have different shapes outside of `axis`
+    fn lfilter_axis<D: Dimension>(
+        &self,
+        x: Array<Complex<T>, D>,
+        axis: Axis,
+        zi: Option<Array<Complex<T>, D>>,
+    ) -> LFilterOutput<Complex<T>, D>
+    where
+        Self: Sized + Sync,
+        T: Float + Send + Sync,
+    {
+        signal_tools::lfilter_axis(self, x, axis, zi, false)
+    }
+
+    /// Parallel version of [`Filter::lfilter_axis`], lanes are filtered concurrently with rayon
+    fn par_lfilter_axis<D: Dimension>(
+        &self,
+        x: Array<Complex<T>, D>,
+        axis: Axis,
+        zi: Option<Array<Complex<T>, D>>,
+    ) -> LFilterOutput<Complex<T>, D>
+    where
+        Self: Sized + Sync,
+        T: Float + Send + Sync,
+    {
+        signal_tools::lfilter_axis(self, x, axis, zi, true)
+    }
+}
+
+/// Filtering of real signals with real coefficients
+///
+/// avoids the complex arithmetic of [`Filter`], the output has the same values
+/// as [`Filter::lfilter`] without the imaginary parts.
+pub trait RealFilter<T> {
+    /// # Errors
+    ///
+    /// Fails if the filter has complex coefficients
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, error::Error>;
+}
+
+/// Zero-phase forward-backward filtering, see [`filtfilt`]
+pub trait FiltFilt<T>: Filter<T> {
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, error::Error>;
 }
 
+/// Output of a filtering operation
+///
+/// `zi` holds the final filter state, it can be passed as the initial state of the next call to
+/// filter a long signal in chunks, its dimension `Z` defaults to the dimension of the signal.
+///
+/// `A` is the element type, `Complex<T>` for [`Filter`] and `T` for [`RealFilter`]
 #[derive(Debug, Clone)]
-pub struct LFilterOutput<T, D: Dimension> {
-    pub filtered: Array<Complex<T>, D>,
-    pub zi: Option<Array<Complex<T>, D>>,
+pub struct LFilterOutput<A, D: Dimension, Z: Dimension = D> {
+    pub filtered: Array<A, D>,
+    pub zi: Option<Array<A, Z>>,
 }
@@ -1,9 +1,15 @@
 use crate::{
-    signal::{signal_tools::linear_filter, tools::zpk2ba},
+    signal::{
+        error::Error,
+        filtfilt,
+        signal_tools::linear_filter,
+        tools::{ba, normalize, tf2zpk, zpk2ba},
+        FiltFilt, FiltFiltMethod, GenericSampling, RealFilter,
+    },
     tools::convolve1d,
 };
-use ndarray::{Array1, Ix1};
-use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, Zero};
+use ndarray::{s, Array1, Ix1};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, Num, Zero};
 
 use super::{Filter, GenericBa, GenericZpk, LFilterOutput};
 
@@ -30,34 +36,161 @@ fn sub(input: &mut [Complex<f64>], other: &[Complex<f64>]) {
 }
 
 impl<T: Float> Filter<T> for GenericBa<T> {
+    /// Filter data along one-dimension with an IIR or FIR filter
+    ///
+    /// `zi` is the initial state of the filter delays, it must have length
+    /// `max(len(a), len(b)) - 1`, if `None` the filter starts at rest.
+    /// The returned [`LFilterOutput::zi`] holds the final state, passing it to the
+    /// next call filters a long signal in chunks with the same result as a single call.
     fn lfilter(
         &self,
         x: ndarray::Array1<Complex<T>>,
         zi: Option<ndarray::Array1<Complex<T>>>,
-    ) -> LFilterOutput<T, Ix1> {
-        let b = self.b.clone();
-        let a = self.a.clone();
-
-        let zi = if let Some(zi) = zi {
-            zi
-        } else {
-            Array1::zeros(b.raw_dim() - 1)
-        };
+    ) -> LFilterOutput<Complex<T>, Ix1> {
+        lfilter_ba(self.b.clone(), self.a.clone(), x, zi)
+    }
+}
 
-        if a.len() == 1 {
+impl<T: Float> RealFilter<T> for GenericBa<T> {
+    /// Real valued version of [`Filter::lfilter`]
+    ///
+    /// # Errors
+    ///
+    /// Fails if the filter has complex coefficients or if `zi` doesn't have length
+    /// `max(len(a), len(b)) - 1`
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, Error> {
+        let (b, a) = self.real_coefficients().ok_or(Error::ComplexCoefficients)?;
+        let expected = b.len().max(a.len()) - 1;
+        if let Some(len) = zi.as_ref().map(Array1::len).filter(|len| *len != expected) {
+            return Err(Error::BaInitialState { expected, len });
+        }
+        Ok(lfilter_ba(b, a, x, zi))
+    }
+}
+
+fn lfilter_ba<E: Num + Copy>(
+    b: Array1<E>,
+    a: Array1<E>,
+    x: Array1<E>,
+    zi: Option<Array1<E>>,
+) -> LFilterOutput<E, Ix1> {
+    match zi {
+        None if a.len() == 1 && b.len() <= x.len() => {
             let b = b.mapv(|e| e / a[0]);
 
             let out_full = convolve1d(x.view(), b.view());
+            let zi = fir_final_state(&b, &x);
 
             LFilterOutput {
                 filtered: out_full,
-                zi: None,
+                zi: Some(zi),
             }
-        } else {
-            LFilterOutput {
-                filtered: linear_filter(b, a, x, zi),
-                zi: None,
+        }
+        zi => {
+            let zi = zi.unwrap_or_else(|| Array1::zeros(b.len().max(a.len()) - 1));
+            linear_filter(b, a, x, zi)
+        }
+    }
+}
+
+impl<T: Float> FiltFilt<T> for GenericBa<T> {
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, Error> {
+        filtfilt(self, x, method)
+    }
+}
+
+impl<T: Float> GenericBa<T> {
+    /// See [`normalize`](crate::signal::tools::normalize)
+    ///
+    /// # Errors
+    ///
+    /// Fails if all the coefficients of the denominator are zero
+    pub fn normalize(&self) -> Result<Self, ba::Error> {
+        normalize(self.clone())
+    }
+
+    /// `true` if all the poles are stable, see [`GenericZpk::is_stable`]
+    ///
+    /// a transfer function with a zero denominator is never stable
+    pub fn is_stable(&self, sampling: GenericSampling<T>) -> bool {
+        tf2zpk(self.clone()).is_ok_and(|zpk| zpk.is_stable(sampling))
+    }
+
+    /// `true` if the filter and its inverse are stable, see [`GenericZpk::is_minimum_phase`]
+    pub fn is_minimum_phase(&self, sampling: GenericSampling<T>) -> bool {
+        tf2zpk(self.clone()).is_ok_and(|zpk| zpk.is_minimum_phase(sampling))
+    }
+
+    /// `true` if the filter is FIR and its numerator is symmetric or antisymmetric
+    ///
+    /// leading and trailing zeros of the numerator only add a delay and are ignored,
+    /// coefficients are compared with a tolerance relative to the largest one.
+    pub fn is_linear_phase(&self) -> bool {
+        if self.a.iter().filter(|a| !a.is_zero()).count() != 1 {
+            return false;
+        }
+
+        let max = self.b.iter().fold(T::zero(), |acc, b| acc.max(b.norm()));
+        let tol = T::epsilon().sqrt() * max;
+        let first = self.b.iter().position(|b| b.norm() > tol);
+        let last = self.b.iter().rposition(|b| b.norm() > tol);
+        let (Some(first), Some(last)) = (first, last) else {
+            return true;
+        };
+
+        let b = self.b.slice(s![first..=last]);
+        let pairs = || b.iter().zip(b.iter().rev());
+        pairs().all(|(l, r)| (l - r).norm() <= tol) || pairs().all(|(l, r)| (l + r).norm() <= tol)
+    }
+
+    /// Gain of the filter at zero frequency, `H(s = 0)` for analog filters and `H(z = 1)`
+    /// for digital ones
+    pub fn dc_gain(&self, sampling: GenericSampling<T>) -> Complex<T> {
+        match sampling {
+            GenericSampling::Analog => {
+                let last = |c: &Array1<Complex<T>>| c.last().copied().unwrap_or_else(Complex::zero);
+                last(&self.b) / last(&self.a)
             }
+            GenericSampling::Digital { .. } => self.b.sum() / self.a.sum(),
         }
     }
+
+    /// Gain of a digital filter at the Nyquist frequency, `H(z = -1)`, `None` for analog filters
+    pub fn nyquist_gain(&self, sampling: GenericSampling<T>) -> Option<Complex<T>> {
+        let alternating = |c: &Array1<Complex<T>>| {
+            c.iter()
+                .enumerate()
+                .fold(Complex::<T>::zero(), |acc, (i, c)| {
+                    if i % 2 == 0 {
+                        acc + *c
+                    } else {
+                        acc - *c
+                    }
+                })
+        };
+        match sampling {
+            GenericSampling::Analog => None,
+            GenericSampling::Digital { .. } => Some(alternating(&self.b) / alternating(&self.a)),
+        }
+    }
+}
+
+/// final delay values of a FIR filter started at rest
+fn fir_final_state<E: Num + Copy>(b: &Array1<E>, x: &Array1<E>) -> Array1<E> {
+    let n = x.len();
+    (1..b.len())
+        .map(|k| {
+            (k..b.len())
+                .map(|j| b[j] * x[n - 1 - (j - k)])
+                .fold(E::zero(), |acc, a| acc + a)
+        })
+        .collect()
 }
@@ -1,8 +1,13 @@
-use super::{tools::zpk2ba, Filter, LFilterOutput};
-use ndarray::{Array1, Ix1};
+use super::{
+    tools::{zpk2ba, zpk2sos, zpk2ss},
+    FiltFilt, FiltFiltMethod, Filter, GenericSampling, LFilterOutput, RealFilter,
+};
+use nalgebra::DMatrix;
+use ndarray::{Array, Array1, Array2, Dimension, Ix1};
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
 mod ba;
 mod sos;
+mod ss;
 mod zpk;
 
 #[derive(Debug, Clone, Copy)]
@@ -10,6 +15,7 @@ pub enum DesiredFilterOutput {
     Zpk,
     Ba,
     Sos,
+    StateSpace,
 }
 /// Enum containing the filter output
 pub type FilterOutput = GenericFilterOutput<f64>;
@@ -21,7 +27,9 @@ pub enum GenericFilterOutput<T> {
     /// See [Ba]
     Ba(GenericBa<T>),
     /// See [Sos]
-    Sos(Sos),
+    Sos(GenericSos<T>),
+    /// See [StateSpace]
+    StateSpace(GenericStateSpace<T>),
 }
 
 /// # Zeros and poles representation
@@ -74,33 +82,48 @@ impl<T> GenericFilterOutput<T> {
             _ => unreachable!(),
         }
     }
+
+    pub fn sos(self) -> GenericSos<T> {
+        match self {
+            Self::Sos(data) => data,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn state_space(self) -> GenericStateSpace<T> {
+        match self {
+            Self::StateSpace(data) => data,
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl FilterOutput {
+    /// Converts a zpk filter in the desired output representation
+    ///
+    /// the sampling is needed to choose the pairing strategy of [`zpk2sos`],
+    /// analog filters are always paired with [`SosPairing::Minimal`](crate::signal::tools::SosPairing::Minimal)
     pub fn get_output<T>(
         input: GenericZpk<T>,
         desired: DesiredFilterOutput,
-    ) -> GenericFilterOutput<T>
+        sampling: GenericSampling<T>,
+    ) -> Result<GenericFilterOutput<T>, crate::signal::error::Error>
     where
         T: Float + FloatConst + ComplexFloat,
     {
-        match desired {
+        Ok(match desired {
             DesiredFilterOutput::Zpk => GenericFilterOutput::Zpk(input),
             DesiredFilterOutput::Ba => GenericFilterOutput::Ba(zpk2ba(input)),
-            _ => todo!(),
-        }
+            DesiredFilterOutput::Sos => {
+                GenericFilterOutput::Sos(zpk2sos(input, None, sampling.is_analog())?)
+            }
+            DesiredFilterOutput::StateSpace => GenericFilterOutput::StateSpace(zpk2ss(input)?),
+        })
     }
 
     pub fn new(data: Zpk) -> Self {
         Self::Zpk(data)
     }
-
-    pub fn sos(self) -> Sos {
-        match self {
-            Self::Sos(data) => data,
-            _ => unreachable!(),
-        }
-    }
 }
 
 /// # Transfer function representation
@@ -145,19 +168,136 @@ impl<T: Clone> GenericBa<T> {
     }
 }
 
+impl<T: Float> GenericBa<T> {
+    /// the coefficients `(b, a)` as real arrays, `None` if any of them has a non zero imaginary part
+    pub fn real_coefficients(&self) -> Option<(Array1<T>, Array1<T>)> {
+        Some((real_parts(&self.b)?, real_parts(&self.a)?))
+    }
+}
+
+/// # Second-order sections representation
+///
+/// The sos format is a single 2D array of shape (n_sections, 6), representing a sequence of second-order
+/// transfer functions which, when cascaded in series, realize a higher-order filter with minimal numerical error.
+/// Each row corresponds to a second-order ba representation, with the first three columns providing the numerator
+/// coefficients and the last three providing the denominator coefficients:
+///
+/// ```text
+/// [[b00, b01, b02, a00, a01, a02],
+///  [b10, b11, b12, a10, a11, a12],
+///  ...]
+/// ```
+///
+/// The coefficients are typically normalized such that a0 is always 1.
+/// The section order is usually not important with floating-point computation;
+/// the filter output will be the same, regardless of the order.
+pub type Sos = GenericSos<f64>;
+
+#[derive(Debug, Clone)]
+pub struct GenericSos<T> {
+    pub sos: Array2<Complex<T>>,
+}
+
+impl<T: Clone> GenericSos<T> {
+    pub fn cast_with_fn<K>(self, f: impl Fn(T) -> K) -> GenericSos<K> {
+        GenericSos {
+            sos: self.sos.mapv(|a| Complex::new(f(a.re), f(a.im))),
+        }
+    }
+
+    /// number of second-order sections
+    pub fn n_sections(&self) -> usize {
+        self.sos.nrows()
+    }
+}
+
+impl<T: Float> GenericSos<T> {
+    /// the sections as a real array, `None` if any coefficient has a non zero imaginary part
+    pub fn real_sections(&self) -> Option<Array2<T>> {
+        real_parts(&self.sos)
+    }
+}
+
+/// # State-space representation
+///
+/// The ss format is a 4-tuple (A, B, C, D) of matrices representing the system of first-order
+/// differential equations
+///
+/// ```text
+/// dx/dt = A x + B u
+///     y = C x + D u
+/// ```
+///
+/// or, for a discrete-time system, the difference equations
+///
+/// ```text
+/// x[k + 1] = A x[k] + B u[k]
+///     y[k] = C x[k] + D u[k]
+/// ```
+///
+/// where x is the state vector of length N and the input u and the output y are scalars, so
+/// that A has shape (N, N), B (N, 1), C (1, N) and D (1, 1). Only single input single output
+/// systems are represented, the shapes are checked by [`GenericStateSpace::new`].
+///
+/// A transfer function has infinitely many state-space realizations, the conversions from
+/// [`Ba`] and [`Zpk`] return the controller canonical form, see [`tf2ss`](crate::signal::tools::tf2ss).
+pub type StateSpace = GenericStateSpace<f64>;
+
 #[derive(Debug, Clone)]
-pub struct Sos {}
+pub struct GenericStateSpace<T> {
+    pub(crate) a: DMatrix<Complex<T>>,
+    pub(crate) b: DMatrix<Complex<T>>,
+    pub(crate) c: DMatrix<Complex<T>>,
+    pub(crate) d: DMatrix<Complex<T>>,
+}
+
+fn real_parts<T: Float, D: Dimension>(data: &Array<Complex<T>, D>) -> Option<Array<T, D>> {
+    data.iter()
+        .all(|a| a.im.is_zero())
+        .then(|| data.mapv(|a| a.re))
+}
 
 impl<T: Float + FloatConst> Filter<T> for GenericFilterOutput<T> {
     fn lfilter(
         &self,
         x: Array1<Complex<T>>,
         zi: Option<Array1<Complex<T>>>,
-    ) -> LFilterOutput<T, Ix1> {
+    ) -> LFilterOutput<Complex<T>, Ix1> {
         match self {
             Self::Zpk(zpk) => zpk.lfilter(x, zi),
             Self::Ba(ba) => ba.lfilter(x, zi),
-            Self::Sos(_sos) => todo!(),
+            Self::Sos(sos) => sos.lfilter(x, zi),
+            Self::StateSpace(ss) => ss.lfilter(x, zi),
+        }
+    }
+}
+
+impl<T: Float + FloatConst> RealFilter<T> for GenericFilterOutput<T> {
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, crate::signal::error::Error> {
+        match self {
+            Self::Zpk(zpk) => zpk.lfilter_real(x, zi),
+            Self::Ba(ba) => ba.lfilter_real(x, zi),
+            Self::Sos(sos) => sos.lfilter_real(x, zi),
+            Self::StateSpace(ss) => ss.lfilter_real(x, zi),
+        }
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> FiltFilt<T> for GenericFilterOutput<T> {
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, crate::signal::error::Error> {
+        match self {
+            Self::Zpk(zpk) => zpk.filtfilt(x, method),
+            Self::Ba(ba) => ba.filtfilt(x, method),
+            Self::Sos(sos) => sos.filtfilt(x, method),
+            Self::StateSpace(ss) => ss.filtfilt(x, method),
         }
     }
 }
@@ -1 +1,126 @@
+use crate::signal::{
+    error::Error,
+    signal_tools::sections_filter,
+    sosfilt, sosfiltfilt,
+    tools::{sos2tf, sos2zpk},
+    FiltFilt, FiltFiltMethod, GenericSampling, RealFilter,
+};
+use ndarray::{s, Array1, Ix1};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, One};
 
+use super::{Filter, GenericBa, GenericSos, GenericZpk, LFilterOutput};
+
+impl<T: Float + FloatConst + ComplexFloat> From<GenericSos<T>> for GenericBa<T> {
+    fn from(value: GenericSos<T>) -> Self {
+        sos2tf(value)
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> From<GenericSos<T>> for GenericZpk<T> {
+    fn from(value: GenericSos<T>) -> Self {
+        sos2zpk(value)
+    }
+}
+
+impl<T: Float> GenericSos<T> {
+    /// `true` if the poles of all the sections are stable, see [`GenericZpk::is_stable`]
+    pub fn is_stable(&self, sampling: GenericSampling<T>) -> bool {
+        self.sections().all(|ba| ba.is_stable(sampling))
+    }
+
+    /// `true` if all the sections are minimum phase, see [`GenericZpk::is_minimum_phase`]
+    pub fn is_minimum_phase(&self, sampling: GenericSampling<T>) -> bool {
+        self.sections().all(|ba| ba.is_minimum_phase(sampling))
+    }
+
+    /// Gain of the filter at zero frequency, the product of the gains of the sections,
+    /// see [`GenericBa::dc_gain`]
+    pub fn dc_gain(&self, sampling: GenericSampling<T>) -> Complex<T> {
+        self.sections()
+            .fold(Complex::one(), |acc, ba| acc * ba.dc_gain(sampling))
+    }
+
+    /// Gain of a digital filter at the Nyquist frequency, `None` for analog filters,
+    /// see [`GenericBa::nyquist_gain`]
+    pub fn nyquist_gain(&self, sampling: GenericSampling<T>) -> Option<Complex<T>> {
+        self.sections().try_fold(Complex::one(), |acc, ba| {
+            Some(acc * ba.nyquist_gain(sampling)?)
+        })
+    }
+
+    fn sections(&self) -> impl Iterator<Item = GenericBa<T>> + '_ {
+        self.sos.outer_iter().map(|section| GenericBa {
+            b: section.slice(s![..3]).to_owned(),
+            a: section.slice(s![3..]).to_owned(),
+        })
+    }
+}
+
+impl<T: Float + FloatConst> Filter<T> for GenericSos<T> {
+    /// `zi` holds two states for each section, see [`sosfilt`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zi` doesn't hold two states for each section
+    fn lfilter(
+        &self,
+        x: Array1<Complex<T>>,
+        zi: Option<Array1<Complex<T>>>,
+    ) -> LFilterOutput<Complex<T>, Ix1> {
+        let n_sections = self.n_sections();
+        let zi = zi.map(|zi| {
+            zi.into_shape((n_sections, 2))
+                .expect("zi should contain two states for each section")
+        });
+
+        let LFilterOutput { filtered, zi } = sosfilt(self, x, zi);
+        LFilterOutput {
+            filtered,
+            zi: zi.map(Array1::from_iter),
+        }
+    }
+}
+
+impl<T: Float> RealFilter<T> for GenericSos<T> {
+    /// Real valued version of [`Filter::lfilter`], `zi` holds two states for each section
+    ///
+    /// # Errors
+    ///
+    /// Fails if the filter has complex coefficients or if `zi` doesn't hold two states for
+    /// each section
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, Error> {
+        let sos = self.real_sections().ok_or(Error::ComplexCoefficients)?;
+        let sections = sos.nrows();
+        let zi = zi
+            .map(|zi| {
+                let len = zi.len();
+                zi.into_shape((sections, 2))
+                    .map_err(|_| Error::SosInitialState { sections, len })
+            })
+            .transpose()?;
+
+        let LFilterOutput { filtered, zi } = sections_filter(&sos, x, zi);
+        Ok(LFilterOutput {
+            filtered,
+            zi: zi.map(Array1::from_iter),
+        })
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> FiltFilt<T> for GenericSos<T> {
+    /// [`FiltFiltMethod::Gust`] is applied to the equivalent [`GenericBa`] filter
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, Error> {
+        match method {
+            FiltFiltMethod::Pad { padtype, padlen } => sosfiltfilt(self, x, padtype, padlen),
+            FiltFiltMethod::Gust { .. } => GenericBa::from(self.clone()).filtfilt(x, method),
+        }
+    }
+}
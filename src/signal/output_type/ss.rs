@@ -0,0 +1,219 @@
+use super::{Filter, GenericBa, GenericSos, GenericStateSpace, GenericZpk, LFilterOutput};
+use crate::signal::{
+    error::Error,
+    tools::{
+        sos2tf,
+        ss::{self, map_matrix},
+        ss2tf, ss2zpk, tf2ss, zpk2ss,
+    },
+    FiltFilt, FiltFiltMethod, RealFilter,
+};
+use nalgebra::DMatrix;
+use ndarray::{Array1, Ix1};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, Num};
+
+impl<T: Clone> GenericStateSpace<T> {
+    pub fn cast_with_fn<K>(self, f: impl Fn(T) -> K) -> GenericStateSpace<K> {
+        let cast = |m: &DMatrix<Complex<T>>| {
+            map_matrix(m, |a| Complex::new(f(a.re.clone()), f(a.im.clone())))
+        };
+
+        GenericStateSpace {
+            a: cast(&self.a),
+            b: cast(&self.b),
+            c: cast(&self.c),
+            d: cast(&self.d),
+        }
+    }
+
+    /// Build a single input single output system from its matrices
+    ///
+    /// # Errors
+    ///
+    /// Fails if the system has more than one input or output or if the shapes of the matrices
+    /// are inconsistent
+    pub fn new(
+        a: DMatrix<Complex<T>>,
+        b: DMatrix<Complex<T>>,
+        c: DMatrix<Complex<T>>,
+        d: DMatrix<Complex<T>>,
+    ) -> Result<Self, ss::Error> {
+        if b.ncols() != 1 || c.nrows() != 1 {
+            return Err(ss::Error::NotSiso {
+                inputs: b.ncols(),
+                outputs: c.nrows(),
+            });
+        }
+        let n = a.nrows();
+        if a.ncols() != n || b.nrows() != n || c.ncols() != n || d.shape() != (1, 1) {
+            return Err(ss::Error::Shape {
+                a: a.shape(),
+                b: b.shape(),
+                c: c.shape(),
+                d: d.shape(),
+            });
+        }
+
+        Ok(Self { a, b, c, d })
+    }
+
+    /// the state matrix `A`
+    pub const fn a(&self) -> &DMatrix<Complex<T>> {
+        &self.a
+    }
+
+    /// the input matrix `B`
+    pub const fn b(&self) -> &DMatrix<Complex<T>> {
+        &self.b
+    }
+
+    /// the output matrix `C`
+    pub const fn c(&self) -> &DMatrix<Complex<T>> {
+        &self.c
+    }
+
+    /// the feedthrough matrix `D`
+    pub const fn d(&self) -> &DMatrix<Complex<T>> {
+        &self.d
+    }
+
+    /// length of the state vector
+    pub fn n_states(&self) -> usize {
+        self.a.nrows()
+    }
+}
+
+impl<T: Float> GenericStateSpace<T> {
+    /// the matrices `(A, B, C, D)` as real matrices, `None` if any element has a non zero imaginary part
+    #[allow(clippy::type_complexity)]
+    pub fn real_matrices(&self) -> Option<(DMatrix<T>, DMatrix<T>, DMatrix<T>, DMatrix<T>)> {
+        let real = |m: &DMatrix<Complex<T>>| {
+            m.as_slice()
+                .iter()
+                .all(|a| a.im.is_zero())
+                .then(|| map_matrix(m, |a| a.re))
+        };
+
+        Some((
+            real(&self.a)?,
+            real(&self.b)?,
+            real(&self.c)?,
+            real(&self.d)?,
+        ))
+    }
+}
+
+impl<T: Float> TryFrom<GenericBa<T>> for GenericStateSpace<T> {
+    type Error = ss::Error;
+
+    fn try_from(value: GenericBa<T>) -> Result<Self, Self::Error> {
+        tf2ss(value)
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> TryFrom<GenericZpk<T>> for GenericStateSpace<T> {
+    type Error = ss::Error;
+
+    fn try_from(value: GenericZpk<T>) -> Result<Self, Self::Error> {
+        zpk2ss(value)
+    }
+}
+
+impl<T: Float + FloatConst + ComplexFloat> TryFrom<GenericSos<T>> for GenericStateSpace<T> {
+    type Error = ss::Error;
+
+    fn try_from(value: GenericSos<T>) -> Result<Self, Self::Error> {
+        tf2ss(sos2tf(value))
+    }
+}
+
+impl<T: Float> TryFrom<GenericStateSpace<T>> for GenericBa<T> {
+    type Error = ss::Error;
+
+    fn try_from(value: GenericStateSpace<T>) -> Result<Self, Self::Error> {
+        ss2tf(value)
+    }
+}
+
+impl<T: Float> TryFrom<GenericStateSpace<T>> for GenericZpk<T> {
+    type Error = ss::Error;
+
+    fn try_from(value: GenericStateSpace<T>) -> Result<Self, Self::Error> {
+        ss2zpk(value)
+    }
+}
+
+impl<T: Float> Filter<T> for GenericStateSpace<T> {
+    /// Filter with the difference equations of the system, `zi` is the initial state vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zi` doesn't hold one entry for each state
+    fn lfilter(
+        &self,
+        x: Array1<Complex<T>>,
+        zi: Option<Array1<Complex<T>>>,
+    ) -> LFilterOutput<Complex<T>, Ix1> {
+        simulate((&self.a, &self.b, &self.c, &self.d), x, zi)
+            .expect("zi should contain one entry for each state")
+    }
+}
+
+impl<T: Float> RealFilter<T> for GenericStateSpace<T> {
+    /// Real valued version of [`Filter::lfilter`], `zi` is the initial state vector
+    ///
+    /// # Errors
+    ///
+    /// Fails if the system has complex matrices or if `zi` doesn't hold one entry for each
+    /// state
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, Error> {
+        let (a, b, c, d) = self.real_matrices().ok_or(Error::ComplexCoefficients)?;
+        simulate((&a, &b, &c, &d), x, zi)
+    }
+}
+
+impl<T: Float> FiltFilt<T> for GenericStateSpace<T> {
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, Error> {
+        ss2tf(self.clone())?.filtfilt(x, method)
+    }
+}
+
+/// run the difference equations `x[k + 1] = A x[k] + B u[k]` and `y[k] = C x[k] + D u[k]`
+/// from the state `zi`, the final state is returned with the output
+fn simulate<E: Num + Copy>(
+    (a, b, c, d): (&DMatrix<E>, &DMatrix<E>, &DMatrix<E>, &DMatrix<E>),
+    x: Array1<E>,
+    zi: Option<Array1<E>>,
+) -> Result<LFilterOutput<E, Ix1>, Error> {
+    let n = a.nrows();
+    let mut state = zi.unwrap_or_else(|| Array1::zeros(n));
+    if state.len() != n {
+        return Err(Error::StateSpaceInitialState {
+            states: n,
+            len: state.len(),
+        });
+    }
+
+    // nalgebra matrices are stored in column-major order
+    let (a, b, c, d) = (a.as_slice(), b.as_slice(), c.as_slice(), d[0]);
+    let filtered = x.mapv(|u| {
+        let y = (0..n).fold(d * u, |y, j| y + c[j] * state[j]);
+        state = Array1::from_shape_fn(n, |i| {
+            (0..n).fold(b[i] * u, |next, j| next + a[i + j * n] * state[j])
+        });
+        y
+    });
+
+    Ok(LFilterOutput {
+        filtered,
+        zi: Some(state),
+    })
+}
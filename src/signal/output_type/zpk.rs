@@ -1,13 +1,69 @@
 use super::{GenericBa, GenericZpk, LFilterOutput};
 use std::ops::Mul;
 
-use ndarray::Ix1;
-use num::{traits::FloatConst, Complex, Float, Num};
+use ndarray::{Array1, Ix1};
+use num::{traits::FloatConst, Complex, Float, Num, One, Zero};
 
 use super::{Filter, Zpk};
+use crate::signal::{
+    error::Error,
+    tools::{ba, tf2zpk},
+    FiltFilt, FiltFiltMethod, GenericSampling, RealFilter,
+};
 
 impl Zpk {}
 
+impl<T: Float> TryFrom<GenericBa<T>> for GenericZpk<T> {
+    type Error = ba::Error;
+
+    /// See [`tf2zpk`]
+    fn try_from(value: GenericBa<T>) -> Result<Self, Self::Error> {
+        tf2zpk(value)
+    }
+}
+
+impl<T: Float> GenericZpk<T> {
+    /// `true` if all the poles are stable, see [`GenericSampling::is_stable_root`]
+    pub fn is_stable(&self, sampling: GenericSampling<T>) -> bool {
+        self.p.iter().all(|p| sampling.is_stable_root(*p))
+    }
+
+    /// `true` if the filter is stable and all its zeros lie strictly inside the stability region,
+    /// so that its inverse is stable as well
+    pub fn is_minimum_phase(&self, sampling: GenericSampling<T>) -> bool {
+        self.is_stable(sampling) && self.z.iter().all(|z| sampling.is_stable_root(*z))
+    }
+
+    /// Gain of the filter at zero frequency, `H(s = 0)` for analog filters and `H(z = 1)`
+    /// for digital ones
+    pub fn dc_gain(&self, sampling: GenericSampling<T>) -> Complex<T> {
+        match sampling {
+            GenericSampling::Analog => self.evaluate(Complex::zero()),
+            GenericSampling::Digital { .. } => self.evaluate(Complex::one()),
+        }
+    }
+
+    /// Gain of a digital filter at the Nyquist frequency, `H(z = -1)`, `None` for analog filters
+    pub fn nyquist_gain(&self, sampling: GenericSampling<T>) -> Option<Complex<T>> {
+        match sampling {
+            GenericSampling::Analog => None,
+            GenericSampling::Digital { .. } => Some(self.evaluate(-Complex::one())),
+        }
+    }
+
+    fn evaluate(&self, x: Complex<T>) -> Complex<T> {
+        let num = self
+            .z
+            .iter()
+            .fold(Complex::<T>::one(), |acc, z| acc * (x - *z));
+        let den = self
+            .p
+            .iter()
+            .fold(Complex::<T>::one(), |acc, p| acc * (x - *p));
+        num / den * self.k
+    }
+}
+
 impl<T: Num + Copy> Mul<T> for Zpk
 where
     Complex<f64>: Mul<T>,
@@ -29,12 +85,34 @@ impl<T: Float + FloatConst> Filter<T> for GenericZpk<T> {
         &self,
         x: ndarray::Array1<Complex<T>>,
         zi: Option<ndarray::Array1<Complex<T>>>,
-    ) -> LFilterOutput<T, Ix1> {
+    ) -> LFilterOutput<Complex<T>, Ix1> {
         let ba: GenericBa<T> = self.clone().into();
         ba.lfilter(x, zi)
     }
 }
 
+impl<T: Float + FloatConst> RealFilter<T> for GenericZpk<T> {
+    fn lfilter_real(
+        &self,
+        x: Array1<T>,
+        zi: Option<Array1<T>>,
+    ) -> Result<LFilterOutput<T, Ix1>, Error> {
+        let ba: GenericBa<T> = self.clone().into();
+        ba.lfilter_real(x, zi)
+    }
+}
+
+impl<T: Float + FloatConst> FiltFilt<T> for GenericZpk<T> {
+    fn filtfilt(
+        &self,
+        x: Array1<Complex<T>>,
+        method: FiltFiltMethod,
+    ) -> Result<Array1<Complex<T>>, Error> {
+        let ba: GenericBa<T> = self.clone().into();
+        ba.filtfilt(x, method)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ndarray::Array1;
@@ -1,39 +1,525 @@
-use ndarray::Array1;
-use num::{Complex, Float};
+use super::{
+    error::Error,
+    output_type::{GenericBa, GenericSos},
+    Filter, LFilterOutput,
+};
+use nalgebra::{DMatrix, DVector};
+use ndarray::{concatenate, s, Array, Array1, Array2, Axis, Dimension, Ix1, Ix2, Zip};
+use num::{complex::Complex64, Complex, Float, Num, Zero};
 
 /// Copy-pasted from scipy, this can probably be optimized
-pub(crate) fn c_filt<T: Float>(
-    mut b: Array1<Complex<T>>,
-    mut a: Array1<Complex<T>>,
-    signal: Array1<Complex<T>>,
-    mut filter_state: Array1<Complex<T>>,
-) -> Array1<Complex<T>> {
-    let a0 = a[0];
-
-    b = b.mapv(|a| a / a0);
-    a = a.mapv(|a| a / a0);
+///
+/// returns the filtered signal and the final filter state
+pub(crate) fn c_filt<E: Num + Copy>(
+    b: Array1<E>,
+    a: Array1<E>,
+    signal: Array1<E>,
+    mut filter_state: Array1<E>,
+) -> (Array1<E>, Array1<E>) {
+    let (b, a) = normalize_ba(b, a);
+    let n = b.len();
 
-    let mut ret = Array1::<Complex<T>>::zeros(signal.raw_dim());
+    let mut ret = Array1::<E>::zeros(signal.raw_dim());
 
     let len_signal = signal.len();
 
     for i in 0..len_signal {
-        if b.len() > 1 {
+        if n > 1 {
             ret[i] = filter_state[0] + signal[i] * b[0];
-            for n in 1..(b.len() - 1) {
-                filter_state[n - 1] = filter_state[n] + signal[i] * b[n] - ret[i] * a[n];
+            for k in 1..(n - 1) {
+                filter_state[k - 1] = filter_state[k] + signal[i] * b[k] - ret[i] * a[k];
             }
-            filter_state[b.len() - 2] = signal[i] * b[b.len() - 1] - ret[i] * a[b.len() - 1];
+            filter_state[n - 2] = signal[i] * b[n - 1] - ret[i] * a[n - 1];
+        } else {
+            ret[i] = signal[i] * b[0];
+        }
+    }
+    (ret, filter_state)
+}
+
+/// Filter data along one-dimension with an IIR or FIR filter
+///
+/// the filter is implemented with the transposed direct form II structure,
+/// `filter_state` must have length `max(len(a), len(b)) - 1`, the returned
+/// [`LFilterOutput::zi`] contains the final filter state.
+///
+/// the element type can be real or complex, real signals filtered with real coefficients
+/// avoid the complex arithmetic.
+pub fn linear_filter<E: Num + Copy>(
+    b: Array1<E>,
+    a: Array1<E>,
+    signal: Array1<E>,
+    filter_state: Array1<E>,
+) -> LFilterOutput<E, Ix1> {
+    let (filtered, zi) = c_filt(b, a, signal, filter_state);
+    LFilterOutput {
+        filtered,
+        zi: Some(zi),
+    }
+}
+
+/// Filter every lane of `x` along `axis` with [`Filter::lfilter`]
+///
+/// `zi` must have the same shape of `x` except along `axis`, where its length is
+/// the length of the filter state, the lanes are filtered in parallel if `parallel` is set.
+///
+/// # Panics
+///
+/// Panics if `zi` and `x` have different shapes outside of `axis`
+pub fn lfilter_axis<T, D, F>(
+    filter: &F,
+    x: Array<Complex<T>, D>,
+    axis: Axis,
+    zi: Option<Array<Complex<T>, D>>,
+    parallel: bool,
+) -> LFilterOutput<Complex<T>, D>
+where
+    T: Float + Send + Sync,
+    D: Dimension,
+    F: Filter<T> + Sync,
+{
+    let lanes = x.lanes(axis);
+    let results = match (zi.as_ref(), parallel) {
+        (None, false) => Zip::from(lanes).map_collect(|x| filter.lfilter(x.to_owned(), None)),
+        (None, true) => Zip::from(lanes).par_map_collect(|x| filter.lfilter(x.to_owned(), None)),
+        (Some(zi), false) => Zip::from(lanes)
+            .and(zi.lanes(axis))
+            .map_collect(|x, zi| filter.lfilter(x.to_owned(), Some(zi.to_owned()))),
+        (Some(zi), true) => Zip::from(lanes)
+            .and(zi.lanes(axis))
+            .par_map_collect(|x, zi| filter.lfilter(x.to_owned(), Some(zi.to_owned()))),
+    };
+
+    let mut filtered = Array::zeros(x.raw_dim());
+    Zip::from(filtered.lanes_mut(axis))
+        .and(&results)
+        .for_each(|mut lane, result| lane.assign(&result.filtered));
+
+    let states = results
+        .iter()
+        .map(|result| result.zi.as_ref())
+        .collect::<Option<Vec<_>>>();
+    let zi = states.and_then(|states| {
+        // the length of the final state is only known once a lane has been filtered
+        let mut dim = x.raw_dim();
+        dim[axis.index()] = states.first()?.len();
+
+        let mut zi = Array::zeros(dim);
+        for (mut lane, state) in zi.lanes_mut(axis).into_iter().zip(states) {
+            lane.assign(state);
+        }
+        Some(zi)
+    });
+
+    LFilterOutput { filtered, zi }
+}
+
+/// Construct initial conditions for lfilter for step response steady-state
+///
+/// Compute an initial state `zi` for the [`Filter::lfilter`](super::Filter::lfilter) function
+/// that corresponds to the steady state of the step response.
+/// A typical use of this function is to set the initial state so that the output of
+/// the filter starts at the same value as the first element of the signal to be filtered.
+///
+/// the returned state has length `max(len(a), len(b)) - 1`
+pub fn lfilter_zi<T: Float>(ba: &GenericBa<T>) -> Array1<Complex<T>> {
+    let (b, a) = normalize_ba(ba.b.clone(), ba.a.clone());
+    let n = b.len();
+
+    // at steady state the output of a step is the gain at omega = 0, solving
+    // zi = A^T zi + B backwards from the last delay gives the same result as
+    // inverting (I - A^T)
+    let gain = b.sum() / a.sum();
+    let mut zi = Array1::zeros(n - 1);
+    let mut acc = Complex::zero();
+    for k in (1..n).rev() {
+        acc = acc + b[k] - a[k] * gain;
+        zi[k - 1] = acc;
+    }
+    zi
+}
+
+/// strips the leading zeros of `a`, normalizes both polynomials by `a[0]`
+/// and pads the shortest one with zeros so that they have the same length
+fn normalize_ba<E: Num + Copy>(b: Array1<E>, a: Array1<E>) -> (Array1<E>, Array1<E>) {
+    let first = a.iter().position(|a| !a.is_zero()).unwrap_or(0);
+    let a0 = a[first];
+    let n = b.len().max(a.len() - first);
+
+    let mut b_norm = Array1::zeros(n);
+    let mut a_norm = Array1::zeros(n);
+    b_norm.slice_mut(s![..b.len()]).assign(&b.mapv(|b| b / a0));
+    a_norm
+        .slice_mut(s![..a.len() - first])
+        .assign(&a.slice(s![first..]).mapv(|a| a / a0));
+    (b_norm, a_norm)
+}
+
+/// Type of extension used to pad a signal before forward-backward filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadType {
+    /// odd extension, reflects the signal around its end points and negates it
+    Odd,
+    /// even extension, reflects the signal around its end points
+    Even,
+    /// constant extension, repeats the end points of the signal
+    Constant,
+}
+
+/// Filter data along one dimension using cascaded second-order sections
+///
+/// Filter a data sequence, `x`, using a digital IIR filter defined by `sos`, each section is applied
+/// with the transposed direct form II structure.
+///
+/// `zi` are the initial conditions for the cascaded filter delays, it has shape `(n_sections, 2)`,
+/// if `None` the filter starts at rest.
+/// The returned [`LFilterOutput::zi`] always contains the final filter delay values, so that a long
+/// signal can be filtered in chunks.
+///
+/// sections are normalized by their a0 coefficient.
+pub fn sosfilt<T: Float>(
+    sos: &GenericSos<T>,
+    x: Array1<Complex<T>>,
+    zi: Option<Array2<Complex<T>>>,
+) -> LFilterOutput<Complex<T>, Ix1, Ix2> {
+    sections_filter(&sos.sos, x, zi)
+}
+
+/// filters `x` with the cascaded second-order sections `sos`, see [`sosfilt`]
+pub(super) fn sections_filter<E: Num + Copy>(
+    sos: &Array2<E>,
+    x: Array1<E>,
+    zi: Option<Array2<E>>,
+) -> LFilterOutput<E, Ix1, Ix2> {
+    let mut zi = zi.unwrap_or_else(|| Array2::zeros((sos.nrows(), 2)));
+    let sos = normalize_sections(sos);
+
+    // the closure carries the filter state, `iter` guarantees that the samples are visited
+    // in logical order even when `x` is not in standard layout
+    let filtered = Array1::from_iter(x.iter().map(|&x| {
+        sos.rows()
+            .into_iter()
+            .zip(zi.rows_mut())
+            .fold(x, |x, (section, mut zi)| {
+                let y = section[0] * x + zi[0];
+                zi[0] = section[1] * x - section[4] * y + zi[1];
+                zi[1] = section[2] * x - section[5] * y;
+                y
+            })
+    }));
+
+    LFilterOutput {
+        filtered,
+        zi: Some(zi),
+    }
+}
+
+/// Construct initial conditions for [`sosfilt`] for step response steady-state
+///
+/// Compute an initial state `zi` for the [`sosfilt`] function that corresponds
+/// to the steady state of the step response.
+/// A typical use of this function is to set the initial state so that the output of
+/// the filter starts at the same value as the first element of the signal to be filtered.
+pub fn sosfilt_zi<T: Float>(sos: &GenericSos<T>) -> Array2<Complex<T>> {
+    let mut zi = Array2::zeros((sos.n_sections(), 2));
+    let mut scale = Complex::from(T::one());
+
+    for (section, mut zi) in sos.sos.rows().into_iter().zip(zi.rows_mut()) {
+        let ba = GenericBa {
+            b: section.slice(s![..3]).to_owned(),
+            a: section.slice(s![3..]).to_owned(),
+        };
+        zi.assign(&lfilter_zi(&ba).mapv(|a| a * scale));
+
+        // b.sum() / a.sum() is the gain at omega = 0, the steady state of the section's step response
+        scale = scale * ba.b.sum() / ba.a.sum();
+    }
+    zi
+}
+
+/// A forward-backward digital filter using cascaded second-order sections
+///
+/// The combined filter has zero phase and a filter order twice that of the original.
+///
+/// The signal is extended at both ends by `padlen` samples using `padtype` before filtering,
+/// if `padlen` is `None` it defaults to three times the number of taps of the filter,
+/// with `padtype` `None` no extension is used.
+/// The initial state of each pass is the steady state of the step response scaled by the
+/// first sample.
+///
+/// # Errors
+///
+/// Fails if the signal is not longer than `padlen`
+pub fn sosfiltfilt<T: Float>(
+    sos: &GenericSos<T>,
+    x: Array1<Complex<T>>,
+    padtype: Option<PadType>,
+    padlen: Option<usize>,
+) -> Result<Array1<Complex<T>>, Error> {
+    let n_sections = sos.n_sections();
+    let zero_b2 = sos.sos.column(2).iter().filter(|a| a.is_zero()).count();
+    let zero_a2 = sos.sos.column(5).iter().filter(|a| a.is_zero()).count();
+    let ntaps = 2 * n_sections + 1 - zero_b2.min(zero_a2);
+
+    let (edge, ext) = validate_pad(x, padtype, padlen, ntaps)?;
+
+    let zi = sosfilt_zi(sos);
+
+    let x0 = ext[0];
+    let y = sosfilt(sos, ext, Some(zi.mapv(|a| a * x0))).filtered;
+
+    let y0 = y[y.len() - 1];
+    let y = reversed(sosfilt(sos, reversed(y), Some(zi.mapv(|a| a * y0))).filtered);
+
+    Ok(y.slice(s![edge..y.len() - edge]).to_owned())
+}
+
+/// Method used by [`filtfilt`] to handle the edges of the signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiltFiltMethod {
+    /// the signal is padded before filtering, see [`PadType`]
+    ///
+    /// if `padlen` is `None` it defaults to three times the number of taps of the filter,
+    /// with `padtype` `None` no extension is used.
+    Pad {
+        padtype: Option<PadType>,
+        padlen: Option<usize>,
+    },
+    /// Gustafsson's method, the initial conditions of the forward and backward passes are
+    /// chosen so that the result is the same as filtering backward-forward
+    ///
+    /// `irlen` is the length of the impulse response of the filter, if `None` or if the signal
+    /// is shorter than `2 * irlen` the whole impulse response is used
+    Gust { irlen: Option<usize> },
+}
+
+impl Default for FiltFiltMethod {
+    fn default() -> Self {
+        Self::Pad {
+            padtype: Some(PadType::Odd),
+            padlen: None,
         }
     }
-    ret
 }
 
-pub fn linear_filter<T: Float>(
-    b: Array1<Complex<T>>,
-    a: Array1<Complex<T>>,
-    signal: Array1<Complex<T>>,
-    filter_state: Array1<Complex<T>>,
+/// Apply a digital filter forward and backward to a signal
+///
+/// The combined filter has zero phase and a filter order twice that of the original.
+///
+/// With [`FiltFiltMethod::Pad`] the signal is extended at both ends before filtering and the
+/// initial state of each pass is the steady state of the step response scaled by the
+/// first sample, with [`FiltFiltMethod::Gust`] Gustafsson's method is used instead.
+///
+/// # Errors
+///
+/// Fails if the signal is not longer than `padlen`
+pub fn filtfilt<T: Float>(
+    ba: &GenericBa<T>,
+    x: Array1<Complex<T>>,
+    method: FiltFiltMethod,
+) -> Result<Array1<Complex<T>>, Error> {
+    let (padtype, padlen) = match method {
+        FiltFiltMethod::Pad { padtype, padlen } => (padtype, padlen),
+        FiltFiltMethod::Gust { irlen } => return Ok(filtfilt_gust(ba, x, irlen)),
+    };
+
+    let ntaps = ba.a.len().max(ba.b.len());
+    let (edge, ext) = validate_pad(x, padtype, padlen, ntaps)?;
+
+    let zi = lfilter_zi(ba);
+
+    let x0 = ext[0];
+    let y = linear_filter(ba.b.clone(), ba.a.clone(), ext, zi.mapv(|a| a * x0)).filtered;
+
+    let y0 = y[y.len() - 1];
+    let y = reversed(
+        linear_filter(ba.b.clone(), ba.a.clone(), reversed(y), zi.mapv(|a| a * y0)).filtered,
+    );
+
+    Ok(y.slice(s![edge..y.len() - edge]).to_owned())
+}
+
+/// Forward-backward filter using Gustafsson's method
+///
+/// F. Gustafsson, "Determining the initial states in forward-backward filtering",
+/// Transactions on Signal Processing, Vol. 46, pp. 988-992, 1996.
+fn filtfilt_gust<T: Float>(
+    ba: &GenericBa<T>,
+    x: Array1<Complex<T>>,
+    irlen: Option<usize>,
 ) -> Array1<Complex<T>> {
-    c_filt(b, a, signal, filter_state)
+    let order = ba.a.len().max(ba.b.len()) - 1;
+    if order == 0 {
+        // the filter is just a scalar multiplication, with no state
+        let scale = ba.b[0] / ba.a[0];
+        return x.mapv(|x| x * scale * scale);
+    }
+
+    let filt = |x: Array1<Complex<T>>, zi: Array1<Complex<T>>| {
+        linear_filter(ba.b.clone(), ba.a.clone(), x, zi).filtered
+    };
+
+    let n = x.len();
+    let m = match irlen {
+        Some(irlen) if n > 2 * irlen => irlen,
+        _ => n,
+    };
+
+    // observability matrix, propagates an arbitrary initial state to the output
+    // assuming the input is zero
+    let mut obs = Array2::zeros((m, order));
+    let mut zi = Array1::zeros(order);
+    zi[0] = Complex::from(T::one());
+    obs.column_mut(0).assign(&filt(Array1::zeros(m), zi));
+    for k in 1..order {
+        let col = obs.slice(s![..m - k, 0]).to_owned();
+        obs.slice_mut(s![k.., k]).assign(&col);
+    }
+    let obsr = obs.slice(s![..;-1, ..]).to_owned();
+
+    // applies the filter to the reversed propagated initial conditions
+    let mut s = Array2::zeros((m, order));
+    for (mut s, obsr) in s.columns_mut().into_iter().zip(obsr.columns()) {
+        s.assign(&filt(obsr.to_owned(), Array1::zeros(order)));
+    }
+    let sr = s.slice(s![..;-1, ..]).to_owned();
+
+    let block = |left: Array2<Complex<T>>, right: Array2<Complex<T>>| {
+        if m == n {
+            concatenate![Axis(1), left, right]
+        } else {
+            let mut out = Array2::zeros((2 * m, 2 * order));
+            out.slice_mut(s![..m, ..order]).assign(&left);
+            out.slice_mut(s![m.., order..]).assign(&right);
+            out
+        }
+    };
+    let mat = block(&sr - &obs, &obsr - &s);
+    let w = block(sr, obsr);
+
+    // naive forward-backward and backward-forward filters, they have
+    // large transients because they use zero initial conditions
+    let zeros = || Array1::zeros(order);
+    let y_f = filt(x.clone(), zeros());
+    let y_fb = reversed(filt(reversed(y_f), zeros()));
+    let y_b = reversed(filt(reversed(x), zeros()));
+    let y_bf = filt(y_b, zeros());
+
+    let delta = y_bf - &y_fb;
+    let delta = if m == n {
+        delta
+    } else {
+        concatenate![Axis(0), delta.slice(s![..m]), delta.slice(s![n - m..])]
+    };
+
+    let ic_opt = lstsq(&mat, &delta);
+    let wic: Array1<_> = w
+        .rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .zip(ic_opt.iter())
+                .fold(Complex::zero(), |acc, (w, ic)| acc + *w * ic)
+        })
+        .collect();
+
+    let mut y_opt = y_fb;
+    if m == n {
+        y_opt = y_opt + wic;
+    } else {
+        y_opt
+            .slice_mut(s![..m])
+            .zip_mut_with(&wic.slice(s![..m]), |y, w| *y = *y + w);
+        y_opt
+            .slice_mut(s![n - m..])
+            .zip_mut_with(&wic.slice(s![m..]), |y, w| *y = *y + w);
+    }
+    y_opt
+}
+
+/// least squares solution of `a x = b` computed with the singular value decomposition of `a`
+fn lstsq<T: Float>(a: &Array2<Complex<T>>, b: &Array1<Complex<T>>) -> Array1<Complex<T>> {
+    let to_c64 = |a: &Complex<T>| {
+        Complex64::new(
+            a.re.to_f64().unwrap_or(f64::NAN),
+            a.im.to_f64().unwrap_or(f64::NAN),
+        )
+    };
+    let (rows, cols) = a.dim();
+    let mat = DMatrix::from_fn(rows, cols, |i, j| to_c64(&a[[i, j]]));
+    let rhs = DVector::from_iterator(b.len(), b.iter().map(to_c64));
+
+    let svd = mat.svd(true, true);
+    let eps = svd.singular_values.max() * f64::EPSILON * rows.max(cols) as f64;
+    let x = svd
+        .solve(&rhs, eps)
+        .expect("u and v are computed and eps is positive");
+
+    x.iter()
+        .map(|a| {
+            Complex::new(
+                T::from(a.re).unwrap_or_else(T::nan),
+                T::from(a.im).unwrap_or_else(T::nan),
+            )
+        })
+        .collect()
+}
+
+fn normalize_sections<E: Num + Copy>(sos: &Array2<E>) -> Array2<E> {
+    let mut sos = sos.clone();
+    for mut section in sos.rows_mut() {
+        let a0 = section[3];
+        section.mapv_inplace(|a| a / a0);
+    }
+    sos
+}
+
+/// reverses the signal, the result is always in standard layout
+fn reversed<T: Clone>(x: Array1<T>) -> Array1<T> {
+    x.iter().rev().cloned().collect()
+}
+
+/// pads the signal for forward-backward filtering, returns the length of the padding and the extended signal
+fn validate_pad<T: Float>(
+    x: Array1<Complex<T>>,
+    padtype: Option<PadType>,
+    padlen: Option<usize>,
+    ntaps: usize,
+) -> Result<(usize, Array1<Complex<T>>), Error> {
+    let edge = match padtype {
+        None => 0,
+        Some(_) => padlen.unwrap_or(ntaps * 3),
+    };
+
+    if x.len() <= edge {
+        return Err(Error::PadLen {
+            len: x.len(),
+            padlen: edge,
+        });
+    }
+
+    let ext = match padtype {
+        Some(padtype) if edge > 0 => {
+            let n = x.len();
+            let (first, last) = (x[0], x[n - 1]);
+            let left = x.slice(s![1..=edge;-1]);
+            let right = x.slice(s![n - 1 - edge..n - 1;-1]);
+
+            let (left, right) = match padtype {
+                PadType::Odd => (
+                    left.mapv(|a| first + first - a),
+                    right.mapv(|a| last + last - a),
+                ),
+                PadType::Even => (left.to_owned(), right.to_owned()),
+                PadType::Constant => (
+                    Array1::from_elem(edge, first),
+                    Array1::from_elem(edge, last),
+                ),
+            };
+            concatenate![Axis(0), left, x, right]
+        }
+        _ => x,
+    };
+
+    Ok((edge, ext))
 }
@@ -0,0 +1,121 @@
+use super::roots;
+use crate::signal::output_type::{GenericBa, GenericZpk};
+use ndarray::{array, Array1};
+use num::{Complex, Float, Zero};
+use thiserror::Error;
+
+/// Normalize the numerator and denominator of a transfer function
+///
+/// leading zeros of the denominator are removed and both polynomials are divided by its
+/// first coefficient, leading numerator coefficients smaller than `1e-14` are then removed
+/// keeping at least one coefficient.
+///
+/// # Errors
+///
+/// Fails if all the coefficients of the denominator are zero
+pub fn normalize<T: Float>(ba: GenericBa<T>) -> Result<GenericBa<T>, Error> {
+    let GenericBa { b, a } = ba;
+
+    let a: Array1<_> = a.iter().skip_while(|a| a.is_zero()).copied().collect();
+    let Some(&a0) = a.first() else {
+        return Err(Error::ZeroDenominator);
+    };
+
+    let tol = T::from(1e-14).unwrap();
+    let b = b.mapv(|b| b / a0);
+    let leading = b
+        .iter()
+        .take_while(|b| b.norm() <= tol)
+        .count()
+        .min(b.len().saturating_sub(1));
+
+    Ok(GenericBa {
+        b: b.iter().skip(leading).copied().collect(),
+        a: a.mapv(|a| a / a0),
+    })
+}
+
+/// Return zeros, poles, and gain of a transfer function
+///
+/// the coefficients are [`normalize`]d and the zeros and poles are computed with [`roots`],
+/// the gain is the real part of the leading numerator coefficient.
+///
+/// # Errors
+///
+/// Fails if the denominator is zero or if the roots don't converge
+pub fn tf2zpk<T: Float>(ba: GenericBa<T>) -> Result<GenericZpk<T>, Error> {
+    let GenericBa { b, a } = normalize(ba)?;
+
+    let k = b[0];
+    let z = if k.is_zero() {
+        array![]
+    } else {
+        roots(b.mapv(|b| b / k).view())?
+    };
+
+    Ok(GenericZpk {
+        z,
+        p: roots(a.view())?,
+        k: k.re,
+    })
+}
+
+/// Return a digital filter from an analog one using a bilinear transform
+///
+/// the transfer function version of [`bilinear_zpk`](super::bilinear_zpk), `s` is replaced
+/// with `2 fs (z - 1) / (z + 1)` and the result is [`normalize`]d.
+///
+/// # Errors
+///
+/// Fails if the denominator is zero
+pub fn bilinear<T: Float>(ba: GenericBa<T>, fs: T) -> Result<GenericBa<T>, Error> {
+    let GenericBa { b, a } = coefficients(ba)?;
+    let m = b.len().max(a.len()) - 1;
+    let fs2 = fs + fs;
+
+    // (s)^i = (2 fs)^i (z - 1)^i (z + 1)^(m - i) / (z + 1)^m
+    let transform = |coeffs: &Array1<Complex<T>>| -> Array1<Complex<T>> {
+        let n = coeffs.len() - 1;
+        let mut out = Array1::zeros(m + 1);
+        for i in 0..=n {
+            let c = coeffs[n - i] * fs2.powi(i as _);
+            for k in 0..=i {
+                let sign = if k % 2 == 1 { -T::one() } else { T::one() };
+                for l in 0..=m - i {
+                    out[k + l] = out[k + l] + c * (comb::<T>(i, k) * comb::<T>(m - i, l) * sign);
+                }
+            }
+        }
+        out
+    };
+
+    normalize(GenericBa {
+        b: transform(&b),
+        a: transform(&a),
+    })
+}
+
+/// coefficients of a transfer function with at least one element, an empty numerator is zero
+pub(crate) fn coefficients<T: Float>(ba: GenericBa<T>) -> Result<GenericBa<T>, Error> {
+    let GenericBa { b, a } = ba;
+    if a.is_empty() {
+        return Err(Error::ZeroDenominator);
+    }
+    let b = if b.is_empty() { Array1::zeros(1) } else { b };
+    Ok(GenericBa { b, a })
+}
+
+/// binomial coefficient `n` choose `k`
+pub(crate) fn comb<T: Float>(n: usize, k: usize) -> T {
+    (0..k.min(n - k)).fold(T::one(), |acc, i| {
+        acc * T::from(n - i).unwrap() / T::from(i + 1).unwrap()
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("the denominator of the transfer function is zero")]
+    ZeroDenominator,
+    #[error(transparent)]
+    Roots(#[from] super::Error),
+}
@@ -1,5 +1,6 @@
 use super::output_type::{GenericBa, GenericZpk};
 use crate::odr::polynomial::Polynomial;
+use nalgebra::{DMatrix, DVector, Schur};
 use ndarray::{array, concatenate, s, Array1, ArrayView1, ArrayViewMut1, Axis};
 use num::{
     complex::{Complex64, ComplexFloat},
@@ -7,6 +8,14 @@ use num::{
     Complex, Float, Num, Zero,
 };
 use std::fmt::Debug;
+use thiserror::Error;
+
+pub mod ba;
+pub use ba::{bilinear, normalize, tf2zpk};
+pub mod sos;
+pub use sos::{sos2tf, sos2zpk, zpk2sos, SosPairing};
+pub mod ss;
+pub use ss::{ss2tf, ss2zpk, tf2ss, zpk2ss};
 
 pub fn bilinear_zpk<T>(input: GenericZpk<T>, fs: T) -> GenericZpk<T>
 where
@@ -78,6 +87,120 @@ pub fn poly<T: Num + Copy>(zeroes: ArrayView1<'_, T>) -> Array1<T> {
     coeff
 }
 
+/// Multiply two polynomials, coefficients are in descending powers
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciport_rs::signal::tools::polymul;
+/// # use ndarray::array;
+/// let coeffs = polymul(array![1.0, 2.0].view(), array![1.0, -2.0].view());
+///
+/// assert_eq!(coeffs, array![1.0, 0.0, -4.0]);
+/// ```
+#[must_use]
+pub fn polymul<T: Num + Copy>(a: ArrayView1<'_, T>, b: ArrayView1<'_, T>) -> Array1<T> {
+    if a.is_empty() || b.is_empty() {
+        return array![];
+    }
+    let mut coeff = Array1::from_elem(a.len() + b.len() - 1, T::zero());
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            coeff[i + j] = coeff[i + j] + *a * *b;
+        }
+    }
+    coeff
+}
+
+/// Return the roots of a polynomial with coefficients given in descending powers
+///
+/// The roots are the eigenvalues of the companion matrix, leading zeros are ignored.
+///
+/// # Errors
+///
+/// Fails if the eigenvalues of the companion matrix don't converge
+///
+/// # Examples
+///
+/// ```rust
+/// # use sciport_rs::signal::tools::roots;
+/// # use num::complex::Complex64;
+/// # use ndarray::array;
+/// let coeffs = array![1.0, -3.0, 2.0].mapv(|a| Complex64::new(a, 0.0));
+///
+/// let mut r = roots(coeffs.view()).unwrap().mapv(|r| r.re).to_vec();
+/// r.sort_by(f64::total_cmp);
+///
+/// assert!((r[0] - 1.0).abs() < 1e-12 && (r[1] - 2.0).abs() < 1e-12);
+/// ```
+pub fn roots<T: Float>(p: ArrayView1<'_, Complex<T>>) -> Result<Array1<Complex<T>>, Error> {
+    let non_zero: Vec<_> = p
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| !a.is_zero())
+        .map(|(i, _)| i)
+        .collect();
+    let (Some(&first), Some(&last)) = (non_zero.first(), non_zero.last()) else {
+        return Ok(array![]);
+    };
+
+    // trailing zeros are roots at the origin
+    let trailing = p.len() - last - 1;
+    let p = p.slice(s![first..=last]);
+    let n = p.len() - 1;
+
+    let to_c64 = |a: Complex<T>| {
+        Complex64::new(
+            a.re.to_f64().unwrap_or(f64::NAN),
+            a.im.to_f64().unwrap_or(f64::NAN),
+        )
+    };
+    let companion = DMatrix::from_fn(n, n, |i, j| match i {
+        0 => to_c64(-p[j + 1] / p[0]),
+        _ if i == j + 1 => Complex64::new(1.0, 0.0),
+        _ => Complex64::zero(),
+    });
+
+    let values = eigenvalues(companion)?;
+
+    Ok(values
+        .iter()
+        .map(|a| {
+            Complex::new(
+                T::from(a.re).unwrap_or_else(T::nan),
+                T::from(a.im).unwrap_or_else(T::nan),
+            )
+        })
+        .chain(std::iter::repeat_n(Complex::zero(), trailing))
+        .collect())
+}
+
+/// eigenvalues of a square matrix computed from its complex Schur form, the QR iteration is
+/// stopped with an error after `MAX_ITERATIONS` iterations per eigenvalue
+pub(crate) fn eigenvalues(m: DMatrix<Complex64>) -> Result<DVector<Complex64>, Error> {
+    const MAX_ITERATIONS: usize = 30;
+
+    let n = m.nrows();
+    if n == 0 {
+        return Ok(DVector::zeros(0));
+    }
+    let iterations = MAX_ITERATIONS * n;
+    let schur = |m| Schur::try_new(m, f64::EPSILON, iterations);
+
+    // the QR iteration stalls on matrices like the companion matrix of `z^n - c`, whose
+    // eigenvalues all have the same modulus, in that case the eigenvalues are computed on a
+    // matrix made unitarily similar through a Householder reflection
+    let schur = schur(m.clone()).or_else(|| {
+        let v = DVector::from_fn(n, |i, _| Complex64::new((i + 1) as f64, 0.0)).normalize();
+        let h = DMatrix::identity(n, n) - &v * v.adjoint() * Complex64::new(2.0, 0.0);
+        schur(&h * m * &h)
+    });
+    Ok(schur
+        .ok_or(Error::NotConverged { iterations })?
+        .eigenvalues()
+        .expect("the complex Schur form is upper triangular"))
+}
+
 fn mul_by_x<T: Num + Clone>(coeff: &mut Array1<T>) {
     coeff.append(Axis(0), (&array![T::zero()]).into()).unwrap();
 }
@@ -312,3 +435,9 @@ where
         )
     });
 }
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("the eigenvalue decomposition did not converge in {iterations} iterations")]
+    NotConverged { iterations: usize },
+}
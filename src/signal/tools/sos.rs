@@ -0,0 +1,386 @@
+use super::polymul;
+use crate::signal::output_type::{GenericBa, GenericSos, GenericZpk};
+use crate::tools::complex::normalize_zeros;
+use ndarray::{array, s, Array1, Array2};
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, Zero};
+use thiserror::Error;
+
+/// Pairing strategy used by [`zpk2sos`] to combine poles and zeros into sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SosPairing {
+    /// pair every pole with the nearest zero, an odd number of poles is padded
+    /// with a pole and a zero at the origin
+    Nearest,
+    /// like [`SosPairing::Nearest`] but keeps an odd order filter with a single
+    /// first order section
+    KeepOdd,
+    /// like [`SosPairing::KeepOdd`] but never adds extra poles or zeros,
+    /// the only pairing valid for analog filters
+    Minimal,
+}
+
+/// Return second-order sections from zeros, poles, and gain of a system
+///
+/// The algorithm is the one used by scipy: poles are processed starting from the
+/// one closest to the unit circle (or to the imaginary axis for analog filters),
+/// each one is paired with its conjugate and with the nearest zeros, and the
+/// resulting sections are ordered so that the poles closest to the unit circle
+/// end up in the last section.
+///
+/// if no pairing is specified [`SosPairing::Nearest`] is used for digital filters
+/// and [`SosPairing::Minimal`] for analog ones.
+///
+/// # Errors
+///
+/// Fails if a complex zero or pole is missing its conjugate, or if an analog
+/// filter is converted with a pairing other than [`SosPairing::Minimal`] or has
+/// more zeros than poles.
+pub fn zpk2sos<T>(
+    zpk: GenericZpk<T>,
+    pairing: impl Into<Option<SosPairing>>,
+    analog: bool,
+) -> Result<GenericSos<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let pairing = pairing.into().unwrap_or(if analog {
+        SosPairing::Minimal
+    } else {
+        SosPairing::Nearest
+    });
+
+    if analog && pairing != SosPairing::Minimal {
+        return Err(Error::AnalogPairing(pairing));
+    }
+
+    let GenericZpk { z, p, k } = zpk;
+    let mut z = z.to_vec();
+    let mut p = p.to_vec();
+
+    if z.is_empty() && p.is_empty() {
+        let k = Complex::from(k);
+        let zero = Complex::zero();
+        let one = Complex::from(T::one());
+        let sos = if analog {
+            array![[zero, zero, k, zero, zero, one]]
+        } else {
+            array![[k, zero, zero, one, zero, zero]]
+        };
+        return Ok(GenericSos { sos });
+    }
+
+    let n_sections = if pairing == SosPairing::Minimal {
+        if p.len() < z.len() {
+            return Err(Error::AnalogDegree);
+        }
+        p.len().div_ceil(2)
+    } else {
+        let len = p.len().max(z.len());
+        p.resize(len, Complex::zero());
+        z.resize(len, Complex::zero());
+        if len % 2 == 1 && pairing == SosPairing::Nearest {
+            p.push(Complex::zero());
+            z.push(Complex::zero());
+        }
+        len.div_ceil(2)
+    };
+
+    // only one element of each conjugate pair is kept from now on
+    let mut z = cplxreal(z)?;
+    let mut p = cplxreal(p)?;
+
+    let idx_worst = |p: &[Complex<T>]| -> usize {
+        let distance = |a: &Complex<T>| {
+            if analog {
+                Float::abs(a.re)
+            } else {
+                Float::abs(T::one() - a.norm())
+            }
+        };
+        argmin(p.iter().map(distance))
+    };
+
+    let mut sos = Array2::zeros((n_sections, 6));
+
+    // poles closest to the unit circle go in the last sections
+    for si in (0..n_sections).rev() {
+        let p1 = p.remove(idx_worst(&p));
+        let n_real_p = p.iter().filter(|a| is_real(a)).count();
+
+        let section = if is_real(&p1) && n_real_p == 0 {
+            // last remaining real pole
+            if pairing != SosPairing::Minimal {
+                let z1 = z.remove(nearest_idx(&z, p1, Which::Real));
+                single_section(&[z1, Complex::zero()], &[p1, Complex::zero()])
+            } else if !z.is_empty() {
+                let z1 = z.remove(nearest_idx(&z, p1, Which::Real));
+                single_section(&[z1], &[p1])
+            } else {
+                single_section(&[], &[p1])
+            }
+        } else if p.len() + 1 == z.len()
+            && !is_real(&p1)
+            && n_real_p == 1
+            && z.iter().filter(|a| is_real(a)).count() == 1
+        {
+            // one real pole and one real zero left, the complex pole must
+            // be paired with a complex zero
+            let z1 = z.remove(nearest_idx(&z, p1, Which::Complex));
+            single_section(&[z1, z1.conj()], &[p1, p1.conj()])
+        } else {
+            let p2 = if is_real(&p1) {
+                let real_idx = p
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, a)| is_real(a))
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+                let reals = real_idx.iter().map(|&i| p[i]).collect::<Vec<_>>();
+                p.remove(real_idx[idx_worst(&reals)])
+            } else {
+                p1.conj()
+            };
+
+            if z.is_empty() {
+                single_section(&[], &[p1, p2])
+            } else {
+                let z1 = z.remove(nearest_idx(&z, p1, Which::Any));
+                if !is_real(&z1) {
+                    single_section(&[z1, z1.conj()], &[p1, p2])
+                } else if !z.is_empty() {
+                    let z2 = z.remove(nearest_idx(&z, p1, Which::Real));
+                    single_section(&[z1, z2], &[p1, p2])
+                } else {
+                    single_section(&[z1], &[p1, p2])
+                }
+            }
+        };
+
+        sos.row_mut(si).assign(&section);
+    }
+
+    debug_assert!(p.is_empty() && z.is_empty());
+
+    let k = Complex::from(k);
+    sos.slice_mut(s![0, ..3]).mapv_inplace(|a| a * k);
+
+    Ok(GenericSos { sos })
+}
+
+/// Return zeros, poles, and gain of a series of second-order sections
+///
+/// every section contributes exactly two zeros and two poles, sections of a lower
+/// order are padded with roots at the origin.
+pub fn sos2zpk<T>(sos: GenericSos<T>) -> GenericZpk<T>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let n_sections = sos.n_sections();
+    let mut z = Array1::zeros(2 * n_sections);
+    let mut p = Array1::zeros(2 * n_sections);
+    let mut k = T::one();
+
+    for (i, section) in sos.sos.rows().into_iter().enumerate() {
+        let b = trim_leading_zeros(section.slice(s![..3]).to_vec());
+        let a = trim_leading_zeros(section.slice(s![3..]).to_vec());
+
+        k = k * (b[0] / a[0]).re;
+
+        for (j, root) in quadratic_roots(&b).into_iter().enumerate() {
+            z[2 * i + j] = root;
+        }
+        for (j, root) in quadratic_roots(&a).into_iter().enumerate() {
+            p[2 * i + j] = root;
+        }
+    }
+
+    GenericZpk {
+        z: normalize_zeros(z),
+        p: normalize_zeros(p),
+        k,
+    }
+}
+
+/// Return a single transfer function from a series of second-order sections
+pub fn sos2tf<T>(sos: GenericSos<T>) -> GenericBa<T>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    let one = array![Complex::from(T::one())];
+
+    let (b, a) = sos
+        .sos
+        .rows()
+        .into_iter()
+        .fold((one.clone(), one), |(b, a), section| {
+            (
+                polymul(b.view(), section.slice(s![..3])),
+                polymul(a.view(), section.slice(s![3..])),
+            )
+        });
+
+    GenericBa { a, b }
+}
+
+fn is_real<T: Float>(a: &Complex<T>) -> bool {
+    a.im == T::zero()
+}
+
+fn argmin<T: Float>(values: impl Iterator<Item = T>) -> usize {
+    values
+        .enumerate()
+        .fold((0, T::infinity()), |(min_i, min), (i, v)| {
+            if v < min {
+                (i, v)
+            } else {
+                (min_i, min)
+            }
+        })
+        .0
+}
+
+#[derive(Clone, Copy)]
+enum Which {
+    Real,
+    Complex,
+    Any,
+}
+
+/// index of the element of `from` closest to `to` of the requested kind
+fn nearest_idx<T: Float>(from: &[Complex<T>], to: Complex<T>, which: Which) -> usize {
+    let mut order = (0..from.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        (from[a] - to)
+            .norm()
+            .partial_cmp(&(from[b] - to).norm())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    order
+        .into_iter()
+        .find(|&i| match which {
+            Which::Any => true,
+            Which::Real => is_real(&from[i]),
+            Which::Complex => !is_real(&from[i]),
+        })
+        .expect("pairing always leaves a root of the requested kind")
+}
+
+/// build one section from up to two zeros and two poles, with unit gain
+fn single_section<T: Float>(z: &[Complex<T>], p: &[Complex<T>]) -> Array1<Complex<T>> {
+    let mut section = Array1::zeros(6);
+    let b = real_poly(z);
+    let a = real_poly(p);
+    section.slice_mut(s![3 - b.len()..3]).assign(&b);
+    section.slice_mut(s![6 - a.len()..6]).assign(&a);
+    section
+}
+
+/// polynomial coefficients of real or conjugate roots, the imaginary part is discarded
+fn real_poly<T: Float>(roots: &[Complex<T>]) -> Array1<Complex<T>> {
+    let one = Complex::from(T::one());
+    let coeffs = match roots {
+        [] => vec![one],
+        [r] => vec![one, -r],
+        [r1, r2] => vec![one, -(r1 + r2), r1 * r2],
+        _ => unreachable!("a section has at most two roots"),
+    };
+    coeffs.into_iter().map(|a| Complex::from(a.re)).collect()
+}
+
+fn trim_leading_zeros<T: Float>(coeffs: Vec<Complex<T>>) -> Vec<Complex<T>> {
+    let tol = T::from(1e-14).unwrap();
+    let first = coeffs
+        .iter()
+        .position(|a| a.norm() > tol)
+        .unwrap_or(coeffs.len() - 1);
+    coeffs[first..].to_vec()
+}
+
+/// roots of a polynomial of degree at most two, with a non zero leading coefficient
+fn quadratic_roots<T: Float>(coeffs: &[Complex<T>]) -> Vec<Complex<T>> {
+    match *coeffs {
+        [_] => vec![],
+        [c0, c1] => vec![-c1 / c0],
+        [c0, c1, c2] => {
+            let two = T::from(2.0).unwrap();
+            let four = T::from(4.0).unwrap();
+            let mut d = (c1 * c1 - c0 * c2 * four).sqrt();
+            // avoid cancellation between c1 and the square root of the discriminant
+            if (c1.conj() * d).re < T::zero() {
+                d = -d;
+            }
+            let q = -(c1 + d) / two;
+            if q.is_zero() {
+                vec![Complex::zero(), Complex::zero()]
+            } else {
+                vec![q / c0, c2 / q]
+            }
+        }
+        _ => unreachable!("a section has at most two roots"),
+    }
+}
+
+/// Split into complex and real parts, combining conjugate pairs
+///
+/// returns the complex roots with positive imaginary part (one for each conjugate
+/// pair) followed by the real roots, both sorted by real part
+fn cplxreal<T: Float>(mut z: Vec<Complex<T>>) -> Result<Vec<Complex<T>>, Error> {
+    if z.is_empty() {
+        return Ok(z);
+    }
+    let tol = T::from(100.0).unwrap() * T::epsilon();
+
+    let cmp = |a: T, b: T| a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal);
+    z.sort_by(|a, b| cmp(a.re, b.re).then(cmp(a.im.abs(), b.im.abs())));
+
+    let (zr, z): (Vec<_>, Vec<_>) = z.into_iter().partition(|a| a.im.abs() <= tol * a.norm());
+    let zr = zr
+        .into_iter()
+        .map(|a| Complex::from(a.re))
+        .collect::<Vec<_>>();
+
+    if z.is_empty() {
+        return Ok(zr);
+    }
+
+    let (mut zp, mut zn): (Vec<_>, Vec<_>) = z.into_iter().partition(|a| a.im > T::zero());
+    if zp.len() != zn.len() {
+        return Err(Error::MissingConjugate);
+    }
+
+    // sort runs of (approximately) equal real part by their imaginary part
+    let mut start = 0;
+    while start < zp.len() {
+        let mut stop = start + 1;
+        while stop < zp.len() && zp[stop].re - zp[stop - 1].re <= tol * zp[stop - 1].norm() {
+            stop += 1;
+        }
+        zp[start..stop].sort_by(|a, b| cmp(a.im.abs(), b.im.abs()));
+        zn[start..stop].sort_by(|a, b| cmp(a.im.abs(), b.im.abs()));
+        start = stop;
+    }
+
+    if zp
+        .iter()
+        .zip(&zn)
+        .any(|(p, n)| (p - n.conj()).norm() > tol * n.norm())
+    {
+        return Err(Error::MissingConjugate);
+    }
+
+    let two = T::from(2.0).unwrap();
+    let zc = zp.iter().zip(&zn).map(|(p, n)| (p + n.conj()) / two);
+
+    Ok(zc.chain(zr).collect())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("array contains a complex value with no matching conjugate")]
+    MissingConjugate,
+    #[error("for analog zpk2sos conversion, pairing must be minimal, received {0:?}")]
+    AnalogPairing(SosPairing),
+    #[error("minimal pairing requires at least as many poles as zeros")]
+    AnalogDegree,
+}
@@ -0,0 +1,201 @@
+use super::{poly, roots, zpk2ba};
+use crate::signal::output_type::{GenericBa, GenericStateSpace, GenericZpk};
+use nalgebra::{DMatrix, Dyn, VecStorage};
+use ndarray::{array, Array1};
+use num::{
+    complex::{Complex64, ComplexFloat},
+    traits::FloatConst,
+    Complex, Float, Zero,
+};
+use thiserror::Error;
+
+/// Transfer function to state-space representation
+///
+/// returns the controller canonical form of the single input single output system,
+/// the first row of `A` holds the normalized denominator coefficients.
+///
+/// # Errors
+///
+/// Fails if the denominator is zero or if the numerator has a higher degree than the
+/// denominator, such a system has no state-space realization.
+pub fn tf2ss<T: Float>(ba: GenericBa<T>) -> Result<GenericStateSpace<T>, Error> {
+    let GenericBa { b, a } = ba;
+    let a = trim_leading_zeros(&a);
+    let b = trim_leading_zeros(&b);
+
+    let Some(&a0) = a.first() else {
+        return Err(Error::ZeroDenominator);
+    };
+    let a = a.mapv(|a| a / a0);
+    let b = b.mapv(|b| b / a0);
+
+    if b.len() > a.len() {
+        return Err(Error::ImproperTransferFunction {
+            num: b.len() - 1,
+            den: a.len() - 1,
+        });
+    }
+
+    // numerator padded to the length of the denominator
+    let k = a.len();
+    let num: Array1<_> = std::iter::repeat_n(Complex::zero(), k - b.len())
+        .chain(b.iter().copied())
+        .collect();
+    let d = matrix(1, 1, |_, _| num[0]);
+
+    if k == 1 {
+        // static gain, keep a single state that doesn't affect the output
+        return Ok(GenericStateSpace {
+            a: matrix(1, 1, |_, _| Complex::zero()),
+            b: matrix(1, 1, |_, _| Complex::zero()),
+            c: matrix(1, 1, |_, _| Complex::zero()),
+            d,
+        });
+    }
+
+    let n = k - 1;
+    Ok(GenericStateSpace {
+        a: matrix(n, n, |i, j| match i {
+            0 => -a[j + 1],
+            _ if i == j + 1 => Complex::new(T::one(), T::zero()),
+            _ => Complex::zero(),
+        }),
+        b: matrix(n, 1, |i, _| match i {
+            0 => Complex::new(T::one(), T::zero()),
+            _ => Complex::zero(),
+        }),
+        c: matrix(1, n, |_, j| num[j + 1] - num[0] * a[j + 1]),
+        d,
+    })
+}
+
+/// Zero-pole-gain representation to state-space representation
+///
+/// see [`tf2ss`]
+///
+/// # Errors
+///
+/// Fails if the system has more zeros than poles
+pub fn zpk2ss<T>(zpk: GenericZpk<T>) -> Result<GenericStateSpace<T>, Error>
+where
+    T: Float + FloatConst + ComplexFloat,
+{
+    tf2ss(zpk2ba(zpk))
+}
+
+/// State-space to transfer function representation
+///
+/// the denominator is the characteristic polynomial of `A` and the numerator is computed from
+/// the characteristic polynomial of `A - B C`, the coefficients are real if the matrices are real.
+///
+/// # Errors
+///
+/// Fails if the eigenvalues don't converge
+pub fn ss2tf<T: Float>(ss: GenericStateSpace<T>) -> Result<GenericBa<T>, Error> {
+    let one = Complex::new(T::one(), T::zero());
+    let d = ss.d[(0, 0)];
+    let n = ss.n_states();
+    if n == 0 {
+        return Ok(GenericBa {
+            b: array![d],
+            a: array![one],
+        });
+    }
+
+    let den = poly(eigenvalues(&ss.a)?.view());
+    let feedback = matrix(n, n, |i, j| ss.a[(i, j)] - ss.b[(i, 0)] * ss.c[(0, j)]);
+    let num = poly(eigenvalues(&feedback)?.view()) + den.mapv(|a| a * (d - one));
+
+    if ss.real_matrices().is_some() {
+        let real = |a: Complex<T>| Complex::new(a.re, T::zero());
+        return Ok(GenericBa {
+            b: num.mapv(real),
+            a: den.mapv(real),
+        });
+    }
+    Ok(GenericBa { b: num, a: den })
+}
+
+/// State-space to zero-pole-gain representation
+///
+/// the poles are the eigenvalues of `A` and the zeros the roots of the numerator returned by
+/// [`ss2tf`], leading numerator coefficients smaller than `1e-14` are considered zero.
+///
+/// # Errors
+///
+/// Fails if the poles or the roots of the numerator don't converge
+pub fn ss2zpk<T: Float>(ss: GenericStateSpace<T>) -> Result<GenericZpk<T>, Error> {
+    let p = eigenvalues(&ss.a)?;
+    let GenericBa { b, .. } = ss2tf(ss)?;
+
+    let tol = T::from(1e-14).unwrap();
+    let b: Array1<_> = b.iter().skip_while(|b| b.norm() <= tol).copied().collect();
+    let k = b.first().map_or_else(T::zero, |b| b.re);
+
+    Ok(GenericZpk {
+        z: roots(b.view())?,
+        p,
+        k,
+    })
+}
+
+/// eigenvalues of a square matrix, see [`super::eigenvalues`]
+fn eigenvalues<T: Float>(m: &DMatrix<Complex<T>>) -> Result<Array1<Complex<T>>, Error> {
+    let m = map_matrix(m, |a| {
+        Complex64::new(
+            a.re.to_f64().unwrap_or(f64::NAN),
+            a.im.to_f64().unwrap_or(f64::NAN),
+        )
+    });
+    Ok(super::eigenvalues(m)?
+        .iter()
+        .map(|a| {
+            Complex::new(
+                T::from(a.re).unwrap_or_else(T::nan),
+                T::from(a.im).unwrap_or_else(T::nan),
+            )
+        })
+        .collect())
+}
+
+/// build a matrix from its elements, without the bounds required by the nalgebra constructors
+pub(crate) fn matrix<T>(rows: usize, cols: usize, f: impl Fn(usize, usize) -> T) -> DMatrix<T> {
+    // nalgebra matrices are stored in column-major order
+    let data = (0..cols)
+        .flat_map(|j| (0..rows).map(move |i| (i, j)))
+        .map(|(i, j)| f(i, j))
+        .collect();
+    DMatrix::from_data(VecStorage::new(Dyn(rows), Dyn(cols), data))
+}
+
+/// apply `f` to every element of a matrix, see [`matrix`]
+pub(crate) fn map_matrix<A, B>(m: &DMatrix<A>, f: impl FnMut(&A) -> B) -> DMatrix<B> {
+    let (rows, cols) = m.shape();
+    let data = m.as_slice().iter().map(f).collect();
+    DMatrix::from_data(VecStorage::new(Dyn(rows), Dyn(cols), data))
+}
+
+fn trim_leading_zeros<T: Float>(coeffs: &Array1<Complex<T>>) -> Array1<Complex<T>> {
+    coeffs.iter().skip_while(|a| a.is_zero()).copied().collect()
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("the denominator of the transfer function is zero")]
+    ZeroDenominator,
+    #[error(
+        "improper transfer function, the numerator has degree {num} and the denominator {den}"
+    )]
+    ImproperTransferFunction { num: usize, den: usize },
+    #[error("the system must have a single input and a single output, received {inputs} inputs and {outputs} outputs")]
+    NotSiso { inputs: usize, outputs: usize },
+    #[error("A must be square and B, C and D must have one column, one row and one element, received A {a:?}, B {b:?}, C {c:?} and D {d:?}")]
+    Shape {
+        a: (usize, usize),
+        b: (usize, usize),
+        c: (usize, usize),
+        d: (usize, usize),
+    },
+    #[error(transparent)]
+    Roots(#[from] super::Error),
+}
@@ -0,0 +1,125 @@
+use num::{traits::FloatConst, Float};
+
+/// Complete elliptic integral of the first kind
+///
+/// <math xmlns="http://www.w3.org/1998/Math/MathML"><mi>K</mi><mo stretchy="false">(</mo><mi>m</mi><mo stretchy="false">)</mo><mo>=</mo><msubsup><mo>&#x222B;</mo><mn>0</mn><mrow><mi>&#x03C0;</mi><mo>/</mo><mn>2</mn></mrow></msubsup><msup><mrow><mo stretchy="false">[</mo><mn>1</mn><mo>&#x2212;</mo><mi>m</mi><msup><mi>sin</mi><mn>2</mn></msup><mi>t</mi><mo stretchy="false">]</mo></mrow><mrow><mo>&#x2212;</mo><mn>1</mn><mo>/</mo><mn>2</mn></mrow></msup><mi>d</mi><mi>t</mi></math>
+///
+/// computed with the arithmetic-geometric mean, returns `NaN` for `m > 1`
+pub fn ellipk<T: Float + FloatConst>(m: T) -> T {
+    ellipkm1(T::one() - m)
+}
+
+/// Complete elliptic integral of the first kind around `m = 1`
+///
+/// returns `K(1 - p)`, this function is more accurate than [`ellipk`] for `p` close to zero
+pub fn ellipkm1<T: Float + FloatConst>(p: T) -> T {
+    if p < T::zero() || p.is_nan() {
+        return T::nan();
+    }
+    T::FRAC_PI_2() / agm(T::one(), p.sqrt())
+}
+
+/// Complete elliptic integral of the second kind
+///
+/// <math xmlns="http://www.w3.org/1998/Math/MathML"><mi>E</mi><mo stretchy="false">(</mo><mi>m</mi><mo stretchy="false">)</mo><mo>=</mo><msubsup><mo>&#x222B;</mo><mn>0</mn><mrow><mi>&#x03C0;</mi><mo>/</mo><mn>2</mn></mrow></msubsup><msup><mrow><mo stretchy="false">[</mo><mn>1</mn><mo>&#x2212;</mo><mi>m</mi><msup><mi>sin</mi><mn>2</mn></msup><mi>t</mi><mo stretchy="false">]</mo></mrow><mrow><mn>1</mn><mo>/</mo><mn>2</mn></mrow></msup><mi>d</mi><mi>t</mi></math>
+///
+/// returns `NaN` for `m > 1`
+pub fn ellipe<T: Float + FloatConst>(m: T) -> T {
+    if m > T::one() || m.is_nan() {
+        return T::nan();
+    }
+    if m == T::one() {
+        return T::one();
+    }
+
+    let two = T::one() + T::one();
+    let (mut a, mut b) = (T::one(), (T::one() - m).sqrt());
+
+    // E(m) / K(m) = 1 - sum(2^(n - 1) c_n^2), with c_0^2 = m
+    let mut pow = T::one() / two;
+    let mut sum = pow * m;
+    while Float::abs(a - b) > T::epsilon() * a {
+        let c = (a - b) / two;
+        (a, b) = ((a + b) / two, (a * b).sqrt());
+        pow = pow * two;
+        sum = sum + pow * c * c;
+    }
+
+    T::FRAC_PI_2() / a * (T::one() - sum)
+}
+
+/// Jacobi elliptic functions
+///
+/// returns `(sn, cn, dn, ph)` evaluated at `u` with parameter `0 <= m <= 1`, `ph` is the
+/// amplitude such that `sn(u, m) = sin(ph)` and `cn(u, m) = cos(ph)`.
+/// `NaN` is returned for `m` outside of `[0, 1]`
+///
+/// computed with the descending Landen transformation, ported from cephes
+pub fn ellipj<T: Float + FloatConst>(u: T, m: T) -> (T, T, T, T) {
+    let from = |v: f64| T::from(v).unwrap();
+
+    if m < T::zero() || m > T::one() || m.is_nan() {
+        let nan = T::nan();
+        return (nan, nan, nan, nan);
+    }
+
+    if m < from(1e-9) {
+        let t = u.sin();
+        let b = u.cos();
+        let ai = from(0.25) * m * (u - t * b);
+        let sn = t - ai * b;
+        let cn = b + ai * t;
+        let ph = u - ai;
+        let dn = T::one() - from(0.5) * m * t * t;
+        return (sn, cn, dn, ph);
+    }
+
+    if m >= from(0.9999999999) {
+        let ai = from(0.25) * (T::one() - m);
+        let b = u.cosh();
+        let t = u.tanh();
+        let phi = T::one() / b;
+        let twon = b * u.sinh();
+        let sn = t + ai * (twon - u) / (b * b);
+        let ph = from(2.0) * u.exp().atan() - T::FRAC_PI_2() + ai * (twon - u) / b;
+        let ai = ai * t * phi;
+        let cn = phi - ai * (twon - u);
+        let dn = phi + ai * (twon + u);
+        return (sn, cn, dn, ph);
+    }
+
+    // arithmetic-geometric mean sequence
+    let mut a = vec![T::one()];
+    let mut c = vec![m.sqrt()];
+    let mut b = (T::one() - m).sqrt();
+    let mut twon = T::one();
+    while Float::abs(c[c.len() - 1] / a[a.len() - 1]) > T::epsilon() && a.len() < 9 {
+        let ai = a[a.len() - 1];
+        c.push((ai - b) / from(2.0));
+        a.push((ai + b) / from(2.0));
+        b = (ai * b).sqrt();
+        twon = twon * from(2.0);
+    }
+
+    // backward recurrence for the amplitude
+    let mut phi = twon * a[a.len() - 1] * u;
+    let mut prev = phi;
+    for i in (1..a.len()).rev() {
+        let t = c[i] * phi.sin() / a[i];
+        prev = phi;
+        phi = (t.asin() + phi) / from(2.0);
+    }
+
+    let sn = phi.sin();
+    let cn = phi.cos();
+    let dn = cn / (phi - prev).cos();
+    (sn, cn, dn, phi)
+}
+
+fn agm<T: Float>(mut a: T, mut b: T) -> T {
+    let two = T::one() + T::one();
+    while Float::abs(a - b) > T::epsilon() * a {
+        (a, b) = ((a + b) / two, (a * b).sqrt());
+    }
+    a
+}
@@ -1,5 +1,7 @@
+mod ellip;
 mod kv;
 mod trig;
+pub use ellip::*;
 pub use kv::*;
 use ndarray::Array1;
 use num::Complex;
@@ -1,12 +1,13 @@
 use ndarray::{concatenate, Array1, ArrayView1, Axis};
-use num::{Complex, Float};
+use num::Num;
 
 pub(crate) mod complex;
 
-pub fn convolve1d<T: Float>(
-    data: ArrayView1<Complex<T>>,
-    window: ArrayView1<Complex<T>>,
-) -> Array1<Complex<T>> {
+/// Discrete linear convolution of two one-dimensional sequences
+///
+/// the output is truncated to the length of the longest sequence, which is the output of a
+/// FIR filter started at rest, the element type can be real or complex
+pub fn convolve1d<T: Num + Copy>(data: ArrayView1<T>, window: ArrayView1<T>) -> Array1<T> {
     if window.len() > data.len() {
         return convolve1d(window, data);
     }
@@ -135,7 +135,9 @@ pub fn test_butter(order: u32, band_filter: BandFilter, analog: Sampling) {
     let rust = FilterOutput::get_output(
         rust,
         sciport_rs::signal::output_type::DesiredFilterOutput::Ba,
+        analog,
     )
+    .expect("valid filter output")
     .ba();
     let python = if let Some(p) = python {
         p
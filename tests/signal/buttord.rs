@@ -0,0 +1,108 @@
+use crate::common::with_scipy;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::{BandFilter, GenericOrdBandFilter, GenericOrdBandFilterType},
+    butterord::Error,
+    buttord, Sampling,
+};
+
+#[test]
+fn test_ord_band_filter_constructors() {
+    let highpass = GenericOrdBandFilter::highpass(0.4, 0.2).expect("valid highpass");
+    assert!(matches!(
+        highpass.as_ref(),
+        GenericOrdBandFilterType::Highpass { .. }
+    ));
+
+    let bandstop = GenericOrdBandFilter::bandstop(0.1, 0.6, 0.2, 0.5).expect("valid bandstop");
+    assert!(matches!(
+        bandstop.as_ref(),
+        GenericOrdBandFilterType::Bandstop { .. }
+    ));
+
+    assert!(GenericOrdBandFilter::highpass(0.2, 0.4).is_err());
+    assert!(GenericOrdBandFilter::bandpass(0.2, 0.5, 0.1, 0.4).is_err());
+    assert!(GenericOrdBandFilter::bandstop(0.1, 0.4, 0.2, 0.5).is_err());
+}
+
+#[test]
+fn test_buttord_bad_edges() {
+    let lowpass = |wp, ws| GenericOrdBandFilter::lowpass(wp, ws).expect("valid lowpass");
+    let digital = Sampling::Digital { fs: 100.0 };
+
+    assert!(matches!(
+        buttord(lowpass(60.0, 70.0), 3.0, 40.0, digital),
+        Err(Error::EdgesAboveNyquist)
+    ));
+    assert!(matches!(
+        buttord(lowpass(0.0, 0.3), 3.0, 40.0, Sampling::Analog),
+        Err(Error::BadEdges)
+    ));
+    assert!(matches!(
+        buttord(lowpass(20.0, 20.0), 3.0, 40.0, digital),
+        Err(Error::BadOrder(_))
+    ));
+}
+
+#[test]
+fn with_py_test_buttord() {
+    for _ in 0..500 {
+        let kind = thread_rng().gen_range(0..4);
+        let gpass = thread_rng().gen_range(0.5..3.0);
+        let gstop = thread_rng().gen_range(20.0..80.0);
+
+        let mut edges = [0.0; 4].map(|_: f64| thread_rng().gen_range(0.05..0.95));
+        edges.sort_by(f64::total_cmp);
+        let [e0, e1, e2, e3] = edges;
+
+        let (band_filter, wp, ws) = match kind {
+            0 => (
+                GenericOrdBandFilter::lowpass(e0, e1),
+                format!("{e0}"),
+                format!("{e1}"),
+            ),
+            1 => (
+                GenericOrdBandFilter::highpass(e1, e0),
+                format!("{e1}"),
+                format!("{e0}"),
+            ),
+            2 => (
+                GenericOrdBandFilter::bandpass(e1, e2, e0, e3),
+                format!("[{e1}, {e2}]"),
+                format!("[{e0}, {e3}]"),
+            ),
+            3 => (
+                GenericOrdBandFilter::bandstop(e0, e3, e1, e2),
+                format!("[{e0}, {e3}]"),
+                format!("[{e1}, {e2}]"),
+            ),
+            _ => unreachable!(),
+        };
+        let band_filter = band_filter.expect("valid band edges");
+
+        let (sampling, analog, fs) = match thread_rng().gen_range(0..2) {
+            0 => (Sampling::Analog, "True", "None".to_string()),
+            _ => (Sampling::Digital { fs: 2.0 }, "False", "2.0".to_string()),
+        };
+
+        let python = with_scipy::<(u32, Vec<f64>)>(&format!(
+            "(lambda n, wn: (n, list(np.atleast_1d(wn))))(*signal.buttord({wp}, {ws}, {gpass}, {gstop}, analog={analog}, fs={fs}))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let rust = buttord(band_filter, gpass, gstop, sampling).expect("valid order");
+        let wn = match rust.filter {
+            BandFilter::Lowpass(wn) | BandFilter::Highpass(wn) => vec![wn],
+            BandFilter::Bandpass { low, high } | BandFilter::Bandstop { low, high } => {
+                vec![low, high]
+            }
+        };
+
+        assert_eq!(rust.order, python.0);
+        approx::assert_relative_eq!(wn.as_slice(), python.1.as_slice(), max_relative = 1e-6);
+    }
+}
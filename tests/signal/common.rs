@@ -1,13 +1,19 @@
 use std::fmt::Debug;
 
+use ndarray::Array1;
 use num::{Float, NumCast};
 use numpy::Complex64;
 use pyo3::prelude::*;
 use pyo3::types::IntoPyDict;
-use sciport_rs::signal::output_type::{GenericBa, GenericZpk};
 use sciport_rs::signal::tools::{
     generic_approx_complex_relative_slice_eq_dbg, generic_approx_relative_eq,
 };
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    freqz,
+    output_type::{DesiredFilterOutput, GenericBa, GenericFilterOutput, GenericZpk},
+    ButterFilter, Cheby1Filter, FrequencyGrid, GenericIIRFilterSettings, IIRFilterDesign, Sampling,
+};
 
 #[macro_export]
 macro_rules! tol {
@@ -140,6 +146,53 @@ pub fn check_ba_filter<T: Float + Debug>(
     true //res
 }
 
+/// Butterworth filter fixture
+pub fn butter(
+    order: u32,
+    band_filter: BandFilter,
+    analog: Sampling,
+    output: DesiredFilterOutput,
+) -> GenericFilterOutput<f64> {
+    ButterFilter {
+        settings: GenericIIRFilterSettings {
+            order,
+            band_filter,
+            analog,
+        },
+    }
+    .compute_filter(output)
+    .expect("valid filter output")
+}
+
+/// Chebyshev type I filter fixture
+pub fn cheby1(
+    order: u32,
+    rp: f64,
+    band_filter: BandFilter,
+    analog: Sampling,
+    output: DesiredFilterOutput,
+) -> GenericFilterOutput<f64> {
+    Cheby1Filter {
+        rp,
+        settings: GenericIIRFilterSettings {
+            order,
+            band_filter,
+            analog,
+        },
+    }
+    .compute_filter(output)
+    .expect("valid filter output")
+}
+
+/// magnitude of the frequency response of a filter at `frequencies`
+pub fn gains(ba: &GenericBa<f64>, frequencies: Array1<f64>, sampling: Sampling) -> Vec<f64> {
+    freqz(ba, FrequencyGrid::Frequencies(frequencies), sampling)
+        .h
+        .iter()
+        .map(|h| h.norm())
+        .collect()
+}
+
 #[macro_export]
 macro_rules! assert_almost_eq {
     ($i1:expr, $i2:expr, $tol:expr) => {
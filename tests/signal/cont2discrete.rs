@@ -0,0 +1,265 @@
+use crate::common::{butter, with_scipy};
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    cont2discrete::Error,
+    output_type::{DesiredFilterOutput, GenericBa, GenericStateSpace, GenericZpk},
+    tools::bilinear_zpk,
+    *,
+};
+
+fn first_order() -> GenericBa<f64> {
+    let one = Complex64::new(1.0, 0.0);
+    GenericBa {
+        b: array![one],
+        a: array![one, one],
+    }
+}
+
+fn ba(system: Dlti) -> GenericBa<f64> {
+    match system.system {
+        GenericLtiSystem::Ba(ba) => ba,
+        _ => panic!("expected a transfer function"),
+    }
+}
+
+fn real(a: &Array1<Complex64>) -> Vec<f64> {
+    a.iter().map(|a| a.re).collect()
+}
+
+fn trimmed(a: &Array1<Complex64>) -> Vec<f64> {
+    real(a)
+        .into_iter()
+        .skip_while(|a| a.abs() < 1e-14)
+        .collect()
+}
+
+#[test]
+fn test_cont2discrete_first_order() {
+    let dt: f64 = 0.1;
+    let e = (-dt).exp();
+
+    let zoh = ba(cont2discrete(first_order(), dt, DiscretizationMethod::Zoh).unwrap());
+    approx::assert_relative_eq!(
+        real(&zoh.b).as_slice(),
+        [0.0, 1.0 - e].as_slice(),
+        epsilon = 1e-12
+    );
+    approx::assert_relative_eq!(
+        real(&zoh.a).as_slice(),
+        [1.0, -e].as_slice(),
+        epsilon = 1e-12
+    );
+
+    let euler = ba(cont2discrete(first_order(), dt, DiscretizationMethod::Euler).unwrap());
+    approx::assert_relative_eq!(
+        real(&euler.a).as_slice(),
+        [1.0, dt - 1.0].as_slice(),
+        epsilon = 1e-12
+    );
+
+    let backward =
+        ba(cont2discrete(first_order(), dt, DiscretizationMethod::BackwardDiff).unwrap());
+    approx::assert_relative_eq!(
+        real(&backward.a).as_slice(),
+        [1.0, -1.0 / (1.0 + dt)].as_slice(),
+        epsilon = 1e-12
+    );
+
+    let impulse = ba(cont2discrete(first_order(), dt, DiscretizationMethod::Impulse).unwrap());
+    approx::assert_relative_eq!(
+        real(&impulse.b).as_slice(),
+        [dt, 0.0].as_slice(),
+        epsilon = 1e-12
+    );
+    approx::assert_relative_eq!(
+        real(&impulse.a).as_slice(),
+        [1.0, -e].as_slice(),
+        epsilon = 1e-12
+    );
+
+    let system = cont2discrete(first_order(), dt, DiscretizationMethod::Foh).unwrap();
+    assert_eq!(system.dt, dt);
+    let foh = ba(system);
+    // the first order hold preserves the DC gain
+    let gain = foh.b.sum() / foh.a.sum();
+    approx::assert_relative_eq!(gain.re, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_cont2discrete_bilinear() {
+    let fs = 10.0;
+    let zpk = butter(
+        4,
+        BandFilter::Lowpass(3.0),
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    )
+    .zpk();
+    let expected = bilinear_zpk(zpk.clone(), fs);
+
+    let discrete = Lti::new(zpk.clone())
+        .to_discrete(1.0 / fs, DiscretizationMethod::Bilinear { prewarp: None })
+        .unwrap();
+    let GenericLtiSystem::Zpk(discrete) = discrete.system else {
+        panic!("expected zpk");
+    };
+    approx::assert_relative_eq!(discrete.k, expected.k, max_relative = 1e-8);
+    for p in discrete.p.iter() {
+        assert!(
+            expected.p.iter().any(|e| (p - e).norm() < 1e-8),
+            "{p} is not a pole"
+        );
+    }
+
+    let gbt = cont2discrete(
+        zpk.clone(),
+        1.0 / fs,
+        DiscretizationMethod::Gbt { alpha: 0.5 },
+    )
+    .unwrap();
+    let GenericLtiSystem::Zpk(gbt) = gbt.system else {
+        panic!("expected zpk");
+    };
+    approx::assert_relative_eq!(gbt.k, discrete.k, max_relative = 1e-12);
+
+    // the prewarped response matches the analog one at the prewarping frequency
+    let omega = 3.0;
+    let discrete = cont2discrete(
+        zpk.clone(),
+        1.0 / fs,
+        DiscretizationMethod::Bilinear {
+            prewarp: Some(omega),
+        },
+    )
+    .unwrap();
+    let GenericLtiSystem::Zpk(discrete) = discrete.system else {
+        panic!("expected zpk");
+    };
+    let analog = freqs_zpk(&zpk, FrequencyGrid::Frequencies(array![omega])).h[0];
+    let digital = freqz_zpk(
+        &discrete,
+        FrequencyGrid::Frequencies(array![omega / std::f64::consts::TAU]),
+        Sampling::Digital { fs },
+    )
+    .h[0];
+    assert!((analog - digital).norm() < 1e-8, "{analog} != {digital}");
+}
+
+#[test]
+fn test_cont2discrete_representations() {
+    let dt = 0.05;
+    let method = DiscretizationMethod::Zoh;
+
+    let filter = |output| butter(3, BandFilter::Lowpass(2.0), Sampling::Analog, output);
+
+    let from_ba = ba(cont2discrete(filter(DesiredFilterOutput::Ba).ba(), dt, method).unwrap());
+    let ss: GenericStateSpace<f64> = filter(DesiredFilterOutput::StateSpace).state_space();
+    let GenericLtiSystem::StateSpace(from_ss) = cont2discrete(ss, dt, method).unwrap().system
+    else {
+        panic!("expected state space");
+    };
+    let from_ss: GenericBa<f64> = from_ss.try_into().unwrap();
+    let GenericLtiSystem::Zpk(from_zpk) =
+        cont2discrete(filter(DesiredFilterOutput::Zpk).zpk(), dt, method)
+            .unwrap()
+            .system
+    else {
+        panic!("expected zpk");
+    };
+    let from_zpk: GenericBa<f64> = from_zpk.into();
+
+    for other in [from_ss, from_zpk] {
+        approx::assert_relative_eq!(
+            real(&other.a).as_slice(),
+            real(&from_ba.a).as_slice(),
+            epsilon = 1e-10
+        );
+        approx::assert_relative_eq!(
+            trimmed(&other.b).as_slice(),
+            trimmed(&from_ba.b).as_slice(),
+            epsilon = 1e-10
+        );
+    }
+}
+
+#[test]
+fn test_cont2discrete_errors() {
+    let one = Complex64::new(1.0, 0.0);
+    let proper = GenericBa {
+        b: array![one, one],
+        a: array![one, one + one],
+    };
+    assert!(matches!(
+        cont2discrete(proper, 0.1, DiscretizationMethod::Impulse),
+        Err(Error::NotStrictlyProper)
+    ));
+    assert!(matches!(
+        cont2discrete(first_order(), 0.1, DiscretizationMethod::Gbt { alpha: 1.5 }),
+        Err(Error::Alpha(_))
+    ));
+    // the backward difference of a pole at 1 / dt is singular
+    let zpk = GenericZpk {
+        z: array![],
+        p: array![Complex64::new(10.0, 0.0)],
+        k: 1.0,
+    };
+    assert!(matches!(
+        cont2discrete(zpk, 0.1, DiscretizationMethod::BackwardDiff),
+        Err(Error::Singular)
+    ));
+}
+
+#[test]
+fn with_py_test_cont2discrete() {
+    let methods = [
+        ("zoh", DiscretizationMethod::Zoh),
+        ("foh", DiscretizationMethod::Foh),
+        ("impulse", DiscretizationMethod::Impulse),
+        ("bilinear", DiscretizationMethod::Bilinear { prewarp: None }),
+        ("euler", DiscretizationMethod::Euler),
+        ("backward_diff", DiscretizationMethod::BackwardDiff),
+    ];
+
+    for _ in 0..20 {
+        let order = thread_rng().gen_range(1..8);
+        let wn = thread_rng().gen_range(0.1..10.0);
+        let dt = thread_rng().gen_range(0.01..0.1);
+
+        for (name, method) in methods {
+            let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+                "(lambda num, den, dt: (list(num.flatten()), list(den)))(*signal.cont2discrete(signal.butter({order}, {wn}, analog=True), {dt}, method=\"{name}\"))"
+            ));
+            let python = if let Some(p) = python {
+                p
+            } else {
+                continue;
+            };
+
+            let rust = ba(cont2discrete(
+                butter(
+                    order,
+                    BandFilter::Lowpass(wn),
+                    Sampling::Analog,
+                    DesiredFilterOutput::Ba,
+                )
+                .ba(),
+                dt,
+                method,
+            )
+            .unwrap());
+            approx::assert_relative_eq!(
+                real(&rust.b).as_slice(),
+                python.0.as_slice(),
+                epsilon = 1e-8
+            );
+            approx::assert_relative_eq!(
+                real(&rust.a).as_slice(),
+                python.1.as_slice(),
+                epsilon = 1e-8
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::common::check_zpk_filter;
+use crate::common::with_scipy;
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter, ellip::*, output_type::DesiredFilterOutput, GenericIIRFilterSettings,
+    IIRFilterDesign, Sampling,
+};
+
+#[test]
+fn with_py_test_ellip() {
+    for _ in 0..1000 {
+        let order = rand::thread_rng().gen_range(0..20);
+        let kind = rand::thread_rng().gen_range(0..4);
+        let rp = rand::thread_rng().gen_range(0.1..5.0);
+        let rs = rand::thread_rng().gen_range(20.0..100.0);
+        let band_filter = match kind {
+            0 => BandFilter::Lowpass(rand::thread_rng().gen_range((0.0)..1.0)),
+            1 => BandFilter::Highpass(rand::thread_rng().gen_range((0.0)..1.0)),
+            2 => {
+                let x1: f64 = rand::thread_rng().gen_range((0.0)..1.0);
+                let x2: f64 = rand::thread_rng().gen_range((0.0)..1.0);
+
+                let low = x1.min(x2);
+                let high = x1.max(x2);
+                BandFilter::Bandpass { low, high }
+            }
+            3 => {
+                let x1: f64 = rand::thread_rng().gen_range((0.0)..1.0);
+                let x2: f64 = rand::thread_rng().gen_range((0.0)..1.0);
+
+                let low = x1.min(x2);
+                let high = x1.max(x2);
+                BandFilter::Bandstop { low, high }
+            }
+            _ => unreachable!(),
+        };
+
+        let analog = match rand::thread_rng().gen_range(0..2) {
+            0 => Sampling::Analog,
+            1 => Sampling::Digital {
+                fs: thread_rng().gen_range((3.0)..15.0),
+            },
+            _ => unreachable!(),
+        };
+        test_ellip(order, band_filter, analog, rp, rs);
+    }
+}
+
+#[test]
+fn test_ellipap() {
+    for i in 0..25 {
+        println!("testing ellipap order {i}");
+        let rp = thread_rng().gen_range(0.1..5.0);
+        let rs = thread_rng().gen_range(20.0..100.0);
+        let python = with_scipy::<(Vec<Complex64>, Vec<Complex64>, f64)>(&format!(
+            "signal.ellipap({i}, rp={rp}, rs={rs})"
+        ));
+        let rust = ellipap(i, rp, rs).expect("valid filter output");
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+        assert!(check_zpk_filter(rust, python));
+    }
+}
+
+fn test_ellip(order: u32, band_filter: BandFilter, analog: Sampling, rp: f64, rs: f64) {
+    let (wn, btype) = match &band_filter {
+        BandFilter::Bandstop { low, high } => (format!("[{low}, {high}]"), "bandstop"),
+        BandFilter::Bandpass { low, high } => (format!("[{low}, {high}]"), "bandpass"),
+        BandFilter::Lowpass(data) => (format!("{data}"), "lowpass"),
+        BandFilter::Highpass(data) => (format!("{data}"), "highpass"),
+    };
+
+    let (analog_s, fs) = match &analog {
+        Sampling::Analog => ("True", "None".to_string()),
+        Sampling::Digital { fs } => ("False", fs.to_string()),
+    };
+    let py_code = &format!(
+        "signal.ellip({order}, rp={rp}, rs={rs}, Wn={wn}, btype=\"{btype}\", output=\"zpk\", analog={analog_s}, fs={fs})"
+    );
+    let python = with_scipy::<(Vec<Complex64>, Vec<Complex64>, f64)>(py_code);
+
+    let python = if let Some(p) = python {
+        p
+    } else {
+        return;
+    };
+
+    let filter = EllipFilter {
+        rp,
+        rs,
+        settings: GenericIIRFilterSettings {
+            order,
+            band_filter,
+            analog,
+        },
+    };
+
+    let rust = filter
+        .compute_filter(DesiredFilterOutput::Zpk)
+        .expect("valid filter output")
+        .zpk();
+
+    let success = check_zpk_filter(rust.clone(), python.clone());
+    if !success {
+        println!("order {order} filter: {band_filter:#?}, analog {analog:#?}, rp: {rp}, rs: {rs}");
+
+        println!("rust: {:?}", rust);
+        println!("python: {:?}", python);
+        println!("python code: {}", py_code);
+    }
+    assert!(success);
+}
@@ -0,0 +1,163 @@
+use crate::common::{butter, with_scipy};
+use ndarray::Array1;
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    firwin,
+    output_type::{DesiredFilterOutput, GenericBa, GenericZpk},
+    Sampling, WindowType,
+};
+
+fn real(a: &[f64]) -> Array1<Complex64> {
+    a.iter().map(|a| Complex64::new(*a, 0.0)).collect()
+}
+
+fn ba(b: &[f64], a: &[f64]) -> GenericBa<f64> {
+    GenericBa {
+        b: real(b),
+        a: real(a),
+    }
+}
+
+#[test]
+fn test_filter_analysis_butter() {
+    let digital = Sampling::Digital { fs: 2.0 };
+    let filter = |output| butter(6, BandFilter::Lowpass(0.3), digital, output);
+
+    let zpk = filter(DesiredFilterOutput::Zpk).zpk();
+    let ba = filter(DesiredFilterOutput::Ba).ba();
+    let sos = filter(DesiredFilterOutput::Sos).sos();
+
+    assert!(zpk.is_stable(digital));
+    assert!(ba.is_stable(digital));
+    assert!(sos.is_stable(digital));
+
+    // the zeros at z = -1 lie on the unit circle
+    assert!(!zpk.is_minimum_phase(digital));
+    assert!(!sos.is_minimum_phase(digital));
+
+    for gain in [
+        zpk.dc_gain(digital),
+        ba.dc_gain(digital),
+        sos.dc_gain(digital),
+    ] {
+        approx::assert_relative_eq!(gain.re, 1.0, epsilon = 1e-8);
+        approx::assert_relative_eq!(gain.im, 0.0, epsilon = 1e-8);
+    }
+    for gain in [
+        zpk.nyquist_gain(digital),
+        ba.nyquist_gain(digital),
+        sos.nyquist_gain(digital),
+    ] {
+        approx::assert_relative_eq!(gain.unwrap().norm(), 0.0, epsilon = 1e-8);
+    }
+
+    let analog = butter(
+        6,
+        BandFilter::Highpass(2.0),
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    )
+    .zpk();
+    assert!(analog.is_stable(Sampling::Analog));
+    approx::assert_relative_eq!(analog.dc_gain(Sampling::Analog).norm(), 0.0);
+    assert!(analog.nyquist_gain(Sampling::Analog).is_none());
+}
+
+#[test]
+fn test_filter_analysis_stability() {
+    let digital = Sampling::Digital { fs: 1.0 };
+    let zpk = GenericZpk {
+        z: real(&[0.5]),
+        p: real(&[0.9, -1.1]),
+        k: 1.0,
+    };
+    assert!(!zpk.is_stable(digital));
+    assert!(!zpk.is_minimum_phase(digital));
+    assert!(!zpk.is_stable(Sampling::Analog));
+
+    let zpk = GenericZpk {
+        z: real(&[-2.0]),
+        p: real(&[-1.0, -3.0]),
+        k: 3.0,
+    };
+    assert!(zpk.is_stable(Sampling::Analog));
+    assert!(zpk.is_minimum_phase(Sampling::Analog));
+    assert!(!zpk.is_stable(digital));
+    approx::assert_relative_eq!(zpk.dc_gain(Sampling::Analog).re, 2.0);
+
+    // 1 - 2 z^-1 has its zero outside of the unit circle
+    assert!(ba(&[1.0, -0.5], &[1.0]).is_minimum_phase(digital));
+    assert!(!ba(&[1.0, -2.0], &[1.0]).is_minimum_phase(digital));
+    assert!(ba(&[1.0], &[1.0, -0.5]).is_stable(digital));
+    assert!(!ba(&[1.0], &[1.0, -1.5]).is_stable(digital));
+    assert!(!ba(&[1.0], &[0.0]).is_stable(digital));
+
+    let gain = ba(&[1.0, 1.0], &[1.0, -0.5]);
+    approx::assert_relative_eq!(gain.dc_gain(digital).re, 4.0);
+    approx::assert_relative_eq!(gain.nyquist_gain(digital).unwrap().norm(), 0.0);
+    approx::assert_relative_eq!(gain.dc_gain(Sampling::Analog).re, -2.0);
+}
+
+#[test]
+fn test_filter_analysis_linear_phase() {
+    let fir = firwin(
+        31,
+        BandFilter::Lowpass(0.3),
+        None,
+        WindowType::Hamming,
+        true,
+        Sampling::Digital { fs: 2.0 },
+    )
+    .ba();
+    assert!(fir.is_linear_phase());
+
+    assert!(ba(&[1.0, 2.0, 1.0], &[1.0]).is_linear_phase());
+    assert!(ba(&[1.0, 0.0, -1.0], &[2.0]).is_linear_phase());
+    assert!(ba(&[0.0, 1.0, 2.0, 1.0, 0.0, 0.0], &[1.0]).is_linear_phase());
+    assert!(ba(&[1.0, 2.0, 1.0], &[0.0, 1.0]).is_linear_phase());
+    assert!(!ba(&[1.0, 2.0, 3.0], &[1.0]).is_linear_phase());
+    assert!(!ba(&[1.0, 2.0, 1.0], &[1.0, -0.5]).is_linear_phase());
+}
+
+#[test]
+fn test_filter_analysis_normalize() {
+    let normalized = ba(&[0.0, 2.0, 4.0], &[0.0, 2.0, 1.0]).normalize().unwrap();
+    assert_eq!(normalized.b, real(&[1.0, 2.0]));
+    assert_eq!(normalized.a, real(&[1.0, 0.5]));
+    assert!(ba(&[1.0], &[0.0]).normalize().is_err());
+}
+
+#[test]
+fn with_py_test_filter_analysis_normalize() {
+    for _ in 0..200 {
+        let len = thread_rng().gen_range(1..8);
+        let b: Vec<f64> = (0..len)
+            .map(|_| thread_rng().gen_range(-2.0..2.0))
+            .collect();
+        let a: Vec<f64> = (0..len)
+            .map(|_| thread_rng().gen_range(-2.0..2.0))
+            .collect();
+
+        let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+            "(lambda b, a: (list(b), list(a)))(*signal.normalize({b:?}, {a:?}))"
+        ));
+        let Some((py_b, py_a)) = python else {
+            continue;
+        };
+
+        let rust = ba(&b, &a).normalize().unwrap();
+        let re = |c: &Array1<Complex64>| c.mapv(|c| c.re).to_vec();
+        approx::assert_relative_eq!(
+            re(&rust.b).as_slice(),
+            py_b.as_slice(),
+            max_relative = 1e-10
+        );
+        approx::assert_relative_eq!(
+            re(&rust.a).as_slice(),
+            py_a.as_slice(),
+            max_relative = 1e-10
+        );
+    }
+}
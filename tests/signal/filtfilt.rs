@@ -0,0 +1,110 @@
+use crate::common::{butter, with_scipy};
+use ndarray::Array1;
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    output_type::{DesiredFilterOutput, GenericFilterOutput},
+    FiltFilt, FiltFiltMethod, PadType, Sampling,
+};
+
+fn random_butter(
+    order: u32,
+) -> (
+    impl Fn(DesiredFilterOutput) -> GenericFilterOutput<f64>,
+    String,
+) {
+    let fs = 100.0;
+    let cutoff: f64 = thread_rng().gen_range((1.0)..49.0);
+
+    let filter = move |output| {
+        butter(
+            order,
+            BandFilter::Lowpass(cutoff),
+            Sampling::Digital { fs },
+            output,
+        )
+    };
+    let py_ba = format!("*signal.butter({order}, {cutoff}, fs={fs})");
+    (filter, py_ba)
+}
+
+#[test]
+fn with_py_test_filtfilt() {
+    for (method, py_method) in [
+        (FiltFiltMethod::default(), "padtype='odd'"),
+        (
+            FiltFiltMethod::Pad {
+                padtype: Some(PadType::Even),
+                padlen: Some(20),
+            },
+            "padtype='even', padlen=20",
+        ),
+        (
+            FiltFiltMethod::Pad {
+                padtype: Some(PadType::Constant),
+                padlen: None,
+            },
+            "padtype='constant'",
+        ),
+        (
+            FiltFiltMethod::Pad {
+                padtype: None,
+                padlen: None,
+            },
+            "padtype=None",
+        ),
+        (FiltFiltMethod::Gust { irlen: None }, "method='gust'"),
+        (
+            FiltFiltMethod::Gust { irlen: Some(80) },
+            "method='gust', irlen=80",
+        ),
+    ] {
+        let order = thread_rng().gen_range(1..8);
+        let (filter, py_ba) = random_butter(order);
+        let signal = Array1::linspace(-1.0, 1.0, 300).mapv(|a: f64| (a * 20.0).sin() + a);
+
+        let python = with_scipy::<Vec<f64>>(&format!(
+            "signal.filtfilt({py_ba}, {signal}, {py_method}).tolist()"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = filter(DesiredFilterOutput::Ba).ba();
+        let rust = ba
+            .filtfilt(signal.mapv(Into::into), method)
+            .expect("signal longer than padlen");
+        approx::assert_relative_eq!(
+            rust.mapv(|a| a.re).to_vec().as_slice(),
+            python.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
+
+#[test]
+fn test_filtfilt_output_types() {
+    let (filter, _) = random_butter(4);
+    let signal = Array1::linspace(-1.0, 1.0, 200).mapv(|a: f64| Complex64::from((a * 5.0).sin()));
+
+    for method in [
+        FiltFiltMethod::default(),
+        FiltFiltMethod::Gust { irlen: None },
+    ] {
+        let ba = filter(DesiredFilterOutput::Ba)
+            .filtfilt(signal.clone(), method)
+            .expect("signal longer than padlen");
+        let zpk = filter(DesiredFilterOutput::Zpk)
+            .filtfilt(signal.clone(), method)
+            .expect("signal longer than padlen");
+        let sos = filter(DesiredFilterOutput::Sos)
+            .filtfilt(signal.clone(), method)
+            .expect("signal longer than padlen");
+
+        approx::assert_relative_eq!(ba, zpk, epsilon = 1e-8);
+        approx::assert_relative_eq!(ba, sos, epsilon = 1e-8);
+    }
+}
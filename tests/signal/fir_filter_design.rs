@@ -1,6 +1,6 @@
 use crate::common::with_scipy;
 use rand::Rng;
-use sciport_rs::signal::{band_filter::BandFilter, firwin, Sampling, WindowType};
+use sciport_rs::signal::{band_filter::BandFilter, firwin, firwin2, Sampling, WindowType};
 
 #[test]
 fn test_firwin() {
@@ -72,3 +72,144 @@ pub fn validate_firwin_input(cutoff: &BandFilter, numtaps: i64) -> bool {
 
     pass_nyquist && numtaps % 2 == 0
 }
+
+/// magnitude of the response of the FIR filter `b` at `f`, normalized to the Nyquist frequency
+fn magnitude(b: &[f64], f: f64) -> f64 {
+    let (re, im) = b.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, b)| {
+        let phase = std::f64::consts::PI * f * n as f64;
+        (re + b * phase.cos(), im - b * phase.sin())
+    });
+    re.hypot(im)
+}
+
+fn firwin2_taps(numtaps: i64, freq: &[f64], gain: &[f64], antisymmetric: bool) -> Vec<f64> {
+    firwin2(
+        numtaps,
+        freq.to_vec(),
+        gain.to_vec(),
+        None,
+        antisymmetric,
+        WindowType::Hamming,
+        Sampling::Digital { fs: 2.0 },
+    )
+    .ba()
+    .b
+    .mapv(|a| a.re)
+    .to_vec()
+}
+
+#[test]
+fn test_firwin2() {
+    // type I lowpass with a discontinuity
+    let b = firwin2_taps(51, &[0.0, 0.5, 0.5, 1.0], &[1.0, 1.0, 0.0, 0.0], false);
+    assert_eq!(b.len(), 51);
+    for (x, y) in b.iter().zip(b.iter().rev()) {
+        approx::assert_relative_eq!(x, y, epsilon = 1e-12);
+    }
+    approx::assert_relative_eq!(magnitude(&b, 0.0), 1.0, epsilon = 1e-2);
+    approx::assert_relative_eq!(magnitude(&b, 0.2), 1.0, epsilon = 1e-2);
+    approx::assert_relative_eq!(magnitude(&b, 0.8), 0.0, epsilon = 1e-2);
+
+    // arbitrary gain curve
+    let freq = [0.0, 0.3, 0.6, 1.0];
+    let gain = [1.0, 0.5, 2.0, 2.0];
+    let b = firwin2_taps(101, &freq, &gain, false);
+    for (f, g) in [(0.1, 5.0 / 6.0), (0.45, 1.25), (0.8, 2.0)] {
+        approx::assert_relative_eq!(magnitude(&b, f), g, epsilon = 2e-2);
+    }
+
+    // type III, antisymmetric with a null at 0 and at the Nyquist frequency
+    let b = firwin2_taps(31, &[0.0, 0.2, 0.8, 1.0], &[0.0, 1.0, 1.0, 0.0], true);
+    approx::assert_relative_eq!(b[15], 0.0);
+    for (x, y) in b.iter().zip(b.iter().rev()) {
+        approx::assert_relative_eq!(*x, -y, epsilon = 1e-12);
+    }
+    approx::assert_relative_eq!(magnitude(&b, 0.5), 1.0, epsilon = 2e-2);
+
+    // type IV differentiator
+    let b = firwin2_taps(30, &[0.0, 1.0], &[0.0, 1.0], true);
+    for (x, y) in b.iter().zip(b.iter().rev()) {
+        approx::assert_relative_eq!(*x, -y, epsilon = 1e-12);
+    }
+    approx::assert_relative_eq!(magnitude(&b, 0.0), 0.0, epsilon = 1e-12);
+    approx::assert_relative_eq!(magnitude(&b, 0.5), 0.5, epsilon = 2e-2);
+}
+
+#[test]
+#[should_panic(expected = "Type II filter must have zero gain at the Nyquist frequency")]
+fn test_firwin2_type_ii_nyquist() {
+    firwin2_taps(30, &[0.0, 1.0], &[1.0, 1.0], false);
+}
+
+#[test]
+#[should_panic(expected = "ntaps must be less than nfreqs")]
+fn test_firwin2_nfreqs() {
+    firwin2(
+        33,
+        vec![0.0, 0.5, 1.0],
+        vec![1.0, 1.0, 0.0],
+        Some(33),
+        false,
+        WindowType::Hamming,
+        Sampling::Digital { fs: 2.0 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "freq must start with 0 and end with fs/2")]
+fn test_firwin2_frequency_range() {
+    firwin2_taps(31, &[0.0, 0.5], &[1.0, 0.0], false);
+}
+
+#[test]
+fn with_py_test_firwin2() {
+    for _ in 0..500 {
+        let numtaps = rand::thread_rng().gen_range(3..80);
+        let antisymmetric = rand::random();
+        let fs: f64 = rand::thread_rng().gen_range(1.0..1000.0);
+        let nyq = fs / 2.0;
+
+        let mut freq: Vec<f64> = (0..rand::thread_rng().gen_range(0..5))
+            .map(|_| rand::thread_rng().gen_range(0.0..nyq))
+            .collect();
+        freq.push(0.0);
+        freq.push(nyq);
+        freq.sort_by(f64::total_cmp);
+        let mut gain: Vec<f64> = freq
+            .iter()
+            .map(|_| rand::thread_rng().gen_range(0.0..2.0))
+            .collect();
+        let last = gain.len() - 1;
+        match (antisymmetric, numtaps % 2 == 1) {
+            (false, false) => gain[last] = 0.0,
+            (true, true) => {
+                gain[0] = 0.0;
+                gain[last] = 0.0;
+            }
+            (true, false) => gain[0] = 0.0,
+            _ => {}
+        }
+
+        let rust_res = firwin2(
+            numtaps,
+            freq.clone(),
+            gain.clone(),
+            None,
+            antisymmetric,
+            WindowType::Hamming,
+            Sampling::Digital { fs },
+        )
+        .ba()
+        .b
+        .mapv(|a| a.re)
+        .to_vec();
+
+        let py_script = format!(
+            "signal.firwin2({numtaps}, {freq:?}, {gain:?}, antisymmetric={}, fs={fs})",
+            if antisymmetric { "True" } else { "False" }
+        );
+        let python: Vec<f64> = with_scipy(&py_script).unwrap();
+
+        approx::assert_relative_eq!(rust_res.as_slice(), python.as_slice(), epsilon = 1e-10);
+    }
+}
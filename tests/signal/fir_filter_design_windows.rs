@@ -314,3 +314,233 @@ pub fn test_general_gaussian() {
         approx::assert_relative_eq!(rust_res.as_slice(), py_res.as_slice(),);
     }
 }
+
+#[test]
+pub fn test_chebwin() {
+    for _ in 0..*TEST_ITER {
+        let len = len(*TEST_LEN);
+        let sym = rand::random();
+        let at = rand::thread_rng().gen_range(20.0..120.0);
+        let rust_res = chebwin(len, at, sym).to_vec();
+        let py_script = format!("signal.windows.chebwin({len}, {at}, {})", py_bool(sym));
+        let py_res: Vec<f64> = with_scipy(&py_script).unwrap();
+        approx::assert_relative_eq!(
+            rust_res.as_slice(),
+            py_res.as_slice(),
+            epsilon = 1e-10,
+            max_relative = 1e-8
+        );
+    }
+}
+
+#[test]
+pub fn test_dpss() {
+    for _ in 0..*TEST_ITER / 10 {
+        let len = len(*TEST_LEN / 4) + 1;
+        let sym = rand::random();
+        let nw = rand::thread_rng().gen_range(1.0..4.0_f64.min(len as f64 / 2.0));
+        let rust_res = dpss(len, nw, sym).to_vec();
+        let py_script = format!("signal.windows.dpss({len}, {nw}, sym={})", py_bool(sym));
+        let py_res: Vec<f64> = with_scipy(&py_script).unwrap();
+        approx::assert_relative_eq!(
+            rust_res.as_slice(),
+            py_res.as_slice(),
+            epsilon = 1e-8,
+            max_relative = 1e-6
+        );
+    }
+}
+
+#[test]
+pub fn test_chebwin_sidelobes() {
+    for (len, at) in [(51, 50.0), (64, 80.0), (31, 30.0)] {
+        let w = chebwin(len, at, true);
+
+        approx::assert_relative_eq!(w.iter().copied().fold(f64::MIN, f64::max), 1.0);
+        for (a, b) in w.iter().zip(w.iter().rev()) {
+            approx::assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+
+        // sample the spectrum finely past the main lobe, the sidelobes are at -at dB
+        let response = |f: f64| {
+            let (re, im) = w.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, w)| {
+                let phase = std::f64::consts::TAU * f * n as f64;
+                (re + w * phase.cos(), im - w * phase.sin())
+            });
+            re.hypot(im)
+        };
+        let peak = response(0.0);
+        let beta = ((10.0_f64.powf(at / 20.0)).acosh() / (len - 1) as f64).cosh();
+        let main_lobe = (1.0 / beta).acos() / std::f64::consts::PI;
+        let sidelobe = (0..2000)
+            .map(|k| main_lobe + (0.5 - main_lobe) * k as f64 / 2000.0)
+            .map(response)
+            .fold(0.0, f64::max);
+        approx::assert_relative_eq!(20.0 * (peak / sidelobe).log10(), at, epsilon = 0.1);
+    }
+}
+
+#[test]
+pub fn test_dpss_tapers() {
+    let (len, nw) = (128, 4.0);
+    let DpssTapers { windows, ratios } = dpss_tapers(len, nw, 8, true, DpssNorm::L2);
+    assert_eq!(windows.dim(), (8, len as usize));
+
+    // the tapers are orthonormal
+    for (i, a) in windows.outer_iter().enumerate() {
+        for (j, b) in windows.outer_iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            approx::assert_relative_eq!(a.dot(&b), expected, epsilon = 1e-10);
+        }
+    }
+
+    // the first tapers are well concentrated, the concentration decreases around 2 NW
+    assert!(ratios.iter().take(6).all(|r| *r > 0.99 && *r <= 1.0));
+    assert!(ratios[7] > 0.5 && ratios[7] < 0.9);
+    assert!(ratios.windows(2).into_iter().all(|r| r[0] >= r[1]));
+
+    // even tapers are symmetric with positive sum, odd ones antisymmetric
+    for (k, taper) in windows.outer_iter().enumerate() {
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        for (a, b) in taper.iter().zip(taper.iter().rev()) {
+            approx::assert_relative_eq!(*a, sign * b, epsilon = 1e-10);
+        }
+    }
+    assert!(windows.row(0).sum() > 0.0);
+
+    // even lengths are scaled to approximate the maximum of the continuous window
+    let w = dpss(len, nw, true);
+    let m = len as f64;
+    approx::assert_relative_eq!(
+        w.iter().copied().fold(f64::MIN, f64::max),
+        m * m / (m * m + nw),
+        epsilon = 1e-12
+    );
+    let w = dpss(len + 1, nw, true);
+    approx::assert_relative_eq!(
+        w.iter().copied().fold(f64::MIN, f64::max),
+        1.0,
+        epsilon = 1e-12
+    );
+    let periodic = dpss(len, nw, false);
+    assert_eq!(periodic.len(), len as usize);
+}
+
+#[test]
+pub fn test_dpss_guards() {
+    let window = || WindowType::Dpss {
+        half_bandwidth: 2.5,
+    };
+    assert!(get_window::<f64>(window(), 0, true).is_empty());
+    assert_eq!(get_window::<f64>(window(), 1, true).to_vec(), vec![1.0]);
+    assert_eq!(
+        dpss_tapers::<f64>(0, 2.5, 2, true, None).windows.dim(),
+        (2, 0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "nw must be positive")]
+pub fn test_dpss_bad_half_bandwidth() {
+    dpss::<f64>(16, 8.0, true);
+}
+
+#[test]
+#[should_panic(expected = "nw must be positive")]
+pub fn test_dpss_negative_half_bandwidth() {
+    dpss::<f64>(16, -1.0, true);
+}
+
+#[test]
+pub fn test_firwin_dpss_chebwin() {
+    use sciport_rs::signal::{band_filter::BandFilter, firwin, Sampling, WindowType};
+
+    for window in [
+        WindowType::Dpss {
+            half_bandwidth: 3.0,
+        },
+        WindowType::Chebwin { attenuation: 60.0 },
+    ] {
+        let fir = firwin(
+            41,
+            BandFilter::Lowpass(0.25),
+            None,
+            window,
+            true,
+            Sampling::Digital { fs: 2.0 },
+        )
+        .ba();
+        assert_eq!(fir.b.len(), 41);
+        approx::assert_relative_eq!(
+            fir.b.iter().map(|b| b.re).sum::<f64>(),
+            1.0,
+            epsilon = 1e-10
+        );
+        assert!(fir.is_linear_phase());
+    }
+}
+
+#[test]
+pub fn test_kaiser_i0() {
+    // a zero beta gives a rectangular window
+    assert_eq!(kaiser(5, 0.0, true).to_vec(), vec![1.0; 5]);
+
+    // the edges of a symmetric kaiser window are 1 / I0(beta)
+    for beta in [0.5, 5.0, 8.6, 14.0, 40.0] {
+        let i0 = sciport_rs::special::i0(ndarray::array![beta]).unwrap()[0].re;
+        let w = kaiser(5, beta, true);
+        approx::assert_relative_eq!(w[0], 1.0 / i0, max_relative = 1e-12);
+        approx::assert_relative_eq!(w[2], 1.0, max_relative = 1e-12);
+    }
+}
+
+#[test]
+pub fn test_windows_f32() {
+    for len in [1, 3, 15, 64] {
+        // nw must be less than half the window length
+        let nw = 2.5_f32.min(len as f32 / 4.0);
+        for sym in [true, false] {
+            let windows: Vec<(Vec<f32>, Vec<f64>)> = vec![
+                (hann(len, sym).to_vec(), hann(len, sym).to_vec()),
+                (bohman(len, sym).to_vec(), bohman(len, sym).to_vec()),
+                (tukey(len, 0.3, sym).to_vec(), tukey(len, 0.3, sym).to_vec()),
+                (
+                    taylor(len, 5, 40.0, true, sym).to_vec(),
+                    taylor(len, 5, 40.0, true, sym).to_vec(),
+                ),
+                (
+                    general_gaussian(len, 1.5, 4.0, sym).to_vec(),
+                    general_gaussian(len, 1.5, 4.0, sym).to_vec(),
+                ),
+                (
+                    chebwin(len, 60.0, sym).to_vec(),
+                    chebwin(len, 60.0, sym).to_vec(),
+                ),
+                (
+                    kaiser(len, 8.6, sym).to_vec(),
+                    kaiser(len, 8.6, sym).to_vec(),
+                ),
+                (
+                    dpss(len, nw, sym).to_vec(),
+                    dpss(len, f64::from(nw), sym).to_vec(),
+                ),
+            ];
+            for (single, double) in windows {
+                let double: Vec<f32> = double.into_iter().map(|a| a as f32).collect();
+                approx::assert_relative_eq!(single.as_slice(), double.as_slice(), epsilon = 1e-5);
+            }
+        }
+    }
+
+    use sciport_rs::signal::{band_filter::GenericBandFilter, firwin, GenericSampling, WindowType};
+    let fir = firwin::<f32>(
+        31,
+        GenericBandFilter::Lowpass(0.3),
+        None,
+        WindowType::Hamming,
+        true,
+        GenericSampling::Digital { fs: 2.0 },
+    )
+    .ba();
+    approx::assert_relative_eq!(fir.b.iter().map(|b| b.re).sum::<f32>(), 1.0, epsilon = 1e-5);
+}
@@ -0,0 +1,199 @@
+use crate::common::{gains, with_scipy};
+use ndarray::{array, Array1};
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{firls, output_type::GenericBa, GenericSampling, Sampling};
+
+/// mean squared deviation from `desired` over `[low, high]`
+fn energy(ba: &GenericBa<f64>, low: f64, high: f64, desired: f64) -> f64 {
+    let gains = gains(
+        ba,
+        Array1::linspace(low, high, 2000),
+        Sampling::Digital { fs: 1.0 },
+    );
+    gains.iter().map(|g| (g - desired).powi(2)).sum::<f64>() / gains.len() as f64
+}
+
+#[test]
+fn test_firls_full_band() {
+    // a flat response over the whole band is the pure delay
+    let ba = firls(
+        11,
+        array![0.0, 1.0],
+        array![1.0, 1.0],
+        None,
+        Sampling::Analog,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(ba.b.len(), 11);
+    for (i, b) in ba.b.iter().enumerate() {
+        let expected = if i == 5 { 1.0 } else { 0.0 };
+        approx::assert_abs_diff_eq!(b.re, expected, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_firls_lowpass() {
+    let sampling = Sampling::Digital { fs: 1.0 };
+    let bands = array![0.0, 0.1, 0.2, 0.5];
+    let ba = firls(
+        31,
+        bands.clone(),
+        array![1.0, 1.0, 0.0, 0.0],
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(ba.b.len(), 31);
+    assert!(ba.is_linear_phase());
+    for (x, y) in ba.b.iter().zip(ba.b.iter().rev()) {
+        approx::assert_relative_eq!(x.re, y.re, epsilon = 1e-12);
+    }
+    assert!(gains(&ba, Array1::linspace(0.0, 0.1, 2000), sampling)
+        .iter()
+        .all(|g| (g - 1.0).abs() < 5e-2));
+    assert!(gains(&ba, Array1::linspace(0.2, 0.5, 2000), sampling)
+        .iter()
+        .all(|g| *g < 5e-2));
+
+    // weighting the stopband trades passband error for stopband error
+    let weighted = firls(
+        31,
+        bands,
+        array![1.0, 1.0, 0.0, 0.0],
+        array![1.0, 100.0],
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    assert!(energy(&weighted, 0.2, 0.5, 0.0) < energy(&ba, 0.2, 0.5, 0.0));
+    assert!(energy(&weighted, 0.0, 0.1, 1.0) > energy(&ba, 0.0, 0.1, 1.0));
+}
+
+#[test]
+fn test_firls_f32() {
+    let bands = array![0.0, 0.3, 0.5, 1.0];
+    let desired = array![1.0, 1.0, 0.0, 0.0];
+    let single = firls::<f32>(
+        21,
+        bands.mapv(|f| f as f32),
+        desired.mapv(|d| d as f32),
+        None,
+        GenericSampling::Analog,
+    )
+    .unwrap()
+    .ba();
+    let double = firls(21, bands, desired, None, Sampling::Analog)
+        .unwrap()
+        .ba();
+    for (x, y) in single.b.iter().zip(double.b.iter()) {
+        approx::assert_relative_eq!(x.re as f64, y.re, epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_firls_errors() {
+    use sciport_rs::signal::error::Error;
+    use sciport_rs::signal::firls::Error as FirlsError;
+
+    let sampling = Sampling::Digital { fs: 1.0 };
+    let design = |numtaps: usize, bands: Array1<f64>, desired: Array1<f64>| {
+        firls(numtaps, bands, desired, None, sampling)
+    };
+
+    assert!(matches!(
+        design(30, array![0.0, 0.5], array![1.0, 1.0]),
+        Err(FirlsError::NumTaps(30))
+    ));
+    assert!(matches!(
+        design(31, array![0.0, 0.2, 0.3], array![1.0, 1.0, 0.0]),
+        Err(FirlsError::BandEdges(3))
+    ));
+    assert!(matches!(
+        design(31, array![0.0, 0.6], array![1.0, 1.0]),
+        Err(FirlsError::Range)
+    ));
+    assert!(matches!(
+        design(31, array![0.0, 0.2, 0.3, 0.5], array![1.0, 0.0]),
+        Err(FirlsError::Desired {
+            desired: 2,
+            edges: 4
+        })
+    ));
+    assert!(design(31, array![0.0, 0.2, 0.2, 0.5], array![1.0, 1.0, 0.0, 0.0]).is_ok());
+    assert!(matches!(
+        design(31, array![0.2, 0.2], array![1.0, 1.0]),
+        Err(FirlsError::Monotonic)
+    ));
+    assert!(matches!(
+        design(31, array![0.0, 0.3, 0.2, 0.5], array![1.0, 1.0, 0.0, 0.0]),
+        Err(FirlsError::Overlap)
+    ));
+    assert!(matches!(
+        design(31, array![0.0, 0.5], array![1.0, -1.0]),
+        Err(FirlsError::NegativeDesired)
+    ));
+    assert!(matches!(
+        firls(
+            31,
+            array![0.0, 0.5],
+            array![1.0, 1.0],
+            array![1.0, 1.0],
+            sampling
+        ),
+        Err(FirlsError::Weight {
+            weight: 2,
+            bands: 1
+        })
+    ));
+
+    let error: Error = firls(
+        31,
+        array![0.0, 0.5],
+        array![1.0, 1.0],
+        array![-1.0],
+        sampling,
+    )
+    .unwrap_err()
+    .into();
+    assert!(matches!(error, Error::Firls(FirlsError::NegativeWeight)));
+}
+
+#[test]
+fn with_py_test_firls() {
+    for _ in 0..200 {
+        let numtaps = 2 * thread_rng().gen_range(1..60) + 1;
+        let fs: f64 = thread_rng().gen_range(1.0..1000.0);
+        let edge = thread_rng().gen_range(0.05..0.35) * fs;
+        let width = thread_rng().gen_range(0.02..0.1) * fs;
+        let bands = array![0.0, edge, edge + width, fs / 2.0];
+        let desired = array![
+            thread_rng().gen_range(0.5..1.5),
+            thread_rng().gen_range(0.5..1.5),
+            thread_rng().gen_range(0.0..0.2),
+            thread_rng().gen_range(0.0..0.2)
+        ];
+        let weight = thread_rng().gen_range(0.1..10.0);
+
+        let rust_res = firls(
+            numtaps,
+            bands.clone(),
+            desired.clone(),
+            array![1.0, weight],
+            Sampling::Digital { fs },
+        )
+        .unwrap()
+        .ba();
+        let py_script = format!(
+            "signal.firls({numtaps}, {:?}, {:?}, weight=[1.0, {weight}], fs={fs})",
+            bands.to_vec(),
+            desired.to_vec()
+        );
+        let Some(python) = with_scipy::<Vec<f64>>(&py_script) else {
+            continue;
+        };
+        let rust_res: Vec<f64> = rust_res.b.iter().map(|b| b.re).collect();
+        approx::assert_relative_eq!(rust_res.as_slice(), python.as_slice(), epsilon = 1e-8);
+    }
+}
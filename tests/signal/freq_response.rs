@@ -0,0 +1,222 @@
+use crate::common::{butter, with_scipy};
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{band_filter::BandFilter, output_type::DesiredFilterOutput, *};
+
+const OUTPUTS: [DesiredFilterOutput; 3] = [
+    DesiredFilterOutput::Ba,
+    DesiredFilterOutput::Zpk,
+    DesiredFilterOutput::Sos,
+];
+
+#[test]
+fn test_freq_response_output_types() {
+    let cases = [
+        (Sampling::Digital { fs: 100.0 }, 10.0),
+        (Sampling::Analog, 10.0),
+    ];
+
+    for (sampling, cutoff) in cases {
+        let grid = FrequencyGrid::Frequencies(array![0.0, cutoff]);
+        for output in OUTPUTS {
+            let filter = butter(4, BandFilter::Lowpass(cutoff), sampling, output);
+            let FrequencyResponse { w, h } = filter
+                .freq_response(grid.clone(), sampling)
+                .expect("valid frequency response");
+
+            assert_eq!(w, array![0.0, cutoff]);
+            approx::assert_relative_eq!(h[0].norm(), 1.0, epsilon = 1e-10);
+            approx::assert_relative_eq!(h[1].norm(), 0.5f64.sqrt(), epsilon = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_freqz_grid() {
+    let filter = butter(
+        3,
+        BandFilter::Highpass(0.3),
+        Sampling::Digital { fs: 2.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+
+    let sampling = Sampling::Digital { fs: 2.0 };
+    let half = freqz(
+        &filter,
+        FrequencyGrid::Points { n: 4, whole: false },
+        sampling,
+    );
+    assert_eq!(half.w, array![0.0, 0.25, 0.5, 0.75]);
+
+    let whole = freqz(
+        &filter,
+        FrequencyGrid::Points { n: 4, whole: true },
+        sampling,
+    );
+    assert_eq!(whole.w, array![0.0, 0.5, 1.0, 1.5]);
+
+    // the response of a real filter is conjugate symmetric around the nyquist frequency
+    approx::assert_relative_eq!(whole.h[1].re, whole.h[3].re, epsilon = 1e-12);
+    approx::assert_relative_eq!(whole.h[1].im, -whole.h[3].im, epsilon = 1e-12);
+
+    let rad = freqz(
+        &filter,
+        FrequencyGrid::Points { n: 4, whole: false },
+        Sampling::Digital {
+            fs: std::f64::consts::TAU,
+        },
+    );
+    approx::assert_relative_eq!(rad.w[2], std::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+    approx::assert_relative_eq!(rad.h[2].norm(), half.h[2].norm(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_freqs_findfreqs() {
+    let filter = butter(
+        4,
+        BandFilter::Lowpass(1.0),
+        Sampling::Analog,
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+
+    let FrequencyResponse { w, .. } = freqs(&filter, FrequencyGrid::Points { n: 3, whole: false });
+    approx::assert_relative_eq!(
+        w.as_slice().unwrap(),
+        [0.1, 1.0, 10.0].as_slice(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_freqz_analog() {
+    let filter = |output| butter(4, BandFilter::Lowpass(1.0), Sampling::Analog, output);
+    let grid = || FrequencyGrid::Frequencies(array![0.0, 0.5, 1.0, 4.0]);
+    let expected = freqs(&filter(DesiredFilterOutput::Ba).ba(), grid());
+
+    let responses = [
+        freqz(
+            &filter(DesiredFilterOutput::Ba).ba(),
+            grid(),
+            Sampling::Analog,
+        ),
+        freqz_zpk(
+            &filter(DesiredFilterOutput::Zpk).zpk(),
+            grid(),
+            Sampling::Analog,
+        ),
+        sosfreqz(
+            &filter(DesiredFilterOutput::Sos).sos(),
+            grid(),
+            Sampling::Analog,
+        ),
+    ];
+    for response in responses {
+        assert_eq!(response.w, expected.w);
+        for (r, e) in response.h.iter().zip(expected.h.iter()) {
+            assert!((r - e).norm() < 1e-10, "{r} {e}");
+        }
+    }
+}
+
+#[test]
+fn test_roots_equal_modulus() {
+    // the roots of z^n - c all have the same modulus, which stalls the QR iteration on the
+    // companion matrix
+    for n in [2, 4, 8, 12, 40] {
+        let mut p = Array1::zeros(n + 1);
+        p[0] = Complex64::new(1.0, 0.0);
+        p[n] = Complex64::new(-0.5, 0.0);
+        let r = tools::roots(p.view()).unwrap();
+        assert_eq!(r.len(), n);
+        for r in r {
+            approx::assert_relative_eq!(r.norm(), 0.5_f64.powf(1.0 / n as f64), epsilon = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn with_py_test_freqz() {
+    for _ in 0..200 {
+        let order = thread_rng().gen_range(1..10);
+        let wn = thread_rng().gen_range(0.05..0.95);
+        let n = thread_rng().gen_range(1..100);
+        let whole = thread_rng().gen_bool(0.5);
+        let whole_s = if whole { "True" } else { "False" };
+
+        let python = with_scipy::<(Vec<f64>, Vec<Complex64>)>(&format!(
+            "(lambda w, h: (list(w), list(h)))(*signal.freqz_zpk(*signal.butter({order}, {wn}, output=\"zpk\"), worN={n}, whole={whole_s}, fs=2.0))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        for output in OUTPUTS {
+            let rust = butter(
+                order,
+                BandFilter::Lowpass(wn),
+                Sampling::Digital { fs: 2.0 },
+                output,
+            )
+            .freq_response(
+                FrequencyGrid::Points { n, whole },
+                Sampling::Digital { fs: 2.0 },
+            )
+            .expect("valid frequency response");
+
+            approx::assert_relative_eq!(
+                rust.w.as_slice().unwrap(),
+                python.0.as_slice(),
+                epsilon = 1e-12
+            );
+            for (r, p) in rust.h.iter().zip(python.1.iter()) {
+                assert!((r - p).norm() < 1e-8, "{r} {p}");
+            }
+        }
+    }
+}
+
+#[test]
+fn with_py_test_freqs() {
+    for _ in 0..200 {
+        let order = thread_rng().gen_range(1..10);
+        let wn = thread_rng().gen_range(0.1..100.0);
+        let n = thread_rng().gen_range(2..100);
+
+        let python = with_scipy::<(Vec<f64>, Vec<Complex64>)>(&format!(
+            "(lambda w, h: (list(w), list(h)))(*signal.freqs(*signal.butter({order}, {wn}, analog=True), worN={n}))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let filter = butter(
+            order,
+            BandFilter::Lowpass(wn),
+            Sampling::Analog,
+            DesiredFilterOutput::Ba,
+        )
+        .ba();
+        let rust = freqs(&filter, FrequencyGrid::Points { n, whole: false });
+
+        approx::assert_relative_eq!(
+            rust.w.as_slice().unwrap(),
+            python.0.as_slice(),
+            max_relative = 1e-10
+        );
+
+        let rust = freqs(
+            &filter,
+            FrequencyGrid::Frequencies(Array1::from_vec(python.0)),
+        );
+        for (r, p) in rust.h.iter().zip(python.1.iter()) {
+            assert!((r - p).norm() < 1e-8, "{r} {p}");
+        }
+    }
+}
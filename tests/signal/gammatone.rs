@@ -0,0 +1,191 @@
+use crate::common::with_scipy;
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    error::Error, freqz, gammatone, output_type::DesiredFilterOutput, sosfreqz,
+    Error as FilterError, FrequencyGrid, GammatoneType, Sampling,
+};
+
+fn gains(freq: f64, ftype: GammatoneType, fs: f64) -> Vec<f64> {
+    let sampling = Sampling::Digital { fs };
+    let ba = gammatone(freq, ftype, sampling, DesiredFilterOutput::Ba)
+        .unwrap()
+        .ba();
+    freqz(
+        &ba,
+        FrequencyGrid::Frequencies(array![freq, freq / 4.0, fs / 2.0 - 1.0]),
+        sampling,
+    )
+    .h
+    .iter()
+    .map(|h| h.norm())
+    .collect()
+}
+
+#[test]
+fn test_gammatone_iir() {
+    for (freq, fs) in [(440.0, 16000.0), (1000.0, 44100.0), (100.0, 8000.0)] {
+        let gain = gains(freq, GammatoneType::Iir, fs);
+        approx::assert_relative_eq!(gain[0], 1.0, epsilon = 1e-4);
+        assert!(gain[1] < 0.1 && gain[2] < 0.1, "{gain:?}");
+    }
+
+    let sampling = Sampling::Digital { fs: 16000.0 };
+    let ba = gammatone(440.0, GammatoneType::Iir, sampling, DesiredFilterOutput::Ba)
+        .unwrap()
+        .ba();
+    assert_eq!(ba.b.len(), 5);
+    assert_eq!(ba.a.len(), 9);
+    assert!(ba.is_stable(sampling));
+
+    let sos = gammatone(
+        440.0,
+        GammatoneType::Iir,
+        sampling,
+        DesiredFilterOutput::Sos,
+    )
+    .unwrap()
+    .sos();
+    let grid = || FrequencyGrid::Frequencies(Array1::linspace(0.0, 8000.0, 101));
+    let expected = freqz(&ba, grid(), sampling).h;
+    let from_sos = sosfreqz(&sos, grid(), sampling).h;
+    // the poles have multiplicity four, their roots are only accurate to about eps^(1/4)
+    for (e, s) in expected.iter().zip(from_sos.iter()) {
+        assert!((e - s).norm() < 1e-4, "{e} {s}");
+    }
+}
+
+#[test]
+fn test_gammatone_fir() {
+    let fir = |order, numtaps| GammatoneType::Fir { order, numtaps };
+
+    let ba = gammatone(
+        440.0,
+        fir(None, None),
+        Sampling::Digital { fs: 16000.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(ba.b.len(), 240);
+    assert_eq!(ba.a, array![Complex64::new(1.0, 0.0)]);
+
+    let ba = gammatone(
+        0.2,
+        fir(None, None),
+        Sampling::Digital { fs: 2.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(ba.b.len(), 15);
+
+    let gain = gains(1000.0, fir(None, None), 16000.0);
+    approx::assert_relative_eq!(gain[0], 1.0, epsilon = 1e-2);
+    assert!(gain[1] < 0.1 && gain[2] < 0.1, "{gain:?}");
+
+    let gain = gains(1000.0, fir(Some(2), Some(400)), 16000.0);
+    assert!(gain[0] > gain[1] && gain[0] > gain[2], "{gain:?}");
+}
+
+#[test]
+fn test_gammatone_errors() {
+    let check = |result: Result<_, Error>, expected: fn(&gammatone::Error) -> bool| match result {
+        Err(Error::IIRFilter(FilterError::Gammatone(e))) => assert!(expected(&e), "{e}"),
+        _ => panic!("expected an error"),
+    };
+    let sampling = Sampling::Digital { fs: 16000.0 };
+
+    check(
+        gammatone(
+            440.0,
+            GammatoneType::Iir,
+            Sampling::Analog,
+            DesiredFilterOutput::Ba,
+        ),
+        |e| matches!(e, gammatone::Error::Analog),
+    );
+    check(
+        gammatone(
+            8000.0,
+            GammatoneType::Iir,
+            sampling,
+            DesiredFilterOutput::Ba,
+        ),
+        |e| matches!(e, gammatone::Error::Frequency { .. }),
+    );
+    check(
+        gammatone(
+            440.0,
+            GammatoneType::Fir {
+                order: Some(25),
+                numtaps: None,
+            },
+            sampling,
+            DesiredFilterOutput::Ba,
+        ),
+        |e| matches!(e, gammatone::Error::Order(25)),
+    );
+    check(
+        gammatone(
+            440.0,
+            GammatoneType::Fir {
+                order: None,
+                numtaps: Some(0),
+            },
+            sampling,
+            DesiredFilterOutput::Ba,
+        ),
+        |e| matches!(e, gammatone::Error::NumTaps),
+    );
+}
+
+#[test]
+fn with_py_test_gammatone() {
+    for _ in 0..200 {
+        let fs: f64 = thread_rng().gen_range(100.0..48000.0);
+        let freq = thread_rng().gen_range(0.01..0.49) * fs;
+        let order = thread_rng().gen_range(1..=24);
+        let numtaps = thread_rng().gen_range(1..100);
+        let sampling = Sampling::Digital { fs };
+
+        let cases = [
+            (
+                format!("signal.gammatone({freq}, 'iir', fs={fs})"),
+                GammatoneType::Iir,
+            ),
+            (
+                format!("signal.gammatone({freq}, 'fir', fs={fs})"),
+                GammatoneType::Fir {
+                    order: None,
+                    numtaps: None,
+                },
+            ),
+            (
+                format!(
+                    "signal.gammatone({freq}, 'fir', order={order}, numtaps={numtaps}, fs={fs})"
+                ),
+                GammatoneType::Fir {
+                    order: Some(order),
+                    numtaps: Some(numtaps),
+                },
+            ),
+        ];
+        for (script, ftype) in cases {
+            let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+                "(lambda b, a: (list(b), list(a)))(*{script})"
+            ));
+            let Some((b, a)) = python else {
+                continue;
+            };
+
+            let ba = gammatone(freq, ftype, sampling, DesiredFilterOutput::Ba)
+                .unwrap()
+                .ba();
+            let re = |c: &Array1<Complex64>| c.mapv(|c| c.re).to_vec();
+            approx::assert_relative_eq!(re(&ba.b).as_slice(), b.as_slice(), max_relative = 1e-8);
+            approx::assert_relative_eq!(re(&ba.a).as_slice(), a.as_slice(), max_relative = 1e-8);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::common::{butter, with_scipy};
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    bessel::{besselap, BesselNorm},
+    output_type::{DesiredFilterOutput, GenericBa, GenericZpk},
+    *,
+};
+
+#[test]
+fn test_group_delay_linear_phase() {
+    let fir = firwin(
+        21,
+        BandFilter::Lowpass(0.3),
+        None,
+        WindowType::Hamming,
+        true,
+        Sampling::Digital { fs: 2.0 },
+    )
+    .ba();
+
+    let grid = FrequencyGrid::Frequencies(Array1::linspace(0.0, 0.25, 10));
+    let gd = group_delay(&fir, grid, Sampling::Digital { fs: 2.0 });
+
+    assert!(gd.singular.is_empty());
+    for gd in gd.gd.iter() {
+        approx::assert_relative_eq!(*gd, 10.0, epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn test_group_delay_singular() {
+    let one = Complex64::new(1.0, 0.0);
+    let ba = GenericBa {
+        b: array![one, one, one],
+        a: array![one],
+    };
+
+    // zeros at 1/3 and 2/3 of the sampling frequency
+    let gd = group_delay(
+        &ba,
+        FrequencyGrid::Points { n: 6, whole: true },
+        Sampling::Digital { fs: 6.0 },
+    );
+    assert_eq!(gd.w, array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(gd.near_singular, vec![2, 4]);
+    approx::assert_relative_eq!(gd.gd[0], 1.0, epsilon = 1e-12);
+
+    let zero = Complex64::from_polar(1.0, std::f64::consts::TAU / 3.0);
+    let zpk = GenericZpk {
+        z: array![zero, zero.conj()],
+        p: Array1::zeros(2),
+        k: 1.0,
+    };
+    let gd = group_delay_zpk(
+        &zpk,
+        FrequencyGrid::Frequencies(array![0.0, 2.0]),
+        Sampling::Digital { fs: 6.0 },
+    );
+    approx::assert_relative_eq!(gd.gd[0], 1.0, epsilon = 1e-12);
+    assert!(gd.singular.contains(&1) || gd.near_singular.contains(&1));
+}
+
+#[test]
+fn test_group_delay_ba_zpk() {
+    let grid = FrequencyGrid::Frequencies(array![0.0, 0.1, 0.3, 0.5, 0.9]);
+
+    for sampling in [Sampling::Analog, Sampling::Digital { fs: 2.0 }] {
+        let ba = butter(
+            4,
+            BandFilter::Lowpass(0.3),
+            sampling,
+            DesiredFilterOutput::Ba,
+        )
+        .ba();
+        let zpk = butter(
+            4,
+            BandFilter::Lowpass(0.3),
+            sampling,
+            DesiredFilterOutput::Zpk,
+        )
+        .zpk();
+
+        let gd_ba = group_delay(&ba, grid.clone(), sampling);
+        let gd_zpk = group_delay_zpk(&zpk, grid.clone(), sampling);
+
+        approx::assert_relative_eq!(
+            gd_ba.gd.as_slice().unwrap(),
+            gd_zpk.gd.as_slice().unwrap(),
+            max_relative = 1e-8
+        );
+    }
+
+    // the delay of a normalized analog butterworth filter at DC is the sum of -1 / p
+    let zpk = butter(
+        4,
+        BandFilter::Lowpass(1.0),
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    )
+    .zpk();
+    let expected = zpk.p.iter().map(|p| (-1.0 / p).re).sum::<f64>();
+    let gd = group_delay_zpk(
+        &zpk,
+        FrequencyGrid::Frequencies(array![0.0]),
+        Sampling::Analog,
+    );
+    approx::assert_relative_eq!(gd.gd[0], expected, epsilon = 1e-12);
+}
+
+#[test]
+fn with_py_test_group_delay() {
+    for _ in 0..200 {
+        let order = thread_rng().gen_range(1..10);
+        let wn = thread_rng().gen_range(0.05..0.95);
+        let n = thread_rng().gen_range(1..100);
+
+        let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+            "(lambda w, gd: (list(w), list(gd)))(*signal.group_delay(signal.butter({order}, {wn}), w={n}, fs=2.0))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = butter(
+            order,
+            BandFilter::Lowpass(wn),
+            Sampling::Digital { fs: 2.0 },
+            DesiredFilterOutput::Ba,
+        )
+        .ba();
+        let rust = group_delay(
+            &ba,
+            FrequencyGrid::Points { n, whole: false },
+            Sampling::Digital { fs: 2.0 },
+        );
+
+        approx::assert_relative_eq!(
+            rust.w.as_slice().unwrap(),
+            python.0.as_slice(),
+            epsilon = 1e-12
+        );
+        approx::assert_relative_eq!(
+            rust.gd.as_slice().unwrap(),
+            python.1.as_slice(),
+            max_relative = 1e-6
+        );
+    }
+}
+
+#[test]
+fn with_py_test_bessel_group_delay() {
+    for order in 1..20 {
+        let python = with_scipy::<Vec<f64>>(&format!(
+            "list(signal.group_delay(signal.bessel({order}, 0.1, norm=\"delay\"), w=[0.0, 0.01], fs=2.0)[1])"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        // the delay normalized prototype has a unit group delay at DC
+        let proto = besselap::<f64>(order, BesselNorm::Delay).expect("valid filter output");
+        let gd = group_delay_zpk(
+            &proto,
+            FrequencyGrid::Frequencies(array![0.0]),
+            Sampling::Analog,
+        );
+        approx::assert_relative_eq!(gd.gd[0], 1.0, epsilon = 1e-8);
+
+        let zpk = BesselFilter {
+            norm: BesselNorm::Delay,
+            settings: GenericIIRFilterSettings {
+                order,
+                band_filter: BandFilter::Lowpass(0.1),
+                analog: Sampling::Digital { fs: 2.0 },
+            },
+        }
+        .compute_filter(DesiredFilterOutput::Zpk)
+        .expect("valid filter output")
+        .zpk();
+        let gd = group_delay_zpk(
+            &zpk,
+            FrequencyGrid::Frequencies(array![0.0, 0.01]),
+            Sampling::Digital { fs: 2.0 },
+        );
+        approx::assert_relative_eq!(
+            gd.gd.as_slice().unwrap(),
+            python.as_slice(),
+            max_relative = 1e-6
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::common::{check_zpk_filter, with_scipy};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::{BandFilter, GenericOrdBandFilter},
+    iirdesign, iirfilter,
+    output_type::DesiredFilterOutput,
+    BesselNorm, EllipFilter, EllipOrd, GenericIIRFilterSettings, IIRFilterDesign, IIRFilterFamily,
+    OrdCompute, Sampling,
+};
+
+#[test]
+fn test_iirdesign_matches_ord_and_design() {
+    let band_filter = GenericOrdBandFilter::bandpass(0.2, 0.5, 0.1, 0.6).unwrap();
+    let sampling = Sampling::Digital { fs: 2.0 };
+
+    let rust = iirdesign(
+        band_filter,
+        1.0,
+        60.0,
+        IIRFilterFamily::Ellip,
+        sampling,
+        DesiredFilterOutput::Zpk,
+    )
+    .expect("valid filter output")
+    .zpk();
+
+    let ord = EllipOrd {
+        band_filter,
+        gpass: 1.0,
+        gstop: 60.0,
+        sampling,
+    }
+    .compute_order()
+    .expect("valid order");
+    let expected = EllipFilter {
+        rp: 1.0,
+        rs: 60.0,
+        settings: GenericIIRFilterSettings {
+            order: ord.order,
+            band_filter: ord.filter,
+            analog: sampling,
+        },
+    }
+    .compute_filter(DesiredFilterOutput::Zpk)
+    .expect("valid filter output")
+    .zpk();
+
+    assert_eq!(rust.p.len(), 2 * ord.order as usize);
+    assert_eq!(rust.z, expected.z);
+    assert_eq!(rust.p, expected.p);
+    assert_eq!(rust.k, expected.k);
+}
+
+#[test]
+fn test_iirdesign_errors() {
+    let band_filter = GenericOrdBandFilter::lowpass(0.2, 0.3).unwrap();
+    let bessel = iirdesign(
+        band_filter,
+        1.0,
+        40.0,
+        IIRFilterFamily::Bessel(BesselNorm::Phase),
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    );
+    assert!(bessel.is_err());
+
+    let cheby1 = iirfilter(
+        4,
+        BandFilter::Lowpass(0.2),
+        None,
+        None,
+        IIRFilterFamily::Cheby1,
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    );
+    assert!(cheby1.is_err());
+}
+
+#[test]
+fn with_py_test_iirdesign() {
+    let families = [
+        (IIRFilterFamily::Butter, "butter"),
+        (IIRFilterFamily::Cheby1, "cheby1"),
+        (IIRFilterFamily::Cheby2, "cheby2"),
+        (IIRFilterFamily::Ellip, "ellip"),
+    ];
+
+    for _ in 0..200 {
+        let gpass = thread_rng().gen_range(0.5..3.0);
+        let gstop = thread_rng().gen_range(20.0..60.0);
+
+        let mut edges = [0.0; 4].map(|_: f64| thread_rng().gen_range(0.05..0.95));
+        edges.sort_by(f64::total_cmp);
+        let [e0, e1, e2, e3] = edges;
+
+        let (band_filter, wp, ws) = match thread_rng().gen_range(0..4) {
+            0 => (
+                GenericOrdBandFilter::lowpass(e0, e1),
+                format!("{e0}"),
+                format!("{e1}"),
+            ),
+            1 => (
+                GenericOrdBandFilter::highpass(e1, e0),
+                format!("{e1}"),
+                format!("{e0}"),
+            ),
+            2 => (
+                GenericOrdBandFilter::bandpass(e1, e2, e0, e3),
+                format!("[{e1}, {e2}]"),
+                format!("[{e0}, {e3}]"),
+            ),
+            _ => (
+                GenericOrdBandFilter::bandstop(e0, e3, e1, e2),
+                format!("[{e0}, {e3}]"),
+                format!("[{e1}, {e2}]"),
+            ),
+        };
+        let band_filter = band_filter.expect("valid band edges");
+
+        let (sampling, analog, fs) = match thread_rng().gen_range(0..2) {
+            0 => (Sampling::Analog, "True", "None".to_string()),
+            _ => (Sampling::Digital { fs: 2.0 }, "False", "2.0".to_string()),
+        };
+
+        for (family, ftype) in families {
+            let python = with_scipy::<(Vec<Complex64>, Vec<Complex64>, f64)>(&format!(
+                "signal.iirdesign({wp}, {ws}, {gpass}, {gstop}, analog={analog}, ftype=\"{ftype}\", output=\"zpk\", fs={fs})"
+            ));
+            let python = if let Some(p) = python {
+                p
+            } else {
+                continue;
+            };
+
+            let rust = iirdesign(
+                band_filter,
+                gpass,
+                gstop,
+                family,
+                sampling,
+                DesiredFilterOutput::Zpk,
+            )
+            .expect("valid filter output")
+            .zpk();
+
+            assert_eq!(rust.z.len(), python.0.len());
+            assert_eq!(rust.p.len(), python.1.len());
+            assert!(check_zpk_filter(rust, python));
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::common::{gains, with_scipy};
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    error::Error, freqz, freqz_zpk, iircomb, iirnotch, iirpeak, output_type::DesiredFilterOutput,
+    sosfreqz, CombType, Error as FilterError, FrequencyGrid, Sampling,
+};
+
+#[test]
+fn test_iirnotch() {
+    let fs = 1000.0;
+    let sampling = Sampling::Digital { fs };
+    let ba = iirnotch(60.0, 30.0, sampling, DesiredFilterOutput::Ba)
+        .unwrap()
+        .ba();
+
+    let gain = gains(&ba, array![0.0, 60.0, 59.0, 61.0, 500.0], sampling);
+    approx::assert_relative_eq!(gain[0], 1.0, epsilon = 1e-12);
+    approx::assert_relative_eq!(gain[1], 0.0, epsilon = 1e-12);
+    approx::assert_relative_eq!(gain[2], std::f64::consts::FRAC_1_SQRT_2, epsilon = 1e-2);
+    approx::assert_relative_eq!(gain[3], std::f64::consts::FRAC_1_SQRT_2, epsilon = 1e-2);
+    approx::assert_relative_eq!(gain[4], 1.0, epsilon = 1e-12);
+    assert!(ba.is_stable(sampling));
+
+    let ba = iirpeak(60.0, 30.0, sampling, DesiredFilterOutput::Ba)
+        .unwrap()
+        .ba();
+    let gain = gains(&ba, array![0.0, 60.0, 500.0], sampling);
+    approx::assert_relative_eq!(gain[0], 0.0, epsilon = 1e-12);
+    approx::assert_relative_eq!(gain[1], 1.0, epsilon = 1e-12);
+    approx::assert_relative_eq!(gain[2], 0.0, epsilon = 1e-12);
+    assert!(ba.is_stable(sampling));
+}
+
+#[test]
+fn test_iircomb() {
+    let fs = 1000.0;
+    let sampling = Sampling::Digital { fs };
+    let harmonics = array![50.0, 100.0, 150.0, 450.0];
+    let between = array![25.0, 75.0, 475.0];
+
+    let notch = iircomb(
+        50.0,
+        30.0,
+        CombType::Notch,
+        false,
+        sampling,
+        DesiredFilterOutput::Ba,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(notch.b.len(), 21);
+    for gain in gains(&notch, harmonics.clone(), sampling) {
+        approx::assert_relative_eq!(gain, 0.0, epsilon = 1e-12);
+    }
+    for gain in gains(&notch, between.clone(), sampling) {
+        approx::assert_relative_eq!(gain, 1.0, epsilon = 1e-12);
+    }
+
+    let peak = iircomb(
+        50.0,
+        30.0,
+        CombType::Peak,
+        false,
+        sampling,
+        DesiredFilterOutput::Ba,
+    )
+    .unwrap()
+    .ba();
+    for gain in gains(&peak, harmonics.clone(), sampling) {
+        approx::assert_relative_eq!(gain, 0.0, epsilon = 1e-12);
+    }
+    for gain in gains(&peak, between.clone(), sampling) {
+        approx::assert_relative_eq!(gain, 1.0, epsilon = 1e-12);
+    }
+
+    let shifted = iircomb(
+        50.0,
+        30.0,
+        CombType::Notch,
+        true,
+        sampling,
+        DesiredFilterOutput::Ba,
+    )
+    .unwrap()
+    .ba();
+    for gain in gains(&shifted, between, sampling) {
+        approx::assert_relative_eq!(gain, 0.0, epsilon = 1e-12);
+    }
+    for gain in gains(&shifted, harmonics, sampling) {
+        approx::assert_relative_eq!(gain, 1.0, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_iirnotch_outputs() {
+    let fs = 48000.0;
+    let sampling = Sampling::Digital { fs };
+    let frequencies = Array1::linspace(0.0, fs / 2.0, 97);
+    let grid = || FrequencyGrid::Frequencies(frequencies.clone());
+
+    let designs: [&dyn Fn(DesiredFilterOutput) -> _; 3] = [
+        &|output| iirnotch(1000.0, 5.0, sampling, output),
+        &|output| iirpeak(1000.0, 5.0, sampling, output),
+        &|output| iircomb(4000.0, 5.0, CombType::Notch, false, sampling, output),
+    ];
+    for design in designs {
+        let ba = design(DesiredFilterOutput::Ba).unwrap().ba();
+        let zpk = design(DesiredFilterOutput::Zpk).unwrap().zpk();
+        let sos = design(DesiredFilterOutput::Sos).unwrap().sos();
+
+        let expected = freqz(&ba, grid(), sampling).h;
+        let from_zpk = freqz_zpk(&zpk, grid(), sampling).h;
+        let from_sos = sosfreqz(&sos, grid(), sampling).h;
+        for ((e, z), s) in expected.iter().zip(from_zpk.iter()).zip(from_sos.iter()) {
+            assert!((e - z).norm() < 1e-8, "{e} {z}");
+            assert!((e - s).norm() < 1e-8, "{e} {s}");
+        }
+    }
+}
+
+#[test]
+fn test_iirnotch_errors() {
+    let sampling = Sampling::Digital { fs: 1000.0 };
+    let check = |result: Result<_, Error>, expected: fn(&iirnotch::Error) -> bool| match result {
+        Err(Error::IIRFilter(FilterError::IIRNotch(e))) => assert!(expected(&e), "{e}"),
+        _ => panic!("expected an error"),
+    };
+
+    check(
+        iirnotch(60.0, 30.0, Sampling::Analog, DesiredFilterOutput::Ba),
+        |e| matches!(e, iirnotch::Error::Analog),
+    );
+    check(
+        iirpeak(600.0, 30.0, sampling, DesiredFilterOutput::Ba),
+        |e| matches!(e, iirnotch::Error::Frequency { .. }),
+    );
+    check(
+        iirnotch(60.0, -1.0, sampling, DesiredFilterOutput::Ba),
+        |e| matches!(e, iirnotch::Error::Quality(_)),
+    );
+    check(
+        iircomb(
+            60.0,
+            30.0,
+            CombType::Notch,
+            false,
+            sampling,
+            DesiredFilterOutput::Ba,
+        ),
+        |e| matches!(e, iirnotch::Error::CombFrequency { .. }),
+    );
+}
+
+#[test]
+fn with_py_test_iirnotch() {
+    for _ in 0..200 {
+        let fs: f64 = thread_rng().gen_range(100.0..10000.0);
+        let w0 = thread_rng().gen_range(0.01..0.49) * fs;
+        let quality = thread_rng().gen_range(0.5..100.0);
+        let sampling = Sampling::Digital { fs };
+
+        for (name, design) in [
+            ("iirnotch", iirnotch::<f64> as fn(_, _, _, _) -> _),
+            ("iirpeak", iirpeak),
+        ] {
+            let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+                "(lambda b, a: (list(b), list(a)))(*signal.{name}({w0}, {quality}, fs={fs}))"
+            ));
+            let Some((b, a)) = python else {
+                continue;
+            };
+
+            let ba = design(w0, quality, sampling, DesiredFilterOutput::Ba)
+                .unwrap()
+                .ba();
+            let re = |c: &Array1<Complex64>| c.mapv(|c| c.re).to_vec();
+            approx::assert_relative_eq!(re(&ba.b).as_slice(), b.as_slice(), epsilon = 1e-12);
+            approx::assert_relative_eq!(re(&ba.a).as_slice(), a.as_slice(), epsilon = 1e-12);
+        }
+
+        let n = thread_rng().gen_range(3..40);
+        let w0 = fs / n as f64;
+        for (ftype, comb_type) in [("notch", CombType::Notch), ("peak", CombType::Peak)] {
+            for pass_zero in [false, true] {
+                let py_pass_zero = if pass_zero { "True" } else { "False" };
+                let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+                    "(lambda b, a: (list(b), list(a)))(*signal.iircomb({w0}, {quality}, ftype='{ftype}', fs={fs}, pass_zero={py_pass_zero}))"
+                ));
+                let Some((b, a)) = python else {
+                    continue;
+                };
+
+                let ba = iircomb(
+                    w0,
+                    quality,
+                    comb_type,
+                    pass_zero,
+                    sampling,
+                    DesiredFilterOutput::Ba,
+                )
+                .unwrap()
+                .ba();
+                let re = |c: &Array1<Complex64>| c.mapv(|c| c.re).to_vec();
+                approx::assert_relative_eq!(re(&ba.b).as_slice(), b.as_slice(), epsilon = 1e-12);
+                approx::assert_relative_eq!(re(&ba.a).as_slice(), a.as_slice(), epsilon = 1e-12);
+            }
+        }
+    }
+}
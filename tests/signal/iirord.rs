@@ -0,0 +1,253 @@
+use crate::common::with_scipy;
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::{BandFilter, GenericOrdBandFilter},
+    output_type::{DesiredFilterOutput, GenericZpk},
+    *,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Family {
+    Cheby1,
+    Cheby2,
+    Ellip,
+}
+
+impl Family {
+    fn scipy_name(&self) -> &'static str {
+        match self {
+            Family::Cheby1 => "cheb1ord",
+            Family::Cheby2 => "cheb2ord",
+            Family::Ellip => "ellipord",
+        }
+    }
+
+    fn ord(
+        &self,
+        band_filter: GenericOrdBandFilter<f64>,
+        gpass: f64,
+        gstop: f64,
+        sampling: Sampling,
+    ) -> OrdResult<f64> {
+        let ord: Box<dyn OrdCompute<f64>> = match self {
+            Family::Cheby1 => Box::new(Cheby1Ord {
+                band_filter,
+                gpass,
+                gstop,
+                sampling,
+            }),
+            Family::Cheby2 => Box::new(Cheby2Ord {
+                band_filter,
+                gpass,
+                gstop,
+                sampling,
+            }),
+            Family::Ellip => Box::new(EllipOrd {
+                band_filter,
+                gpass,
+                gstop,
+                sampling,
+            }),
+        };
+        ord.compute_order().expect("valid order")
+    }
+
+    fn design(&self, ord: OrdResult<f64>, gpass: f64, gstop: f64) -> GenericZpk<f64> {
+        let settings = GenericIIRFilterSettings {
+            order: ord.order,
+            band_filter: ord.filter,
+            analog: Sampling::Analog,
+        };
+        match self {
+            Family::Cheby1 => Cheby1Filter {
+                rp: gpass,
+                settings,
+            }
+            .compute_filter(DesiredFilterOutput::Zpk),
+            Family::Cheby2 => Cheby2Filter {
+                rs: gstop,
+                settings,
+            }
+            .compute_filter(DesiredFilterOutput::Zpk),
+            Family::Ellip => EllipFilter {
+                rp: gpass,
+                rs: gstop,
+                settings,
+            }
+            .compute_filter(DesiredFilterOutput::Zpk),
+        }
+        .expect("valid filter output")
+        .zpk()
+    }
+}
+
+const FAMILIES: [Family; 3] = [Family::Cheby1, Family::Cheby2, Family::Ellip];
+
+/// analog gain in dB at `w` rad/s
+fn gain_db(zpk: &GenericZpk<f64>, w: f64) -> f64 {
+    let s = Complex64::new(0.0, w);
+    let h = zpk
+        .z
+        .iter()
+        .fold(Complex64::new(zpk.k, 0.0), |h, z| h * (s - z));
+    let h = zpk.p.iter().fold(h, |h, p| h / (s - p));
+    20.0 * h.norm().log10()
+}
+
+#[test]
+fn test_iirord_meets_specification() {
+    let (gpass, gstop) = (1.0, 40.0);
+    let specs = [
+        (
+            GenericOrdBandFilter::lowpass(20.0, 30.0).unwrap(),
+            vec![20.0],
+            vec![30.0],
+        ),
+        (
+            GenericOrdBandFilter::highpass(30.0, 20.0).unwrap(),
+            vec![30.0],
+            vec![20.0],
+        ),
+        (
+            GenericOrdBandFilter::bandpass(20.0, 50.0, 14.0, 60.0).unwrap(),
+            vec![20.0, 50.0],
+            vec![14.0, 60.0],
+        ),
+        (
+            GenericOrdBandFilter::bandstop(10.0, 60.0, 20.0, 50.0).unwrap(),
+            vec![10.0, 60.0],
+            vec![20.0, 50.0],
+        ),
+    ];
+
+    for (band_filter, wp, ws) in specs {
+        for family in FAMILIES {
+            let ord = family.ord(band_filter, gpass, gstop, Sampling::Analog);
+            let zpk = family.design(ord, gpass, gstop);
+
+            for w in wp.iter() {
+                assert!(gain_db(&zpk, *w) > -gpass - 1e-6, "{family:?} passband {w}");
+            }
+            for w in ws.iter() {
+                assert!(gain_db(&zpk, *w) < -gstop + 1e-6, "{family:?} stopband {w}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cheb2ord_bandstop() {
+    let ord = cheb2ord(
+        GenericOrdBandFilter::bandstop(0.1, 0.6, 0.2, 0.5).unwrap(),
+        3.0,
+        60.0,
+        Sampling::Digital { fs: 2.0 },
+    )
+    .expect("valid order");
+
+    assert_eq!(ord.order, 7);
+    let BandFilter::Bandstop { low, high } = ord.filter else {
+        panic!("expected a bandstop filter")
+    };
+    approx::assert_relative_eq!(low, 0.19776, max_relative = 1e-4);
+    approx::assert_relative_eq!(high, 0.50381, max_relative = 1e-4);
+}
+
+#[test]
+fn test_iirord_bad_edges() {
+    use sciport_rs::signal::butterord::Error;
+
+    let digital = Sampling::Digital { fs: 100.0 };
+    let above_nyquist = GenericOrdBandFilter::lowpass(60.0, 70.0).unwrap();
+    let coincident = GenericOrdBandFilter::lowpass(20.0, 20.0).unwrap();
+
+    assert!(matches!(
+        cheb1ord(above_nyquist, 3.0, 40.0, digital),
+        Err(Error::EdgesAboveNyquist)
+    ));
+    assert!(matches!(
+        cheb2ord(above_nyquist, 3.0, 40.0, digital),
+        Err(Error::EdgesAboveNyquist)
+    ));
+    assert!(matches!(
+        ellipord(above_nyquist, 3.0, 40.0, digital),
+        Err(Error::EdgesAboveNyquist)
+    ));
+
+    assert!(matches!(
+        cheb1ord(coincident, 3.0, 40.0, digital),
+        Err(Error::BadOrder(_))
+    ));
+    assert!(matches!(
+        cheb2ord(coincident, 3.0, 40.0, digital),
+        Err(Error::BadOrder(_))
+    ));
+    assert!(matches!(
+        ellipord(coincident, 3.0, 40.0, digital),
+        Err(Error::BadOrder(_))
+    ));
+}
+
+#[test]
+fn with_py_test_iirord() {
+    for _ in 0..500 {
+        let kind = thread_rng().gen_range(0..4);
+        let gpass = thread_rng().gen_range(0.5..3.0);
+        let gstop = thread_rng().gen_range(20.0..80.0);
+
+        let mut edges = [0.0; 4].map(|_: f64| thread_rng().gen_range(0.05..0.95));
+        edges.sort_by(f64::total_cmp);
+        let [e0, e1, e2, e3] = edges;
+
+        let (band_filter, wp, ws) = match kind {
+            0 => (
+                GenericOrdBandFilter::lowpass(e0, e1),
+                format!("{e0}"),
+                format!("{e1}"),
+            ),
+            1 => (
+                GenericOrdBandFilter::highpass(e1, e0),
+                format!("{e1}"),
+                format!("{e0}"),
+            ),
+            2 => (
+                GenericOrdBandFilter::bandpass(e1, e2, e0, e3),
+                format!("[{e1}, {e2}]"),
+                format!("[{e0}, {e3}]"),
+            ),
+            3 => (
+                GenericOrdBandFilter::bandstop(e0, e3, e1, e2),
+                format!("[{e0}, {e3}]"),
+                format!("[{e1}, {e2}]"),
+            ),
+            _ => unreachable!(),
+        };
+        let band_filter = band_filter.expect("valid band edges");
+
+        let (sampling, analog, fs) = match thread_rng().gen_range(0..2) {
+            0 => (Sampling::Analog, "True", "None".to_string()),
+            _ => (Sampling::Digital { fs: 2.0 }, "False", "2.0".to_string()),
+        };
+
+        for family in FAMILIES {
+            let python = with_scipy::<(u32, Vec<f64>)>(&format!(
+                "(lambda n, wn: (n, list(np.atleast_1d(wn))))(*signal.{}({wp}, {ws}, {gpass}, {gstop}, analog={analog}, fs={fs}))",
+                family.scipy_name()
+            ));
+            let python = if let Some(p) = python {
+                p
+            } else {
+                continue;
+            };
+
+            let rust = family.ord(band_filter, gpass, gstop, sampling);
+            assert_eq!(rust.order, python.0);
+            approx::assert_relative_eq!(
+                rust.filter.to_vec().as_slice(),
+                python.1.as_slice(),
+                max_relative = 1e-6
+            );
+        }
+    }
+}
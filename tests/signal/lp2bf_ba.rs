@@ -0,0 +1,138 @@
+use crate::common::with_scipy;
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::{lp2bf, lp2bf_zpk, lp2bp, lp2bs, lp2hp, lp2lp, BandFilter},
+    butter::buttap,
+    cheby1::cheb1ap,
+    output_type::{GenericBa, GenericZpk},
+    tools::{ba::Error, bilinear, bilinear_zpk, normalize, zpk2ba},
+};
+
+fn trimmed(a: &Array1<Complex64>) -> Vec<f64> {
+    a.iter()
+        .map(|a| a.re)
+        .skip_while(|a| a.abs() < 1e-12)
+        .collect()
+}
+
+fn assert_same_tf(rust: GenericBa<f64>, expected: GenericZpk<f64>) {
+    let expected = normalize(zpk2ba(expected)).unwrap();
+    approx::assert_relative_eq!(
+        trimmed(&rust.b).as_slice(),
+        trimmed(&expected.b).as_slice(),
+        epsilon = 1e-10,
+        max_relative = 1e-8
+    );
+    approx::assert_relative_eq!(
+        trimmed(&rust.a).as_slice(),
+        trimmed(&expected.a).as_slice(),
+        epsilon = 1e-10,
+        max_relative = 1e-8
+    );
+}
+
+#[test]
+fn test_lp2bf_ba_matches_zpk() {
+    let bands = [
+        BandFilter::Lowpass(2.0),
+        BandFilter::Highpass(0.5),
+        BandFilter::Bandpass {
+            low: 0.5,
+            high: 2.0,
+        },
+        BandFilter::Bandstop {
+            low: 0.5,
+            high: 2.0,
+        },
+    ];
+
+    for order in 1..6 {
+        let zpk: GenericZpk<f64> = cheb1ap(order, 1.0).unwrap();
+        for band in bands {
+            let ba = lp2bf(zpk2ba(zpk.clone()), band).unwrap();
+            assert_same_tf(ba, lp2bf_zpk(zpk.clone(), band));
+        }
+    }
+}
+
+#[test]
+fn test_bilinear_ba_matches_zpk() {
+    for order in 1..8 {
+        let zpk: GenericZpk<f64> = buttap(order).unwrap();
+        let ba = bilinear(zpk2ba(zpk.clone()), 10.0).unwrap();
+        assert_same_tf(ba, bilinear_zpk(zpk, 10.0));
+    }
+}
+
+#[test]
+fn test_lp2bf_ba_first_order() {
+    let one = Complex64::new(1.0, 0.0);
+    let ba = GenericBa {
+        b: array![one],
+        a: array![one, one],
+    };
+
+    // 1 / (s + 1) -> 2 / (s + 2)
+    let lp = lp2lp(ba.clone(), 2.0).unwrap();
+    assert_eq!(trimmed(&lp.b), vec![2.0]);
+    assert_eq!(trimmed(&lp.a), vec![1.0, 2.0]);
+
+    // 1 / (s + 1) -> s / (s + 2)
+    let hp = lp2hp(ba.clone(), 2.0).unwrap();
+    assert_eq!(trimmed(&hp.b), vec![1.0, 0.0]);
+    assert_eq!(trimmed(&hp.a), vec![1.0, 2.0]);
+
+    // 1 / (s + 1) -> s / (s^2 + s + 4)
+    let bp = lp2bp(ba.clone(), 2.0, 1.0).unwrap();
+    assert_eq!(trimmed(&bp.b), vec![1.0, 0.0]);
+    assert_eq!(trimmed(&bp.a), vec![1.0, 1.0, 4.0]);
+
+    // 1 / (s + 1) -> (s^2 + 4) / (s^2 + s + 4)
+    let bs = lp2bs(ba.clone(), 2.0, 1.0).unwrap();
+    assert_eq!(trimmed(&bs.b), vec![1.0, 0.0, 4.0]);
+    assert_eq!(trimmed(&bs.a), vec![1.0, 1.0, 4.0]);
+
+    let zero = GenericBa {
+        b: array![one],
+        a: array![Complex64::new(0.0, 0.0)],
+    };
+    assert!(matches!(lp2lp(zero, 2.0), Err(Error::ZeroDenominator)));
+}
+
+#[test]
+fn with_py_test_lp2bf_ba() {
+    for _ in 0..200 {
+        let order = thread_rng().gen_range(1..10);
+        let wo = thread_rng().gen_range(0.1..10.0);
+        let bw = thread_rng().gen_range(0.1..10.0);
+        let fs = thread_rng().gen_range(1.0..100.0);
+
+        let python = with_scipy::<Vec<(Vec<f64>, Vec<f64>)>>(&format!(
+            "(lambda b, a: [tuple(map(list, f)) for f in [signal.lp2lp(b, a, {wo}), signal.lp2hp(b, a, {wo}), signal.lp2bp(b, a, {wo}, {bw}), signal.lp2bs(b, a, {wo}, {bw}), signal.bilinear(b, a, {fs})]])(*signal.zpk2tf(*signal.buttap({order})))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = zpk2ba(buttap(order).unwrap());
+        let rust = [
+            lp2lp(ba.clone(), wo),
+            lp2hp(ba.clone(), wo),
+            lp2bp(ba.clone(), wo, bw),
+            lp2bs(ba.clone(), wo, bw),
+            bilinear(ba.clone(), fs),
+        ];
+
+        for (rust, python) in rust.into_iter().zip(python) {
+            let rust = rust.unwrap();
+            let b: Vec<_> = rust.b.iter().map(|a| a.re).collect();
+            let a: Vec<_> = rust.a.iter().map(|a| a.re).collect();
+            approx::assert_relative_eq!(b.as_slice(), python.0.as_slice(), max_relative = 1e-8);
+            approx::assert_relative_eq!(a.as_slice(), python.1.as_slice(), max_relative = 1e-8);
+        }
+    }
+}
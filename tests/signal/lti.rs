@@ -0,0 +1,329 @@
+use crate::common::{butter, with_scipy};
+use ndarray::{array, s, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    lti::Error,
+    output_type::{DesiredFilterOutput, GenericBa},
+    *,
+};
+
+fn first_order() -> Lti {
+    let one = Complex64::new(1.0, 0.0);
+    Lti::new(GenericBa {
+        b: array![one],
+        a: array![one, one],
+    })
+}
+
+#[test]
+fn test_lti_first_order() {
+    let system = first_order();
+
+    // the default times span seven time constants
+    let step_response = step(&system, None, None, None).expect("valid system");
+    assert_eq!(step_response.t.len(), 100);
+    approx::assert_relative_eq!(step_response.t[99], 7.0, epsilon = 1e-12);
+    for (t, y) in step_response.t.iter().zip(&step_response.yout) {
+        approx::assert_relative_eq!(*y, 1.0 - (-t).exp(), epsilon = 1e-12);
+    }
+
+    let impulse_response = impulse(&system, None, None, Some(20)).expect("valid system");
+    for (t, y) in impulse_response.t.iter().zip(&impulse_response.yout) {
+        approx::assert_relative_eq!(*y, (-t).exp(), epsilon = 1e-12);
+    }
+
+    // a ramp is reproduced exactly by the first order hold
+    let t = Array1::linspace(0.0, 5.0, 51);
+    let response = lsim(
+        &system,
+        t.clone(),
+        t.clone(),
+        None,
+        InputInterpolation::FirstOrderHold,
+    )
+    .expect("valid system");
+    for (t, y) in t.iter().zip(&response.yout) {
+        approx::assert_relative_eq!(*y, t - 1.0 + (-t).exp(), epsilon = 1e-12);
+    }
+    assert_eq!(response.xout.dim(), (51, 1));
+}
+
+#[test]
+fn test_lsim_start_time() {
+    let system = Lti::new(butter(
+        3,
+        BandFilter::Lowpass(1.0),
+        Sampling::Analog,
+        DesiredFilterOutput::Zpk,
+    ));
+    let x0 = array![1.0, 0.5, -0.2];
+    let t = Array1::linspace(0.0, 5.0, 51);
+    let u = t.mapv(f64::sin);
+    let full = lsim(
+        &system,
+        u.clone(),
+        t.clone(),
+        Some(x0.clone()),
+        InputInterpolation::FirstOrderHold,
+    )
+    .expect("valid system");
+
+    // the free response carries the initial state from zero to the first time
+    let free = lsim(
+        &system,
+        Array1::zeros(51),
+        t.clone(),
+        Some(x0.clone()),
+        InputInterpolation::FirstOrderHold,
+    )
+    .expect("valid system");
+    let late = lsim(
+        &system,
+        Array1::zeros(41),
+        t.slice(s![10..]).to_owned(),
+        Some(x0.clone()),
+        InputInterpolation::FirstOrderHold,
+    )
+    .expect("valid system");
+    approx::assert_relative_eq!(late.yout, free.yout.slice(s![10..]), epsilon = 1e-12);
+
+    // a single sample is the output of the initial state
+    let single = lsim(
+        &system,
+        array![u[0]],
+        array![0.0],
+        Some(x0.clone()),
+        InputInterpolation::FirstOrderHold,
+    )
+    .expect("valid system");
+    assert_eq!(single.yout.len(), 1);
+    approx::assert_relative_eq!(single.yout[0], full.yout[0], epsilon = 1e-12);
+    approx::assert_relative_eq!(single.xout.row(0), x0, epsilon = 1e-12);
+
+    assert!(matches!(
+        lsim(
+            &system,
+            array![0.0, 0.0],
+            array![-1.0, 0.0],
+            None,
+            InputInterpolation::ZeroOrderHold
+        ),
+        Err(Error::InitialTime(_))
+    ));
+    assert!(matches!(
+        lsim(
+            &system,
+            array![],
+            array![],
+            None,
+            InputInterpolation::ZeroOrderHold
+        ),
+        Err(Error::TimeSamples(0))
+    ));
+}
+
+#[test]
+fn test_lti_representations() {
+    let t = Array1::linspace(0.0, 30.0, 300);
+    let responses: Vec<_> = [
+        DesiredFilterOutput::Zpk,
+        DesiredFilterOutput::Ba,
+        DesiredFilterOutput::Sos,
+    ]
+    .into_iter()
+    .map(|output| {
+        let system = Lti::new(butter(
+            4,
+            BandFilter::Lowpass(1.0),
+            Sampling::Analog,
+            output,
+        ));
+        step(&system, None, Some(t.clone()), None).expect("valid system")
+    })
+    .collect();
+
+    for response in &responses[1..] {
+        approx::assert_relative_eq!(
+            response.yout.as_slice().unwrap(),
+            responses[0].yout.as_slice().unwrap(),
+            epsilon = 1e-10
+        );
+    }
+    // unit DC gain
+    approx::assert_relative_eq!(responses[0].yout[299], 1.0, epsilon = 1e-4);
+}
+
+#[test]
+fn test_dlti_matches_lfilter() {
+    let sampling = Sampling::Digital { fs: 10.0 };
+    let ba = butter(
+        3,
+        BandFilter::Lowpass(2.0),
+        sampling,
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+    let system = Dlti::from_sampling(ba.clone(), sampling).expect("digital sampling");
+    assert!(Dlti::from_sampling(ba.clone(), Sampling::Analog).is_none());
+
+    let response = dimpulse(&system, None, None, Some(30)).expect("valid system");
+    let mut x = Array1::zeros(30);
+    x[0] = 1.0;
+    let expected = ba.lfilter_real(x, None).expect("real filter").filtered;
+    approx::assert_relative_eq!(
+        response.yout.as_slice().unwrap(),
+        expected.as_slice().unwrap(),
+        epsilon = 1e-12
+    );
+    approx::assert_relative_eq!(response.t[29], 2.9, epsilon = 1e-12);
+
+    let response = dstep(&system, None, None, None).expect("valid system");
+    let expected = ba
+        .lfilter_real(Array1::ones(100), None)
+        .expect("real filter")
+        .filtered;
+    approx::assert_relative_eq!(
+        response.yout.as_slice().unwrap(),
+        expected.as_slice().unwrap(),
+        epsilon = 1e-12
+    );
+
+    // the input is interpolated on the sampling grid
+    let u = array![0.0, 1.0];
+    let t = array![0.0, 1.0];
+    let response = dlsim(&system, u, Some(t), None).expect("valid system");
+    assert_eq!(response.yout.len(), 11);
+    let expected = ba
+        .lfilter_real(Array1::linspace(0.0, 1.0, 11), None)
+        .expect("real filter")
+        .filtered;
+    approx::assert_relative_eq!(
+        response.yout.as_slice().unwrap(),
+        expected.as_slice().unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_lti_errors() {
+    let one = Complex64::new(1.0, 0.0);
+    let improper = Lti::new(GenericBa {
+        b: array![one, one],
+        a: array![one],
+    });
+    assert!(matches!(
+        step(&improper, None, None, None),
+        Err(Error::StateSpace(_))
+    ));
+
+    let complex = Lti::new(GenericBa {
+        b: array![one],
+        a: array![one, Complex64::new(1.0, 1.0)],
+    });
+    assert!(matches!(
+        step(&complex, None, None, None),
+        Err(Error::ComplexCoefficients)
+    ));
+
+    let system = first_order();
+    assert!(matches!(
+        step(&system, None, Some(array![0.0, 1.0, 3.0]), None),
+        Err(Error::TimeSteps)
+    ));
+    assert!(matches!(
+        step(&system, Some(array![1.0, 2.0]), None, None),
+        Err(Error::InitialState {
+            expected: 1,
+            len: 2
+        })
+    ));
+    assert!(matches!(
+        lsim(
+            &system,
+            array![1.0],
+            array![0.0, 1.0],
+            None,
+            InputInterpolation::ZeroOrderHold
+        ),
+        Err(Error::InputLength { u: 1, t: 2 })
+    ));
+}
+
+#[test]
+fn with_py_test_lti() {
+    for _ in 0..50 {
+        let order = thread_rng().gen_range(1..8);
+        let wn = thread_rng().gen_range(0.1..10.0);
+
+        let python = with_scipy::<(Vec<f64>, Vec<f64>, Vec<f64>)>(&format!(
+            "(lambda sys, t: (list(t), list(signal.step(sys, T=t)[1]), list(signal.impulse(sys, T=t)[1])))(signal.butter({order}, {wn}, analog=True), np.linspace(0.0, 20.0 / {wn}, 100))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let system = Lti::new(butter(
+            order,
+            BandFilter::Lowpass(wn),
+            Sampling::Analog,
+            DesiredFilterOutput::Zpk,
+        ));
+        let t = Array1::from(python.0);
+        let step_response = step(&system, None, Some(t.clone()), None).expect("valid system");
+        let impulse_response = impulse(&system, None, Some(t), None).expect("valid system");
+
+        approx::assert_relative_eq!(
+            step_response.yout.as_slice().unwrap(),
+            python.1.as_slice(),
+            epsilon = 1e-8
+        );
+        approx::assert_relative_eq!(
+            impulse_response.yout.as_slice().unwrap(),
+            python.2.as_slice(),
+            epsilon = 1e-8 * wn
+        );
+    }
+}
+
+#[test]
+fn with_py_test_dlti() {
+    for _ in 0..50 {
+        let order = thread_rng().gen_range(1..8);
+        let wn = thread_rng().gen_range(0.05..0.95);
+
+        let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!(
+            "(lambda sys: (list(np.squeeze(signal.dstep(sys, n=60)[1])), list(np.squeeze(signal.dlsim(sys, np.sin(np.arange(60)))[1]))))(signal.dlti(*signal.butter({order}, {wn}), dt=0.5))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = butter(
+            order,
+            BandFilter::Lowpass(wn),
+            Sampling::Digital { fs: 2.0 },
+            DesiredFilterOutput::Ba,
+        );
+        let system = Dlti::new(ba, 0.5);
+        let step_response = dstep(&system, None, None, Some(60)).expect("valid system");
+        let u = Array1::from_shape_fn(60, |i| (i as f64).sin());
+        let response = dlsim(&system, u, None, None).expect("valid system");
+
+        approx::assert_relative_eq!(
+            step_response.yout.as_slice().unwrap(),
+            python.0.as_slice(),
+            epsilon = 1e-8
+        );
+        approx::assert_relative_eq!(
+            response.yout.as_slice().unwrap(),
+            python.1.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
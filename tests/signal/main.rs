@@ -1,9 +1,28 @@
 mod bessel;
 mod butter;
+mod buttord;
 mod cheby1;
 mod cheby2;
 mod common;
+mod cont2discrete;
+mod ellip;
+mod filter_analysis;
+mod filtfilt;
 mod fir_filter_design;
 mod fir_filter_design_windows;
+mod firls;
+mod freq_response;
+mod gammatone;
+mod group_delay;
+mod iirdesign;
+mod iirnotch;
+mod iirord;
+mod lp2bf_ba;
 mod lp2bf_zpk;
+mod lti;
+mod remez;
 mod signal_tools;
+mod sos;
+mod sosfilt;
+mod state_space;
+mod tf2zpk;
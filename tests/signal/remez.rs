@@ -0,0 +1,278 @@
+use crate::common::{gains, with_scipy};
+use ndarray::{array, Array1};
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{output_type::GenericBa, remez, RemezType, Sampling};
+
+/// largest deviation from `desired` over `[low, high]`
+fn ripple(ba: &GenericBa<f64>, low: f64, high: f64, desired: f64) -> f64 {
+    gains(
+        ba,
+        Array1::linspace(low, high, 4000),
+        Sampling::Digital { fs: 1.0 },
+    )
+    .into_iter()
+    .map(|g| (g - desired).abs())
+    .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_remez_lowpass() {
+    let sampling = Sampling::Digital { fs: 1.0 };
+    let bands = array![0.0, 0.2, 0.3, 0.5];
+    let ba = remez(
+        41,
+        bands.clone(),
+        array![1.0, 0.0],
+        None,
+        RemezType::Bandpass,
+        None,
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+
+    assert_eq!(ba.b.len(), 41);
+    assert!(ba.is_linear_phase());
+    for (x, y) in ba.b.iter().zip(ba.b.iter().rev()) {
+        approx::assert_relative_eq!(x.re, y.re, epsilon = 1e-12);
+    }
+
+    // equal weights give equal ripples in both bands, up to the resolution of the grid which
+    // misses the true peaks by a couple of percent
+    let pass = ripple(&ba, 0.0, 0.2, 1.0);
+    let stop = ripple(&ba, 0.3, 0.5, 0.0);
+    assert!(pass < 1e-2);
+    approx::assert_relative_eq!(pass, stop, max_relative = 5e-2);
+
+    // the ripple scales inversely with the weight
+    let weighted = remez(
+        41,
+        bands,
+        array![1.0, 0.0],
+        array![1.0, 10.0],
+        RemezType::Bandpass,
+        None,
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    let pass = ripple(&weighted, 0.0, 0.2, 1.0);
+    let stop = ripple(&weighted, 0.3, 0.5, 0.0);
+    approx::assert_relative_eq!(pass, 10.0 * stop, max_relative = 5e-2);
+}
+
+#[test]
+fn test_remez_even_and_antisymmetric() {
+    let sampling = Sampling::Digital { fs: 1.0 };
+
+    // type II highpass cannot be designed, the lowpass is fine
+    let ba = remez(
+        30,
+        array![0.0, 0.15, 0.25, 0.5],
+        array![1.0, 0.0],
+        None,
+        RemezType::Bandpass,
+        None,
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    assert_eq!(ba.b.len(), 30);
+    assert!(ripple(&ba, 0.0, 0.15, 1.0) < 1e-2);
+    assert!(ripple(&ba, 0.25, 0.5, 0.0) < 1e-2);
+
+    // type III hilbert transformer
+    let ba = remez(
+        31,
+        array![0.05, 0.45],
+        array![1.0],
+        None,
+        RemezType::Hilbert,
+        None,
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    approx::assert_relative_eq!(ba.b[15].re, 0.0, epsilon = 1e-12);
+    for (x, y) in ba.b.iter().zip(ba.b.iter().rev()) {
+        approx::assert_relative_eq!(x.re, -y.re, epsilon = 1e-12);
+    }
+    assert!(ripple(&ba, 0.05, 0.45, 1.0) < 2e-2);
+
+    // type IV differentiator
+    let ba = remez(
+        30,
+        array![0.0, 0.8],
+        array![1.0],
+        None,
+        RemezType::Differentiator,
+        None,
+        None,
+        Sampling::Analog,
+    )
+    .unwrap()
+    .ba();
+    for (x, y) in ba.b.iter().zip(ba.b.iter().rev()) {
+        approx::assert_relative_eq!(x.re, -y.re, epsilon = 1e-12);
+    }
+    approx::assert_relative_eq!(ba.dc_gain(sampling).re, 0.0, epsilon = 1e-9);
+
+    // the gain of a differentiator is proportional to the frequency
+    let ba = remez(
+        32,
+        array![0.0, 0.4],
+        array![1.0],
+        None,
+        RemezType::Differentiator,
+        None,
+        None,
+        sampling,
+    )
+    .unwrap()
+    .ba();
+    assert!(ba.b.iter().all(|b| b.re.abs() < 1.0));
+    let frequencies = Array1::linspace(0.01, 0.4, 40);
+    for (f, g) in frequencies
+        .iter()
+        .zip(gains(&ba, frequencies.clone(), sampling))
+    {
+        approx::assert_relative_eq!(g / f, 1.0, max_relative = 1e-6);
+    }
+}
+
+#[test]
+fn test_remez_numtaps() {
+    let sampling = Sampling::Digital { fs: 1.0 };
+    for (bands, numtaps) in [
+        (array![0.0, 0.2, 0.25, 0.5], 3..=201),
+        (array![0.0, 0.1, 0.2, 0.5], 3..=110),
+    ] {
+        for numtaps in numtaps {
+            let ba = remez(
+                numtaps,
+                bands.clone(),
+                array![1.0, 0.0],
+                None,
+                RemezType::Bandpass,
+                None,
+                None,
+                sampling,
+            )
+            .unwrap_or_else(|e| panic!("{numtaps} taps: {e}"))
+            .ba();
+            let pass = ripple(&ba, 0.0, bands[1], 1.0);
+            let stop = ripple(&ba, bands[2], 0.5, 0.0);
+            // the ripples of the long filters are small enough for the rounding errors of the
+            // coefficients to show up
+            assert!(pass < 0.5, "{numtaps} taps");
+            approx::assert_relative_eq!(pass, stop, max_relative = 0.15);
+        }
+    }
+
+    // longer filters would need ripples below the precision of the floating point numbers
+    assert!(matches!(
+        remez(
+            301,
+            array![0.0, 0.1, 0.2, 0.5],
+            array![1.0, 0.0],
+            None,
+            RemezType::Bandpass,
+            None,
+            None,
+            sampling,
+        ),
+        Err(remez::Error::NotConverged { .. })
+    ));
+}
+
+#[test]
+fn test_remez_errors() {
+    let sampling = Sampling::Digital { fs: 1.0 };
+    let design = |bands: Array1<f64>, desired: Array1<f64>, maxiter: usize| {
+        remez(
+            41,
+            bands,
+            desired,
+            None,
+            RemezType::Bandpass,
+            maxiter,
+            None,
+            sampling,
+        )
+    };
+
+    assert!(matches!(
+        design(array![0.0, 0.2, 0.3], array![1.0], 25),
+        Err(remez::Error::BandEdges(3))
+    ));
+    assert!(matches!(
+        design(array![], array![], 25),
+        Err(remez::Error::BandEdges(0))
+    ));
+    assert!(matches!(
+        design(array![0.0, 0.2, 0.3, 0.5], array![1.0], 25),
+        Err(remez::Error::Desired {
+            desired: 1,
+            bands: 2
+        })
+    ));
+    assert!(matches!(
+        design(array![0.0, 0.3, 0.2, 0.5], array![1.0, 0.0], 25),
+        Err(remez::Error::Monotonic)
+    ));
+    assert!(matches!(
+        design(array![0.0, 0.2, 0.3, 0.6], array![1.0, 0.0], 25),
+        Err(remez::Error::Nyquist)
+    ));
+    assert!(matches!(
+        design(array![0.0, 0.2, 0.3, 0.5], array![1.0, 0.0], 1),
+        Err(remez::Error::NotConverged { iterations: 1 })
+    ));
+
+    let error: sciport_rs::signal::error::Error =
+        design(array![0.0, 0.2, 0.3, 0.5], array![1.0, 0.0], 0)
+            .unwrap_err()
+            .into();
+    assert!(matches!(
+        error,
+        sciport_rs::signal::error::Error::Remez(remez::Error::NotConverged { iterations: 0 })
+    ));
+}
+
+#[test]
+fn with_py_test_remez() {
+    for _ in 0..200 {
+        let numtaps = thread_rng().gen_range(5..80);
+        let fs: f64 = thread_rng().gen_range(1.0..1000.0);
+        let edge = thread_rng().gen_range(0.05..0.35) * fs;
+        let width = thread_rng().gen_range(0.05..0.1) * fs;
+        let bands = array![0.0, edge, edge + width, fs / 2.0];
+        let weight = thread_rng().gen_range(0.1..10.0);
+
+        let rust_res = remez(
+            numtaps,
+            bands.clone(),
+            array![1.0, 0.0],
+            array![1.0, weight],
+            RemezType::Bandpass,
+            None,
+            None,
+            Sampling::Digital { fs },
+        );
+        let py_script = format!(
+            "signal.remez({numtaps}, {:?}, [1.0, 0.0], weight=[1.0, {weight}], fs={fs})",
+            bands.to_vec()
+        );
+        let python = with_scipy::<Vec<f64>>(&py_script);
+
+        let (Ok(rust_res), Some(python)) = (rust_res, python) else {
+            continue;
+        };
+        let rust_res: Vec<f64> = rust_res.ba().b.iter().map(|b| b.re).collect();
+        approx::assert_relative_eq!(rust_res.as_slice(), python.as_slice(), epsilon = 1e-8);
+    }
+}
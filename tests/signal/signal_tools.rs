@@ -1,11 +1,12 @@
-use ndarray::Array1;
+use ndarray::{concatenate, s, Array1, Array2, Axis};
 use numpy::Complex64;
 use sciport_rs::signal::{
-    band_filter::BandFilter, tools::generic_approx_complex_relative_slice_eq_dbg, Firwin1Filter,
-    GenericFIRFilterSettings, Sampling, WindowType,
+    band_filter::BandFilter, error::Error, lfilter_zi, output_type::DesiredFilterOutput,
+    tools::generic_approx_complex_relative_slice_eq_dbg, Filter, Firwin1Filter,
+    GenericFIRFilterSettings, RealFilter, Sampling, WindowType,
 };
 
-use crate::common::with_scipy;
+use crate::common::{cheby1, with_scipy};
 
 #[test]
 fn bad_test() {
@@ -64,3 +65,251 @@ fn bad_test() {
     // let filtered = result.filtered;
     // assert_relative_eq!(filtered.to_vec().as_slice(), python.as_slice());
 }
+
+#[test]
+fn test_lfilter_chunks() {
+    let filter = cheby1(
+        5,
+        1.0,
+        BandFilter::Bandpass {
+            low: 10.0,
+            high: 30.0,
+        },
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+
+    let signal = Array1::linspace(-1.0, 1.0, 400).mapv(|a: f64| Complex64::from((a * 40.0).sin()));
+
+    let full = filter.lfilter(signal.clone(), None).filtered;
+
+    let first = filter.lfilter(signal.slice(s![..151]).to_owned(), None);
+    let second = filter.lfilter(signal.slice(s![151..]).to_owned(), first.zi);
+    let chunked = concatenate![Axis(0), first.filtered, second.filtered];
+
+    approx::assert_relative_eq!(full, chunked, epsilon = 1e-12);
+}
+
+#[test]
+fn with_py_test_lfilter_zi() {
+    let filter = cheby1(
+        5,
+        1.0,
+        BandFilter::Lowpass(30.0),
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+
+    let rust = lfilter_zi(&filter);
+
+    let python = with_scipy::<Vec<f64>>(
+        "signal.lfilter_zi(*signal.cheby1(5, 1.0, 30.0, fs=200.0)).tolist()",
+    );
+    let python = if let Some(p) = python {
+        p
+    } else {
+        return;
+    };
+
+    approx::assert_relative_eq!(
+        rust.mapv(|a| a.re).to_vec().as_slice(),
+        python.as_slice(),
+        epsilon = 1e-8
+    );
+
+    let step = Array1::from_elem(100, Complex64::from(1.0));
+    let filtered = filter.lfilter(step, Some(rust)).filtered;
+    let gain = filter.b.sum() / filter.a.sum();
+    assert!(filtered.iter().all(|a| (a - gain).norm() < 1e-8));
+}
+
+fn cheby1_bandpass_sos() -> sciport_rs::signal::output_type::Sos {
+    cheby1(
+        4,
+        1.0,
+        BandFilter::Bandpass {
+            low: 10.0,
+            high: 30.0,
+        },
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Sos,
+    )
+    .sos()
+}
+
+#[test]
+fn test_lfilter_axis() {
+    let filter = cheby1_bandpass_sos();
+    let signal = Array2::from_shape_fn((3, 250), |(channel, i)| {
+        Complex64::from((i as f64 * 0.1 * (channel + 1) as f64).sin())
+    });
+
+    let rows = filter.lfilter_axis(signal.clone().into_dyn(), Axis(1), None);
+    let columns = filter.par_lfilter_axis(signal.t().to_owned(), Axis(0), None);
+
+    for (channel, lane) in signal.rows().into_iter().enumerate() {
+        let expected = filter.lfilter(lane.to_owned(), None);
+        approx::assert_relative_eq!(
+            rows.filtered.index_axis(Axis(0), channel),
+            expected.filtered.view().into_dyn(),
+            epsilon = 1e-12
+        );
+        approx::assert_relative_eq!(
+            columns.filtered.column(channel),
+            expected.filtered,
+            epsilon = 1e-12
+        );
+        approx::assert_relative_eq!(
+            columns.zi.as_ref().expect("final state").column(channel),
+            expected.zi.expect("final state"),
+            epsilon = 1e-12
+        );
+    }
+
+    // filter in two chunks carrying the state over
+    let first = filter.par_lfilter_axis(signal.slice(s![.., ..100]).to_owned(), Axis(1), None);
+    let second = filter.par_lfilter_axis(signal.slice(s![.., 100..]).to_owned(), Axis(1), first.zi);
+    let chunked = concatenate![Axis(1), first.filtered, second.filtered];
+    approx::assert_relative_eq!(
+        rows.filtered.into_dimensionality().expect("2d output"),
+        chunked,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn with_py_test_lfilter_axis() {
+    let filter = cheby1(
+        5,
+        1.0,
+        BandFilter::Lowpass(30.0),
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Ba,
+    )
+    .ba();
+    let signal = Array2::from_shape_fn((100, 4), |(i, channel)| {
+        (i as f64 * 0.2 * (channel + 1) as f64).cos()
+    });
+
+    let python = with_scipy::<Vec<Vec<f64>>>(&format!(
+        "signal.lfilter(*signal.cheby1(5, 1.0, 30.0, fs=200.0), np.array({signal}), axis=0).tolist()"
+    ));
+    let python = if let Some(p) = python {
+        p
+    } else {
+        return;
+    };
+
+    let rust = filter
+        .lfilter_axis(signal.mapv(Into::into), Axis(0), None)
+        .filtered;
+    for (rust, python) in rust.rows().into_iter().zip(python.iter()) {
+        approx::assert_relative_eq!(
+            rust.mapv(|a| a.re).to_vec().as_slice(),
+            python.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
+
+#[test]
+fn test_lfilter_real() {
+    let signal = Array1::linspace(-1.0, 1.0, 400).mapv(|a: f64| (a * 40.0).sin());
+
+    for desired in [
+        DesiredFilterOutput::Ba,
+        DesiredFilterOutput::Zpk,
+        DesiredFilterOutput::Sos,
+    ] {
+        let filter = cheby1(
+            5,
+            1.0,
+            BandFilter::Bandpass {
+                low: 10.0,
+                high: 30.0,
+            },
+            Sampling::Digital { fs: 200.0 },
+            desired,
+        );
+
+        let complex = filter.lfilter(signal.mapv(Into::into), None);
+        let real = filter
+            .lfilter_real(signal.clone(), None)
+            .expect("real coefficients");
+        approx::assert_relative_eq!(
+            real.filtered,
+            complex.filtered.mapv(|a| a.re),
+            epsilon = 1e-10
+        );
+
+        // filter in two chunks carrying the state over
+        let first = filter
+            .lfilter_real(signal.slice(s![..151]).to_owned(), None)
+            .expect("real coefficients");
+        let second = filter
+            .lfilter_real(signal.slice(s![151..]).to_owned(), first.zi)
+            .expect("real coefficients");
+        let chunked = concatenate![Axis(0), first.filtered, second.filtered];
+        approx::assert_relative_eq!(real.filtered, chunked, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_lfilter_real_complex_coefficients() {
+    let filter = sciport_rs::signal::output_type::Ba {
+        b: Array1::from_vec(vec![Complex64::new(1.0, 1.0)]),
+        a: Array1::from_vec(vec![Complex64::new(1.0, 0.0)]),
+    };
+    assert!(filter.lfilter_real(Array1::zeros(10), None).is_err());
+}
+
+#[test]
+fn test_lfilter_real_ba_initial_state() {
+    let filter = cheby1(
+        2,
+        1.0,
+        BandFilter::Lowpass(10.0),
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Ba,
+    );
+
+    for len in [1, 3] {
+        let result = filter.lfilter_real(Array1::zeros(10), Some(Array1::zeros(len)));
+        assert!(matches!(
+            result,
+            Err(Error::BaInitialState { expected: 2, len: l }) if l == len
+        ));
+    }
+    let result = filter.lfilter_real(Array1::zeros(10), Some(Array1::zeros(2)));
+    assert_eq!(result.unwrap().zi.unwrap().len(), 2);
+}
+
+#[test]
+fn test_lfilter_real_sos_initial_state() {
+    let filter = cheby1(
+        4,
+        1.0,
+        BandFilter::Lowpass(10.0),
+        Sampling::Digital { fs: 200.0 },
+        DesiredFilterOutput::Sos,
+    );
+
+    let result = filter.lfilter_real(Array1::zeros(10), Some(Array1::zeros(3)));
+    assert!(matches!(
+        result,
+        Err(Error::SosInitialState {
+            sections: 2,
+            len: 3
+        })
+    ));
+}
+
+#[test]
+fn test_real_convolve1d() {
+    let data = Array1::from_vec(vec![1.0, 2.0, 3.0]);
+    let window = Array1::from_vec(vec![0.0, 1.0, 0.5]);
+    let out = sciport_rs::signal::convolve1d(data.view(), window.view());
+    approx::assert_relative_eq!(out, Array1::from_vec(vec![0.0, 1.0, 2.5]), epsilon = 1e-12);
+}
@@ -0,0 +1,117 @@
+use crate::common::{butter, with_scipy};
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    output_type::{DesiredFilterOutput, GenericSos},
+    tools::{sos2tf, sos2zpk},
+    Sampling,
+};
+
+#[test]
+fn with_py_test_butter_sos() {
+    for _ in 0..500 {
+        let order = thread_rng().gen_range(1..20);
+        let kind = thread_rng().gen_range(0..4);
+
+        let mut band_filter = match kind {
+            0 => BandFilter::Lowpass(thread_rng().gen_range((0.0)..1.0)),
+            1 => BandFilter::Highpass(thread_rng().gen_range((0.0)..1.0)),
+            2 => {
+                let x1: f64 = thread_rng().gen_range((0.0)..1.0);
+                let x2: f64 = thread_rng().gen_range((0.0)..1.0);
+                BandFilter::Bandpass {
+                    low: x1.min(x2),
+                    high: x1.max(x2),
+                }
+            }
+            3 => {
+                let x1: f64 = thread_rng().gen_range((0.0)..1.0);
+                let x2: f64 = thread_rng().gen_range((0.0)..1.0);
+                BandFilter::Bandstop {
+                    low: x1.min(x2),
+                    high: x1.max(x2),
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        let analog = match thread_rng().gen_range(0..2) {
+            0 => Sampling::Analog,
+            1 => {
+                let fs = thread_rng().gen_range((3.0)..15.0);
+                band_filter = band_filter * fs / 2.0;
+                Sampling::Digital { fs }
+            }
+            _ => unreachable!(),
+        };
+        test_butter_sos(order, band_filter, analog);
+    }
+}
+
+fn test_butter_sos(order: u32, band_filter: BandFilter, analog: Sampling) {
+    let (wn, btype) = match &band_filter {
+        BandFilter::Bandstop { low, high } => (format!("[{low}, {high}]"), "bandstop"),
+        BandFilter::Bandpass { low, high } => (format!("[{low}, {high}]"), "bandpass"),
+        BandFilter::Lowpass(data) => (format!("{data}"), "lowpass"),
+        BandFilter::Highpass(data) => (format!("{data}"), "highpass"),
+    };
+
+    let (analog_s, fs) = match &analog {
+        Sampling::Analog => ("True", "None".to_string()),
+        Sampling::Digital { fs } => ("False", fs.to_string()),
+    };
+
+    let python = with_scipy::<Vec<Vec<f64>>>(&format!(
+        "signal.butter({order}, Wn={wn}, btype=\"{btype}\", output=\"sos\", analog={analog_s}, fs={fs}).tolist()"
+    ));
+    let python = if let Some(p) = python {
+        p
+    } else {
+        return;
+    };
+
+    let rust = butter(order, band_filter, analog, DesiredFilterOutput::Sos).sos();
+
+    assert_eq!(rust.n_sections(), python.len());
+    for (rust, python) in rust.sos.rows().into_iter().zip(python.iter()) {
+        let rust = rust.mapv(|a| a.re).to_vec();
+        approx::assert_relative_eq!(
+            rust.as_slice(),
+            python.as_slice(),
+            epsilon = 1e-6,
+            max_relative = 1e-6
+        );
+    }
+}
+
+#[test]
+fn with_py_test_sos2zpk_sos2tf() {
+    let sos = vec![
+        [0.0048, 0.0096, 0.0048, 1.0, -1.0486, 0.2961],
+        [1.0, 2.0, 1.0, 1.0, -1.3209, 0.6327],
+        [1.0, -0.5, 0.0, 1.0, 0.3, 0.0],
+    ];
+    let py_sos = format!("{sos:?}");
+    let rust = GenericSos {
+        sos: ndarray::Array2::from_shape_fn((sos.len(), 6), |(i, j)| sos[i][j].into()),
+    };
+
+    let python = with_scipy::<(Vec<f64>, Vec<f64>)>(&format!("signal.sos2tf({py_sos})")).unwrap();
+    let ba = sos2tf(rust.clone());
+    approx::assert_relative_eq!(
+        ba.b.mapv(|a| a.re).to_vec().as_slice(),
+        python.0.as_slice(),
+        epsilon = 1e-10
+    );
+    approx::assert_relative_eq!(
+        ba.a.mapv(|a| a.re).to_vec().as_slice(),
+        python.1.as_slice(),
+        epsilon = 1e-10
+    );
+
+    let python = with_scipy::<f64>(&format!("signal.sos2zpk({py_sos})[2]")).unwrap();
+    let zpk = sos2zpk(rust);
+    approx::assert_relative_eq!(zpk.k, python, epsilon = 1e-10);
+    assert_eq!(zpk.z.len(), 6);
+    assert_eq!(zpk.p.len(), 6);
+}
@@ -0,0 +1,116 @@
+use crate::common::{butter, with_scipy};
+use ndarray::{concatenate, s, Array1, Axis};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter, output_type::DesiredFilterOutput, sosfilt, sosfilt_zi, sosfiltfilt,
+    PadType, Sampling,
+};
+
+fn random_sos(order: u32) -> (sciport_rs::signal::output_type::Sos, String) {
+    let fs = 100.0;
+    let x1: f64 = thread_rng().gen_range((1.0)..49.0);
+    let x2: f64 = thread_rng().gen_range((1.0)..49.0);
+    let (low, high) = (x1.min(x2), x1.max(x2));
+
+    let sos = butter(
+        order,
+        BandFilter::Bandpass { low, high },
+        Sampling::Digital { fs },
+        DesiredFilterOutput::Sos,
+    )
+    .sos();
+    let py_sos = format!(
+        "signal.butter({order}, [{low}, {high}], btype=\"bandpass\", output=\"sos\", fs={fs})"
+    );
+    (sos, py_sos)
+}
+
+#[test]
+fn with_py_test_sosfilt() {
+    for _ in 0..100 {
+        let order = thread_rng().gen_range(1..10);
+        let (sos, py_sos) = random_sos(order);
+        let signal = Array1::linspace(-1.0, 1.0, 200).mapv(|a: f64| (a * 20.0).sin());
+
+        let python =
+            with_scipy::<Vec<f64>>(&format!("signal.sosfilt({py_sos}, {signal}).tolist()"));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let rust = sosfilt(&sos, signal.mapv(Into::into), None).filtered;
+        approx::assert_relative_eq!(
+            rust.mapv(|a| a.re).to_vec().as_slice(),
+            python.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
+
+#[test]
+fn test_sosfilt_chunks() {
+    let (sos, _) = random_sos(8);
+    let signal = Array1::linspace(-1.0, 1.0, 500).mapv(|a: f64| Complex64::from((a * 20.0).sin()));
+
+    let full = sosfilt(&sos, signal.clone(), None).filtered;
+
+    let first = sosfilt(&sos, signal.slice(s![..137]).to_owned(), None);
+    let second = sosfilt(&sos, signal.slice(s![137..]).to_owned(), first.zi);
+    let chunked = concatenate![Axis(0), first.filtered, second.filtered];
+
+    approx::assert_relative_eq!(full, chunked, epsilon = 1e-12);
+}
+
+#[test]
+fn with_py_test_sosfilt_zi() {
+    let (sos, py_sos) = random_sos(6);
+    let python = with_scipy::<Vec<Vec<f64>>>(&format!("signal.sosfilt_zi({py_sos}).tolist()"));
+    let python = if let Some(p) = python {
+        p
+    } else {
+        return;
+    };
+
+    let rust = sosfilt_zi(&sos);
+    for (rust, python) in rust.rows().into_iter().zip(python.iter()) {
+        approx::assert_relative_eq!(
+            rust.mapv(|a| a.re).to_vec().as_slice(),
+            python.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
+
+#[test]
+fn with_py_test_sosfiltfilt() {
+    for (padtype, py_padtype) in [
+        (Some(PadType::Odd), "'odd'"),
+        (Some(PadType::Even), "'even'"),
+        (Some(PadType::Constant), "'constant'"),
+        (None, "None"),
+    ] {
+        let order = thread_rng().gen_range(1..10);
+        let (sos, py_sos) = random_sos(order);
+        let signal = Array1::linspace(-1.0, 1.0, 300).mapv(|a: f64| (a * 20.0).sin() + a);
+
+        let python = with_scipy::<Vec<f64>>(&format!(
+            "signal.sosfiltfilt({py_sos}, {signal}, padtype={py_padtype}).tolist()"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let rust = sosfiltfilt(&sos, signal.mapv(Into::into), padtype, None)
+            .expect("signal longer than padlen");
+        approx::assert_relative_eq!(
+            rust.mapv(|a| a.re).to_vec().as_slice(),
+            python.as_slice(),
+            epsilon = 1e-8
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::common::{butter, with_scipy};
+use nalgebra::DMatrix;
+use ndarray::{array, concatenate, s, Array1, Axis};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    output_type::{DesiredFilterOutput, GenericBa, GenericStateSpace},
+    tools::{ss, ss2tf, ss2zpk, tf2ss, zpk2ss},
+    *,
+};
+
+fn real(a: &[f64]) -> Array1<Complex64> {
+    a.iter().map(|a| Complex64::new(*a, 0.0)).collect()
+}
+
+fn real_matrix(m: &DMatrix<Complex64>) -> Vec<f64> {
+    m.transpose().iter().map(|a| a.re).collect()
+}
+
+#[test]
+fn test_tf2ss() {
+    let ba = GenericBa {
+        b: real(&[1.0, 3.0, 3.0]),
+        a: real(&[1.0, 2.0, 1.0]),
+    };
+    let ss = tf2ss(ba).expect("proper transfer function");
+
+    assert_eq!(real_matrix(ss.a()), vec![-2.0, -1.0, 1.0, 0.0]);
+    assert_eq!(real_matrix(ss.b()), vec![1.0, 0.0]);
+    assert_eq!(real_matrix(ss.c()), vec![1.0, 2.0]);
+    assert_eq!(real_matrix(ss.d()), vec![1.0]);
+
+    let ba = ss2tf(ss).expect("siso system");
+    approx::assert_relative_eq!(
+        ba.b.mapv(|b| b.re).as_slice().unwrap(),
+        [1.0, 3.0, 3.0].as_slice(),
+        epsilon = 1e-12
+    );
+    approx::assert_relative_eq!(
+        ba.a.mapv(|a| a.re).as_slice().unwrap(),
+        [1.0, 2.0, 1.0].as_slice(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_state_space_round_trip() {
+    for sampling in [Sampling::Analog, Sampling::Digital { fs: 2.0 }] {
+        let zpk = butter(
+            5,
+            BandFilter::Lowpass(0.4),
+            sampling,
+            DesiredFilterOutput::Zpk,
+        )
+        .zpk();
+        let ss = zpk2ss(zpk.clone()).expect("proper system");
+        assert_eq!(ss.n_states(), 5);
+
+        let back = ss2zpk(ss).expect("siso system");
+        assert_eq!(back.p.len(), zpk.p.len());
+        for p in back.p.iter() {
+            assert!(
+                zpk.p.iter().any(|e| (p - e).norm() < 1e-8),
+                "{p} is not a pole"
+            );
+        }
+        approx::assert_relative_eq!(back.k, zpk.k, max_relative = 1e-8);
+        assert_eq!(back.z.len(), zpk.z.len());
+    }
+}
+
+#[test]
+fn test_state_space_output() {
+    for sampling in [Sampling::Analog, Sampling::Digital { fs: 2.0 }] {
+        let ss = butter(
+            4,
+            BandFilter::Lowpass(0.3),
+            sampling,
+            DesiredFilterOutput::StateSpace,
+        );
+        let ba = butter(
+            4,
+            BandFilter::Lowpass(0.3),
+            sampling,
+            DesiredFilterOutput::Ba,
+        );
+
+        let grid = FrequencyGrid::Frequencies(array![0.0, 0.1, 0.3, 0.5]);
+        let h_ss = ss
+            .freq_response(grid.clone(), sampling)
+            .expect("valid frequency response")
+            .h;
+        let h_ba = ba
+            .freq_response(grid, sampling)
+            .expect("valid frequency response")
+            .h;
+        for (a, b) in h_ss.iter().zip(&h_ba) {
+            assert!((a - b).norm() < 1e-10, "{a} != {b}");
+        }
+    }
+
+    let sampling = Sampling::Digital { fs: 2.0 };
+    let ss = butter(
+        4,
+        BandFilter::Lowpass(0.3),
+        sampling,
+        DesiredFilterOutput::StateSpace,
+    );
+    let ba = butter(
+        4,
+        BandFilter::Lowpass(0.3),
+        sampling,
+        DesiredFilterOutput::Ba,
+    );
+    let x = Array1::from_shape_fn(50, |i| (i as f64 * 0.3).sin());
+    let y_ss = ss.lfilter_real(x.clone(), None).expect("real system");
+    let y_ba = ba.lfilter_real(x.clone(), None).expect("real system");
+    approx::assert_relative_eq!(
+        y_ss.filtered.as_slice().unwrap(),
+        y_ba.filtered.as_slice().unwrap(),
+        epsilon = 1e-10
+    );
+
+    // filter in two chunks carrying the state vector over
+    let first = ss.lfilter(x.slice(s![..20]).mapv(Into::into), None);
+    let second = ss.lfilter(x.slice(s![20..]).mapv(Into::into), first.zi);
+    let chunked = concatenate![Axis(0), first.filtered, second.filtered].mapv(|y| y.re);
+    approx::assert_relative_eq!(y_ss.filtered, chunked, epsilon = 1e-10);
+
+    assert!(matches!(
+        ss.lfilter_real(x, Some(Array1::zeros(3))),
+        Err(error::Error::StateSpaceInitialState { states: 4, len: 3 })
+    ));
+}
+
+#[test]
+fn test_state_space_errors() {
+    let improper = GenericBa {
+        b: real(&[1.0, 0.0, 1.0]),
+        a: real(&[1.0, 1.0]),
+    };
+    assert!(matches!(
+        tf2ss(improper),
+        Err(ss::Error::ImproperTransferFunction { num: 2, den: 1 })
+    ));
+
+    let one = DMatrix::from_element(1, 1, Complex64::new(1.0, 0.0));
+    let mimo = GenericStateSpace::new(
+        one.clone(),
+        DMatrix::from_element(1, 2, Complex64::new(1.0, 0.0)),
+        one.clone(),
+        DMatrix::from_element(1, 2, Complex64::new(0.0, 0.0)),
+    );
+    assert!(matches!(
+        mimo,
+        Err(ss::Error::NotSiso {
+            inputs: 2,
+            outputs: 1
+        })
+    ));
+    let mismatched = GenericStateSpace::new(
+        DMatrix::from_element(2, 2, Complex64::new(1.0, 0.0)),
+        one.clone(),
+        one.clone(),
+        one,
+    );
+    assert!(matches!(mismatched, Err(ss::Error::Shape { .. })));
+}
+
+#[test]
+fn test_state_space_equal_modulus_poles() {
+    // the poles of z^n - 0.5 all have the same modulus, which stalls the plain QR iteration
+    for n in [2, 4, 8, 12, 16] {
+        let mut a = Array1::zeros(n + 1);
+        a[0] = Complex64::new(1.0, 0.0);
+        a[n] = Complex64::new(-0.5, 0.0);
+        let ss = tf2ss(GenericBa { b: real(&[1.0]), a }).unwrap();
+
+        let zpk = ss2zpk(ss.clone()).unwrap();
+        assert_eq!(zpk.p.len(), n);
+        for p in zpk.p.iter() {
+            approx::assert_relative_eq!(p.norm(), 0.5_f64.powf(1.0 / n as f64), epsilon = 1e-10);
+        }
+        let ba = ss2tf(ss).unwrap();
+        approx::assert_abs_diff_eq!(ba.a[n].re, -0.5, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn with_py_test_state_space() {
+    for _ in 0..100 {
+        let order = thread_rng().gen_range(1..10);
+        let wn = thread_rng().gen_range(0.05..0.95);
+
+        let python = with_scipy::<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>)>(&format!(
+            "(lambda a, b, c, d: (list(a.flatten()), list(b.flatten()), list(c.flatten()), list(d.flatten())))(*signal.butter({order}, {wn}, output=\"ss\"))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = butter(
+            order,
+            BandFilter::Lowpass(wn),
+            Sampling::Digital { fs: 2.0 },
+            DesiredFilterOutput::Ba,
+        )
+        .ba();
+        let ss = tf2ss(ba).expect("proper transfer function");
+
+        for (rust, python) in [
+            (real_matrix(ss.a()), python.0),
+            (real_matrix(ss.b()), python.1),
+            (real_matrix(ss.c()), python.2),
+            (real_matrix(ss.d()), python.3),
+        ] {
+            approx::assert_relative_eq!(rust.as_slice(), python.as_slice(), epsilon = 1e-8);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::common::{butter, cheby1, check_zpk_filter, with_scipy};
+use ndarray::{array, Array1};
+use num::complex::Complex64;
+use rand::{thread_rng, Rng};
+use sciport_rs::signal::{
+    band_filter::BandFilter,
+    output_type::{DesiredFilterOutput, GenericBa, GenericZpk},
+    tools::{ba::Error, tf2zpk, zpk2ba},
+    *,
+};
+
+fn real(a: &[f64]) -> Array1<Complex64> {
+    a.iter().map(|a| Complex64::new(*a, 0.0)).collect()
+}
+
+fn assert_same_roots(rust: &Array1<Complex64>, expected: &Array1<Complex64>, tol: f64) {
+    assert_eq!(rust.len(), expected.len());
+    for r in rust.iter() {
+        assert!(
+            expected.iter().any(|e| (r - e).norm() < tol),
+            "{r} not found in {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_tf2zpk() {
+    let ba = GenericBa {
+        b: real(&[2.0, -6.0, 4.0]),
+        a: real(&[2.0, 6.0, 4.0]),
+    };
+    let zpk = tf2zpk(ba.clone()).unwrap();
+    assert_same_roots(&zpk.z, &real(&[1.0, 2.0]), 1e-12);
+    assert_same_roots(&zpk.p, &real(&[-1.0, -2.0]), 1e-12);
+    approx::assert_relative_eq!(zpk.k, 1.0, epsilon = 1e-12);
+
+    let from = GenericZpk::try_from(ba).unwrap();
+    assert_eq!(from.z, zpk.z);
+    assert_eq!(from.p, zpk.p);
+
+    let zero = GenericBa {
+        b: real(&[0.0]),
+        a: real(&[1.0, 1.0]),
+    };
+    let zpk = tf2zpk(zero).unwrap();
+    assert!(zpk.z.is_empty());
+    assert_eq!(zpk.k, 0.0);
+
+    let invalid = GenericBa {
+        b: real(&[1.0]),
+        a: real(&[0.0, 0.0]),
+    };
+    assert!(matches!(
+        GenericZpk::try_from(invalid.clone()),
+        Err(Error::ZeroDenominator)
+    ));
+    assert!(matches!(tf2zpk(invalid), Err(Error::ZeroDenominator)));
+}
+
+#[test]
+fn test_tf2zpk_round_trip() {
+    for order in 1..10 {
+        let zpk = butter(
+            order,
+            BandFilter::Lowpass(0.3),
+            Sampling::Digital { fs: 2.0 },
+            DesiredFilterOutput::Zpk,
+        )
+        .zpk();
+
+        let back = tf2zpk(zpk2ba(zpk.clone())).unwrap();
+        // the zeros at -1 are a multiple root, their accuracy is limited by the order
+        assert_same_roots(&back.z, &zpk.z, 1e-1);
+        assert_same_roots(&back.p, &zpk.p, 1e-8);
+        approx::assert_relative_eq!(back.k, zpk.k, max_relative = 1e-8);
+    }
+
+    let ba = GenericBa {
+        b: real(&[1.0, 0.0, 0.0]),
+        a: real(&[1.0, -1.5, 0.7]),
+    };
+    let zpk = tf2zpk(ba).unwrap();
+    assert_eq!(
+        zpk.z,
+        array![Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0)]
+    );
+    for p in zpk.p.iter() {
+        approx::assert_relative_eq!(p.norm(), 0.7_f64.sqrt(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn with_py_test_tf2zpk() {
+    for _ in 0..200 {
+        let order = thread_rng().gen_range(1..10);
+        let wn = thread_rng().gen_range(0.05..0.95);
+        let rp = thread_rng().gen_range(0.1..5.0);
+
+        let python = with_scipy::<(Vec<Complex64>, Vec<Complex64>, f64)>(&format!(
+            "(lambda z, p, k: (list(z), list(p), k))(*signal.tf2zpk(*signal.cheby1({order}, {rp}, {wn})))"
+        ));
+        let python = if let Some(p) = python {
+            p
+        } else {
+            continue;
+        };
+
+        let ba = cheby1(
+            order,
+            rp,
+            BandFilter::Lowpass(wn),
+            Sampling::Digital { fs: 2.0 },
+            DesiredFilterOutput::Ba,
+        )
+        .ba();
+
+        assert!(check_zpk_filter(tf2zpk(ba).unwrap(), python));
+    }
+}
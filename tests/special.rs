@@ -44,3 +44,42 @@ pub fn test_i0() {
         );
     }
 }
+
+#[test]
+pub fn test_ellipk_ellipe() {
+    for _ in 0..500 {
+        let m: f64 = rand::thread_rng().gen_range(-5.0..1.0);
+
+        let python: (f64, f64, f64) = with_scipy(&format!(
+            "(special.ellipk({m}), special.ellipkm1({}), special.ellipe({m}))",
+            1.0 - m
+        ))
+        .unwrap();
+        let rust = (
+            sciport_rs::special::ellipk(m),
+            sciport_rs::special::ellipkm1(1.0 - m),
+            sciport_rs::special::ellipe(m),
+        );
+
+        approx::assert_relative_eq!(rust.0, python.0, max_relative = 1e-13);
+        approx::assert_relative_eq!(rust.1, python.1, max_relative = 1e-13);
+        approx::assert_relative_eq!(rust.2, python.2, max_relative = 1e-13);
+    }
+}
+
+#[test]
+pub fn test_ellipj() {
+    for _ in 0..500 {
+        let u: f64 = rand::thread_rng().gen_range(-10.0..10.0);
+        let m: f64 = rand::thread_rng().gen_range(0.0..1.0);
+
+        let python: (f64, f64, f64, f64) =
+            with_scipy(&format!("tuple(special.ellipj({u}, {m}))")).unwrap();
+        let (sn, cn, dn, ph) = sciport_rs::special::ellipj(u, m);
+
+        approx::assert_relative_eq!(sn, python.0, epsilon = 1e-12);
+        approx::assert_relative_eq!(cn, python.1, epsilon = 1e-12);
+        approx::assert_relative_eq!(dn, python.2, epsilon = 1e-12);
+        approx::assert_relative_eq!(ph, python.3, epsilon = 1e-12);
+    }
+}